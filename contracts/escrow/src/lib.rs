@@ -1,8 +1,9 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+use ink::storage::Mapping;
+pub use propchain_traits::EscrowConfig;
 #[cfg(not(feature = "std"))]
 use scale_info::prelude::{string::String, vec::Vec};
-use ink::storage::Mapping;
 
 pub mod tests;
 
@@ -27,6 +28,8 @@ mod propchain_escrow {
         InvalidConfiguration,
         EscrowAlreadyFunded,
         ParticipantNotFound,
+        Overfunded,
+        NotSigned,
     }
 
     /// Escrow status enumeration
@@ -68,6 +71,10 @@ mod propchain_escrow {
         pub created_at: u64,
         pub release_time_lock: Option<u64>,
         pub participants: Vec<AccountId>,
+        pub fee_bps: u16,
+        pub fee_recipient: AccountId,
+        pub condition_deadline: Option<u64>,
+        pub conditions_gate_refund: bool,
     }
 
     /// Multi-signature configuration
@@ -101,6 +108,10 @@ mod propchain_escrow {
         pub met: bool,
         pub verified_by: Option<AccountId>,
         pub verified_at: Option<u64>,
+        /// Document whose verification satisfies this condition
+        pub linked_document: Option<Hash>,
+        /// Only this account may mark the condition met; any participant if None
+        pub verifier: Option<AccountId>,
     }
 
     /// Dispute information
@@ -148,12 +159,30 @@ mod propchain_escrow {
         condition_counters: Mapping<u64, u64>,
         /// Disputes
         disputes: Mapping<u64, DisputeInfo>,
-        /// Audit logs
+        /// Audit logs, holding at most `max_audit_entries` of the newest entries per escrow
         audit_logs: Mapping<u64, Vec<AuditEntry>>,
+        /// Number of oldest audit entries dropped per escrow to respect the cap
+        audit_truncated_counts: Mapping<u64, u64>,
+        /// Maximum audit entries kept per escrow
+        max_audit_entries: u32,
         /// Admin account
         admin: AccountId,
         /// High-value threshold for mandatory multi-sig
         min_high_value_threshold: u128,
+        /// Overpayment policy: refund the excess (true) or reject the deposit (false)
+        refund_overpayment: bool,
+        /// Balances released or refunded to each account, pulled via `withdraw`
+        pending_withdrawals: Mapping<AccountId, u128>,
+        /// Sum of all pending withdrawals, reserved against sweeping
+        total_pending_withdrawals: u128,
+        /// Accounts authorized to resolve disputes alongside the admin
+        arbitrators: Mapping<AccountId, bool>,
+        /// Escrow IDs per involved account (buyer, seller, participants)
+        participant_escrows: Mapping<AccountId, Vec<u64>>,
+        /// Whether resolving a dispute discards Release signatures collected before it
+        reset_signatures_on_resolve: bool,
+        /// Minimum required signatures for escrows at or above the high-value threshold
+        high_value_min_signatures: u8,
     }
 
     // Events
@@ -167,6 +196,16 @@ mod propchain_escrow {
         amount: u128,
     }
 
+    #[ink(event)]
+    pub struct AwaitingFunding {
+        #[ink(topic)]
+        escrow_id: u64,
+        #[ink(topic)]
+        buyer: AccountId,
+        amount: u128,
+        deadline: Option<u64>,
+    }
+
     #[ink(event)]
     pub struct FundsDeposited {
         #[ink(topic)]
@@ -183,6 +222,15 @@ mod propchain_escrow {
         recipient: AccountId,
     }
 
+    #[ink(event)]
+    pub struct FeeCollected {
+        #[ink(topic)]
+        escrow_id: u64,
+        #[ink(topic)]
+        recipient: AccountId,
+        amount: u128,
+    }
+
     #[ink(event)]
     pub struct FundsRefunded {
         #[ink(topic)]
@@ -191,6 +239,20 @@ mod propchain_escrow {
         recipient: AccountId,
     }
 
+    #[ink(event)]
+    pub struct FundsWithdrawn {
+        #[ink(topic)]
+        account: AccountId,
+        amount: u128,
+    }
+
+    #[ink(event)]
+    pub struct FundsSwept {
+        #[ink(topic)]
+        recipient: AccountId,
+        amount: u128,
+    }
+
     #[ink(event)]
     pub struct DocumentUploaded {
         #[ink(topic)]
@@ -216,6 +278,14 @@ mod propchain_escrow {
         description: String,
     }
 
+    #[ink(event)]
+    pub struct ConditionRemoved {
+        #[ink(topic)]
+        escrow_id: u64,
+        condition_id: u64,
+        removed_by: AccountId,
+    }
+
     #[ink(event)]
     pub struct ConditionMet {
         #[ink(topic)]
@@ -232,6 +302,14 @@ mod propchain_escrow {
         signer: AccountId,
     }
 
+    #[ink(event)]
+    pub struct SignatureRevoked {
+        #[ink(topic)]
+        escrow_id: u64,
+        approval_type: ApprovalType,
+        signer: AccountId,
+    }
+
     #[ink(event)]
     pub struct DisputeRaised {
         #[ink(topic)]
@@ -244,9 +322,18 @@ mod propchain_escrow {
     pub struct DisputeResolved {
         #[ink(topic)]
         escrow_id: u64,
+        #[ink(topic)]
+        resolved_by: AccountId,
         resolution: String,
     }
 
+    #[ink(event)]
+    pub struct SignaturesReset {
+        #[ink(topic)]
+        escrow_id: u64,
+        approval_type: ApprovalType,
+    }
+
     #[ink(event)]
     pub struct EmergencyOverride {
         #[ink(topic)]
@@ -269,8 +356,17 @@ mod propchain_escrow {
                 condition_counters: Mapping::default(),
                 disputes: Mapping::default(),
                 audit_logs: Mapping::default(),
+                audit_truncated_counts: Mapping::default(),
+                max_audit_entries: 100,
                 admin: Self::env().caller(),
                 min_high_value_threshold,
+                refund_overpayment: false,
+                pending_withdrawals: Mapping::default(),
+                total_pending_withdrawals: 0,
+                arbitrators: Mapping::default(),
+                participant_escrows: Mapping::default(),
+                reset_signatures_on_resolve: true,
+                high_value_min_signatures: 2,
             }
         }
 
@@ -282,11 +378,18 @@ mod propchain_escrow {
             amount: u128,
             buyer: AccountId,
             seller: AccountId,
-            participants: Vec<AccountId>,
-            required_signatures: u8,
-            release_time_lock: Option<u64>,
+            config: EscrowConfig,
         ) -> Result<u64, Error> {
             let caller = self.env().caller();
+            let EscrowConfig {
+                participants,
+                required_signatures,
+                release_time_lock,
+                fee_bps,
+                fee_recipient,
+                condition_deadline,
+                conditions_gate_refund,
+            } = config;
             
             // Validate configuration
             if required_signatures == 0 || participants.is_empty() {
@@ -297,6 +400,17 @@ mod propchain_escrow {
                 return Err(Error::InvalidConfiguration);
             }
 
+            // High-value escrows must use multi-sig
+            if amount >= self.min_high_value_threshold
+                && required_signatures < self.high_value_min_signatures
+            {
+                return Err(Error::InvalidConfiguration);
+            }
+
+            if fee_bps > 10_000 {
+                return Err(Error::InvalidConfiguration);
+            }
+
             self.escrow_count += 1;
             let escrow_id = self.escrow_count;
 
@@ -312,10 +426,21 @@ mod propchain_escrow {
                 created_at: self.env().block_timestamp(),
                 release_time_lock,
                 participants: participants.clone(),
+                fee_bps,
+                fee_recipient,
+                condition_deadline,
+                conditions_gate_refund,
             };
 
             self.escrows.insert(&escrow_id, &escrow_data);
 
+            // Index escrow by every involved account
+            self.index_participant(buyer, escrow_id);
+            self.index_participant(seller, escrow_id);
+            for participant in participants.iter() {
+                self.index_participant(*participant, escrow_id);
+            }
+
             // Set up multi-sig configuration
             let multi_sig_config = MultiSigConfig {
                 required_signatures,
@@ -345,10 +470,20 @@ mod propchain_escrow {
                 amount,
             });
 
+            // Prompt the buyer to fund the escrow
+            self.env().emit_event(AwaitingFunding {
+                escrow_id,
+                buyer,
+                amount,
+                deadline: release_time_lock,
+            });
+
             Ok(escrow_id)
         }
 
         /// Deposit funds to escrow
+        /// Deposits that would exceed the escrow amount are rejected with `Overfunded`,
+        /// or have the excess refunded to the depositor if `refund_overpayment` is enabled
         #[ink(message, payable)]
         pub fn deposit_funds(&mut self, escrow_id: u64) -> Result<(), Error> {
             let caller = self.env().caller();
@@ -361,8 +496,21 @@ mod propchain_escrow {
                 return Err(Error::InvalidStatus);
             }
 
+            // Handle overpayment according to the configured policy
+            let remaining = escrow.amount.saturating_sub(escrow.deposited_amount);
+            let mut credited = transferred;
+            if transferred > remaining {
+                if !self.refund_overpayment {
+                    return Err(Error::Overfunded);
+                }
+
+                let excess = transferred - remaining;
+                self.credit_withdrawal(caller, excess);
+                credited = remaining;
+            }
+
             // Update deposited amount
-            escrow.deposited_amount += transferred;
+            escrow.deposited_amount += credited;
 
             // Check if fully funded
             if escrow.deposited_amount >= escrow.amount {
@@ -378,12 +526,12 @@ mod propchain_escrow {
                 escrow_id,
                 caller,
                 "FundsDeposited".to_string(),
-                format!("Amount: {}", transferred),
+                format!("Amount: {}", credited),
             );
 
             self.env().emit_event(FundsDeposited {
                 escrow_id,
-                amount: transferred,
+                amount: credited,
                 depositor: caller,
             });
 
@@ -396,38 +544,120 @@ mod propchain_escrow {
             let caller = self.env().caller();
             let escrow = self.escrows.get(&escrow_id).ok_or(Error::EscrowNotFound)?;
 
-            // Check status
-            if escrow.status != EscrowStatus::Active {
-                return Err(Error::InvalidStatus);
-            }
+            // Check status, dispute, time lock, conditions and signatures
+            self.ensure_release_allowed(&escrow)?;
 
-            // Check for active dispute
-            if let Some(dispute) = self.disputes.get(&escrow_id) {
-                if !dispute.resolved {
-                    return Err(Error::DisputeActive);
-                }
+            // Credit funds to seller, minus the platform fee
+            let seller_amount =
+                self.pay_out_with_fee(&escrow, escrow.seller, escrow.deposited_amount);
+
+            // Update status
+            let mut updated_escrow = escrow.clone();
+            updated_escrow.status = EscrowStatus::Released;
+            self.escrows.insert(&escrow_id, &updated_escrow);
+
+            // Add audit entry
+            self.add_audit_entry(
+                escrow_id,
+                caller,
+                "FundsReleased".to_string(),
+                format!("Amount: {} to seller", seller_amount),
+            );
+
+            self.env().emit_event(FundsReleased {
+                escrow_id,
+                amount: seller_amount,
+                recipient: escrow.seller,
+            });
+
+            Ok(())
+        }
+
+        /// Release part of the deposited funds to the seller (milestone payment)
+        /// The escrow stays Active until the remaining balance is exhausted
+        #[ink(message)]
+        pub fn release_partial(&mut self, escrow_id: u64, amount: u128) -> Result<(), Error> {
+            let caller = self.env().caller();
+            let mut escrow = self.escrows.get(&escrow_id).ok_or(Error::EscrowNotFound)?;
+
+            // Check status, dispute, time lock, conditions and signatures
+            self.ensure_release_allowed(&escrow)?;
+
+            if amount == 0 {
+                return Err(Error::InvalidConfiguration);
             }
 
-            // Check time lock
-            if let Some(time_lock) = escrow.release_time_lock {
-                if self.env().block_timestamp() < time_lock {
-                    return Err(Error::TimeLockActive);
-                }
+            if amount > escrow.deposited_amount {
+                return Err(Error::InsufficientFunds);
             }
 
-            // Check all conditions are met
-            if !self.check_all_conditions_met(escrow_id)? {
-                return Err(Error::ConditionsNotMet);
+            // Credit the tranche to seller, minus the platform fee
+            let seller_amount = self.pay_out_with_fee(&escrow, escrow.seller, amount);
+
+            // Update remaining balance and status
+            escrow.deposited_amount -= amount;
+            if escrow.deposited_amount == 0 {
+                escrow.status = EscrowStatus::Released;
             }
+            self.escrows.insert(&escrow_id, &escrow);
 
-            // Check multi-sig threshold
-            if !self.check_signature_threshold(escrow_id, ApprovalType::Release)? {
-                return Err(Error::SignatureThresholdNotMet);
+            // Add audit entry
+            self.add_audit_entry(
+                escrow_id,
+                caller,
+                "PartialRelease".to_string(),
+                format!(
+                    "Amount: {} to seller, remaining: {}",
+                    seller_amount, escrow.deposited_amount
+                ),
+            );
+
+            self.env().emit_event(FundsReleased {
+                escrow_id,
+                amount: seller_amount,
+                recipient: escrow.seller,
+            });
+
+            Ok(())
+        }
+
+        /// Release the deposited funds split across several recipients (seller, agent, tax authority, ...)
+        /// Shares must sum to the deposited amount; the platform fee applies to each share
+        #[ink(message)]
+        pub fn release_to_recipients(
+            &mut self,
+            escrow_id: u64,
+            recipients: Vec<(AccountId, u128)>,
+        ) -> Result<(), Error> {
+            let caller = self.env().caller();
+            let escrow = self.escrows.get(&escrow_id).ok_or(Error::EscrowNotFound)?;
+
+            // Check status, dispute, time lock, conditions and signatures
+            self.ensure_release_allowed(&escrow)?;
+
+            // Validate shares
+            if recipients.is_empty() {
+                return Err(Error::InvalidConfiguration);
+            }
+            let mut total: u128 = 0;
+            for (_, share) in recipients.iter() {
+                total = total
+                    .checked_add(*share)
+                    .ok_or(Error::InvalidConfiguration)?;
+            }
+            if total != escrow.deposited_amount {
+                return Err(Error::InvalidConfiguration);
             }
 
-            // Transfer funds to seller
-            if self.env().transfer(escrow.seller, escrow.deposited_amount).is_err() {
-                return Err(Error::InsufficientFunds);
+            // Credit each share
+            for (recipient, share) in recipients.iter() {
+                let paid = self.pay_out_with_fee(&escrow, *recipient, *share);
+
+                self.env().emit_event(FundsReleased {
+                    escrow_id,
+                    amount: paid,
+                    recipient: *recipient,
+                });
             }
 
             // Update status
@@ -440,15 +670,13 @@ mod propchain_escrow {
                 escrow_id,
                 caller,
                 "FundsReleased".to_string(),
-                format!("Amount: {} to seller", escrow.deposited_amount),
+                format!(
+                    "Amount: {} split across {} recipients",
+                    total,
+                    recipients.len()
+                ),
             );
 
-            self.env().emit_event(FundsReleased {
-                escrow_id,
-                amount: escrow.deposited_amount,
-                recipient: escrow.seller,
-            });
-
             Ok(())
         }
 
@@ -468,11 +696,14 @@ mod propchain_escrow {
                 return Err(Error::SignatureThresholdNotMet);
             }
 
-            // Transfer funds back to buyer
-            if self.env().transfer(escrow.buyer, escrow.deposited_amount).is_err() {
-                return Err(Error::InsufficientFunds);
+            // Check conditions if they gate refunds for this escrow
+            if escrow.conditions_gate_refund && !self.check_all_conditions_met(escrow_id)? {
+                return Err(Error::ConditionsNotMet);
             }
 
+            // Credit funds back to buyer for withdrawal
+            self.credit_withdrawal(escrow.buyer, escrow.deposited_amount);
+
             // Update status
             let mut updated_escrow = escrow.clone();
             updated_escrow.status = EscrowStatus::Refunded;
@@ -495,6 +726,72 @@ mod propchain_escrow {
             Ok(())
         }
 
+        /// Refund the buyer once the condition deadline has passed with conditions still unmet
+        /// An escrow without conditions expires once the deadline passes unreleased
+        /// Does not require the Refund multi-sig threshold; anyone may trigger it unless a dispute is open
+        #[ink(message)]
+        pub fn expire_escrow(&mut self, escrow_id: u64) -> Result<(), Error> {
+            let caller = self.env().caller();
+            let escrow = self.escrows.get(&escrow_id).ok_or(Error::EscrowNotFound)?;
+
+            // An open dispute is decided by the arbitrators, not the deadline
+            if let Some(dispute) = self.disputes.get(&escrow_id) {
+                if !dispute.resolved {
+                    return Err(Error::DisputeActive);
+                }
+            }
+
+            // Check status
+            if escrow.status != EscrowStatus::Active && escrow.status != EscrowStatus::Funded {
+                return Err(Error::InvalidStatus);
+            }
+
+            // Check deadline has passed
+            let deadline = escrow
+                .condition_deadline
+                .ok_or(Error::InvalidConfiguration)?;
+            if self.env().block_timestamp() <= deadline {
+                return Err(Error::TimeLockActive);
+            }
+
+            // Only expire if the seller failed to meet the conditions
+            let has_conditions = !self
+                .conditions
+                .get(&escrow_id)
+                .unwrap_or_default()
+                .is_empty();
+            if has_conditions && self.check_all_conditions_met(escrow_id)? {
+                return Err(Error::InvalidStatus);
+            }
+
+            // Credit funds back to buyer for withdrawal
+            self.credit_withdrawal(escrow.buyer, escrow.deposited_amount);
+
+            // Update status
+            let mut updated_escrow = escrow.clone();
+            updated_escrow.status = EscrowStatus::Refunded;
+            self.escrows.insert(&escrow_id, &updated_escrow);
+
+            // Add audit entry
+            self.add_audit_entry(
+                escrow_id,
+                caller,
+                "EscrowExpired".to_string(),
+                format!(
+                    "Amount: {} to buyer after deadline {}",
+                    escrow.deposited_amount, deadline
+                ),
+            );
+
+            self.env().emit_event(FundsRefunded {
+                escrow_id,
+                amount: escrow.deposited_amount,
+                recipient: escrow.buyer,
+            });
+
+            Ok(())
+        }
+
         /// Upload document hash
         #[ink(message)]
         pub fn upload_document(
@@ -583,12 +880,88 @@ mod propchain_escrow {
                 verifier: caller,
             });
 
+            // Satisfy any conditions waiting on this document
+            let mut conditions = self.conditions.get(&escrow_id).unwrap_or_default();
+            let linked: Vec<u64> = conditions
+                .iter()
+                .filter(|c| !c.met && c.linked_document == Some(document_hash))
+                .filter(|c| c.verifier.is_none() || c.verifier == Some(caller))
+                .map(|c| c.id)
+                .collect();
+            if !linked.is_empty() {
+                for condition in conditions.iter_mut() {
+                    if linked.contains(&condition.id) {
+                        self.satisfy_condition(escrow_id, condition, caller);
+                    }
+                }
+                self.conditions.insert(&escrow_id, &conditions);
+            }
+
+            Ok(())
+        }
+
+        /// Link a condition to a document so that verifying the document marks the condition met
+        /// If the document is already verified the condition is satisfied immediately
+        #[ink(message)]
+        pub fn link_condition_to_document(
+            &mut self,
+            escrow_id: u64,
+            condition_id: u64,
+            document_hash: Hash,
+        ) -> Result<(), Error> {
+            let caller = self.env().caller();
+            let escrow = self.escrows.get(&escrow_id).ok_or(Error::EscrowNotFound)?;
+
+            // Only buyer or seller can link conditions
+            if caller != escrow.buyer && caller != escrow.seller {
+                return Err(Error::Unauthorized);
+            }
+
+            if matches!(
+                escrow.status,
+                EscrowStatus::Released | EscrowStatus::Refunded | EscrowStatus::Cancelled
+            ) {
+                return Err(Error::InvalidStatus);
+            }
+
+            let already_verified = self
+                .documents
+                .get(&escrow_id)
+                .unwrap_or_default()
+                .iter()
+                .any(|doc| doc.hash == document_hash && doc.verified);
+
+            let mut conditions = self.conditions.get(&escrow_id).unwrap_or_default();
+            let condition = conditions
+                .iter_mut()
+                .find(|c| c.id == condition_id)
+                .ok_or(Error::EscrowNotFound)?;
+            condition.linked_document = Some(document_hash);
+            let may_satisfy = condition.verifier.is_none() || condition.verifier == Some(caller);
+            if already_verified && may_satisfy && !condition.met {
+                self.satisfy_condition(escrow_id, condition, caller);
+            }
+            self.conditions.insert(&escrow_id, &conditions);
+
+            // Add audit entry
+            self.add_audit_entry(
+                escrow_id,
+                caller,
+                "ConditionLinked".to_string(),
+                format!("Condition ID: {}", condition_id),
+            );
+
             Ok(())
         }
 
-        /// Add condition to escrow
+        /// Add condition to escrow, optionally restricted to a designated verifier
         #[ink(message)]
-        pub fn add_condition(&mut self, escrow_id: u64, description: String) -> Result<u64, Error> {
+        pub fn add_condition(
+            &mut self,
+            escrow_id: u64,
+            description: String,
+            verifier: Option<AccountId>,
+        ) -> Result<u64, Error> {
             let caller = self.env().caller();
             let escrow = self.escrows.get(&escrow_id).ok_or(Error::EscrowNotFound)?;
 
@@ -606,6 +979,8 @@ mod propchain_escrow {
                 met: false,
                 verified_by: None,
                 verified_at: None,
+                linked_document: None,
+                verifier,
             };
 
             let mut conditions = self.conditions.get(&escrow_id).unwrap_or_default();
@@ -636,16 +1011,20 @@ mod propchain_escrow {
             let caller = self.env().caller();
             let escrow = self.escrows.get(&escrow_id).ok_or(Error::EscrowNotFound)?;
 
-            // Check if caller is a participant
-            if !escrow.participants.contains(&caller) {
-                return Err(Error::Unauthorized);
-            }
-
             let mut conditions = self.conditions.get(&escrow_id).unwrap_or_default();
             let mut found = false;
 
             for condition in conditions.iter_mut() {
                 if condition.id == condition_id {
+                    // A designated verifier replaces the participant check
+                    let authorized = match condition.verifier {
+                        Some(verifier) => caller == verifier,
+                        None => escrow.participants.contains(&caller),
+                    };
+                    if !authorized {
+                        return Err(Error::Unauthorized);
+                    }
+
                     condition.met = true;
                     condition.verified_by = Some(caller);
                     condition.verified_at = Some(self.env().block_timestamp());
@@ -677,31 +1056,85 @@ mod propchain_escrow {
             Ok(())
         }
 
-        /// Sign approval for release or refund
+        /// Remove a condition added by mistake (buyer or seller, before settlement)
         #[ink(message)]
-        pub fn sign_approval(&mut self, escrow_id: u64, approval_type: ApprovalType) -> Result<(), Error> {
+        pub fn remove_condition(&mut self, escrow_id: u64, condition_id: u64) -> Result<(), Error> {
             let caller = self.env().caller();
-            let _escrow = self.escrows.get(&escrow_id).ok_or(Error::EscrowNotFound)?;
-            let config = self.multi_sig_configs.get(&escrow_id).ok_or(Error::EscrowNotFound)?;
+            let escrow = self.escrows.get(&escrow_id).ok_or(Error::EscrowNotFound)?;
 
-            // Check if caller is a valid signer
-            if !config.signers.contains(&caller) {
+            // Only buyer or seller can remove conditions
+            if caller != escrow.buyer && caller != escrow.seller {
                 return Err(Error::Unauthorized);
             }
 
-            // Check if already signed
-            let sig_key = (escrow_id, approval_type.clone(), caller);
-            if self.signatures.get(&sig_key).unwrap_or(false) {
-                return Err(Error::AlreadySigned);
+            // Conditions are frozen once the escrow is settled
+            if matches!(
+                escrow.status,
+                EscrowStatus::Released | EscrowStatus::Refunded | EscrowStatus::Cancelled
+            ) {
+                return Err(Error::InvalidStatus);
             }
 
-            // Add signature
-            self.signatures.insert(&sig_key, &true);
+            let mut conditions = self.conditions.get(&escrow_id).unwrap_or_default();
+            let before = conditions.len();
+            conditions.retain(|c| c.id != condition_id);
 
-            // Update signature count
-            let count_key = (escrow_id, approval_type.clone());
-            let current_count = self.signature_counts.get(&count_key).unwrap_or(0);
-            self.signature_counts.insert(&count_key, &(current_count + 1));
+            if conditions.len() == before {
+                return Err(Error::EscrowNotFound);
+            }
+
+            self.conditions.insert(&escrow_id, &conditions);
+
+            // Add audit entry
+            self.add_audit_entry(
+                escrow_id,
+                caller,
+                "ConditionRemoved".to_string(),
+                format!("Condition ID: {}", condition_id),
+            );
+
+            self.env().emit_event(ConditionRemoved {
+                escrow_id,
+                condition_id,
+                removed_by: caller,
+            });
+
+            Ok(())
+        }
+
+        /// Sign approval for release or refund
+        #[ink(message)]
+        pub fn sign_approval(
+            &mut self,
+            escrow_id: u64,
+            approval_type: ApprovalType,
+        ) -> Result<(), Error> {
+            let caller = self.env().caller();
+            let _escrow = self.escrows.get(&escrow_id).ok_or(Error::EscrowNotFound)?;
+            let config = self
+                .multi_sig_configs
+                .get(&escrow_id)
+                .ok_or(Error::EscrowNotFound)?;
+
+            // Check if caller is a valid signer
+            if !config.signers.contains(&caller) {
+                return Err(Error::Unauthorized);
+            }
+
+            // Check if already signed
+            let sig_key = (escrow_id, approval_type.clone(), caller);
+            if self.signatures.get(&sig_key).unwrap_or(false) {
+                return Err(Error::AlreadySigned);
+            }
+
+            // Add signature
+            self.signatures.insert(&sig_key, &true);
+
+            // Update signature count
+            let count_key = (escrow_id, approval_type.clone());
+            let current_count = self.signature_counts.get(&count_key).unwrap_or(0);
+            self.signature_counts
+                .insert(&count_key, &(current_count + 1));
 
             // Add audit entry
             self.add_audit_entry(
@@ -720,6 +1153,48 @@ mod propchain_escrow {
             Ok(())
         }
 
+        /// Revoke a previously given approval before it is acted upon
+        #[ink(message)]
+        pub fn revoke_approval(
+            &mut self,
+            escrow_id: u64,
+            approval_type: ApprovalType,
+        ) -> Result<(), Error> {
+            let caller = self.env().caller();
+            let _escrow = self.escrows.get(&escrow_id).ok_or(Error::EscrowNotFound)?;
+
+            // Check caller has signed
+            let sig_key = (escrow_id, approval_type.clone(), caller);
+            if !self.signatures.get(&sig_key).unwrap_or(false) {
+                return Err(Error::NotSigned);
+            }
+
+            // Remove signature
+            self.signatures.remove(&sig_key);
+
+            // Update signature count
+            let count_key = (escrow_id, approval_type.clone());
+            let current_count = self.signature_counts.get(&count_key).unwrap_or(0);
+            self.signature_counts
+                .insert(&count_key, &current_count.saturating_sub(1));
+
+            // Add audit entry
+            self.add_audit_entry(
+                escrow_id,
+                caller,
+                "SignatureRevoked".to_string(),
+                format!("Approval type: {:?}", approval_type),
+            );
+
+            self.env().emit_event(SignatureRevoked {
+                escrow_id,
+                approval_type,
+                signer: caller,
+            });
+
+            Ok(())
+        }
+
         /// Raise a dispute
         #[ink(message)]
         pub fn raise_dispute(&mut self, escrow_id: u64, reason: String) -> Result<(), Error> {
@@ -771,13 +1246,13 @@ mod propchain_escrow {
             Ok(())
         }
 
-        /// Resolve dispute (admin only)
+        /// Resolve dispute (admin or arbitrator)
         #[ink(message)]
         pub fn resolve_dispute(&mut self, escrow_id: u64, resolution: String) -> Result<(), Error> {
             let caller = self.env().caller();
 
-            // Only admin can resolve disputes
-            if caller != self.admin {
+            // Only admin or an arbitrator can resolve disputes
+            if caller != self.admin && !self.is_arbitrator(caller) {
                 return Err(Error::Unauthorized);
             }
 
@@ -791,6 +1266,11 @@ mod propchain_escrow {
             escrow.status = EscrowStatus::Active;
             self.escrows.insert(&escrow_id, &escrow);
 
+            // Force re-approval of release after the dispute
+            if self.reset_signatures_on_resolve {
+                self.reset_signatures(escrow_id, ApprovalType::Release)?;
+            }
+
             // Add audit entry
             self.add_audit_entry(
                 escrow_id,
@@ -801,6 +1281,7 @@ mod propchain_escrow {
 
             self.env().emit_event(DisputeResolved {
                 escrow_id,
+                resolved_by: caller,
                 resolution,
             });
 
@@ -825,10 +1306,8 @@ mod propchain_escrow {
                 escrow.buyer
             };
 
-            // Transfer funds
-            if self.env().transfer(recipient, escrow.deposited_amount).is_err() {
-                return Err(Error::InsufficientFunds);
-            }
+            // Credit funds for withdrawal
+            self.credit_withdrawal(recipient, escrow.deposited_amount);
 
             // Update status
             let mut updated_escrow = escrow.clone();
@@ -855,14 +1334,146 @@ mod propchain_escrow {
             Ok(())
         }
 
+        /// Withdraw the caller's released or refunded balance
+        /// Returns the amount withdrawn, zero if nothing is pending
+        #[ink(message)]
+        pub fn withdraw(&mut self) -> Result<u128, Error> {
+            let caller = self.env().caller();
+            let amount = self.pending_withdrawals.get(&caller).unwrap_or(0);
+
+            if amount == 0 {
+                return Ok(0);
+            }
+
+            // Clear the balance before transferring
+            self.pending_withdrawals.remove(&caller);
+            self.total_pending_withdrawals -= amount;
+
+            if self.env().transfer(caller, amount).is_err() {
+                return Err(Error::InsufficientFunds);
+            }
+
+            self.env().emit_event(FundsWithdrawn {
+                account: caller,
+                amount,
+            });
+
+            Ok(amount)
+        }
+
+        /// Transfer funds not owed to any escrow or pending withdrawal to `recipient` (admin only)
+        /// Recovers value sent directly to the contract; returns the amount swept
+        #[ink(message)]
+        pub fn sweep_unallocated(&mut self, recipient: AccountId) -> Result<u128, Error> {
+            let caller = self.env().caller();
+
+            if caller != self.admin {
+                return Err(Error::Unauthorized);
+            }
+
+            let surplus = self.env().balance().saturating_sub(self.allocated_funds());
+            if surplus == 0 {
+                return Ok(0);
+            }
+
+            if self.env().transfer(recipient, surplus).is_err() {
+                return Err(Error::InsufficientFunds);
+            }
+
+            self.env().emit_event(FundsSwept {
+                recipient,
+                amount: surplus,
+            });
+
+            Ok(surplus)
+        }
+
         // Query functions
 
+        /// Get the total deposited into escrows that are not yet released, refunded or cancelled
+        #[ink(message)]
+        pub fn get_total_locked(&self) -> u128 {
+            let mut locked = 0;
+            for id in 1..=self.escrow_count {
+                if let Some(escrow) = self.escrows.get(&id) {
+                    if !matches!(
+                        escrow.status,
+                        EscrowStatus::Released | EscrowStatus::Refunded | EscrowStatus::Cancelled
+                    ) {
+                        locked += escrow.deposited_amount;
+                    }
+                }
+            }
+            locked
+        }
+
+        /// Whether the contract balance covers every locked deposit and pending withdrawal
+        #[ink(message)]
+        pub fn is_solvent(&self) -> bool {
+            self.env().balance() >= self.allocated_funds()
+        }
+
+        /// Get balance available to withdraw for an account
+        #[ink(message)]
+        pub fn get_pending_withdrawal(&self, account: AccountId) -> u128 {
+            self.pending_withdrawals.get(&account).unwrap_or(0)
+        }
+
         /// Get escrow details
         #[ink(message)]
         pub fn get_escrow(&self, escrow_id: u64) -> Option<EscrowData> {
             self.escrows.get(&escrow_id)
         }
 
+        /// Get all escrow IDs an account is involved in as buyer, seller or participant
+        #[ink(message)]
+        pub fn get_escrows_for(&self, account: AccountId) -> Vec<u64> {
+            self.participant_escrows.get(&account).unwrap_or_default()
+        }
+
+        /// Get up to `limit` escrows in ID order, starting from escrow ID `start`
+        #[ink(message)]
+        pub fn get_escrows_paged(&self, start: u64, limit: u64) -> Vec<EscrowData> {
+            let first = start.max(1);
+            let last = first
+                .saturating_add(limit)
+                .min(self.escrow_count.saturating_add(1));
+            (first..last)
+                .filter_map(|id| self.escrows.get(&id))
+                .collect()
+        }
+
+        /// Number of escrows in each status, for an operator overview
+        /// Iterates every escrow; intended for off-chain queries
+        #[ink(message)]
+        pub fn count_by_status(&self) -> Vec<(EscrowStatus, u64)> {
+            let mut counts: Vec<(EscrowStatus, u64)> = [
+                EscrowStatus::Created,
+                EscrowStatus::Funded,
+                EscrowStatus::Active,
+                EscrowStatus::Released,
+                EscrowStatus::Refunded,
+                EscrowStatus::Disputed,
+                EscrowStatus::Cancelled,
+            ]
+            .into_iter()
+            .map(|status| (status, 0))
+            .collect();
+
+            for id in 1..=self.escrow_count {
+                if let Some(escrow) = self.escrows.get(&id) {
+                    if let Some(entry) = counts
+                        .iter_mut()
+                        .find(|(status, _)| *status == escrow.status)
+                    {
+                        entry.1 += 1;
+                    }
+                }
+            }
+
+            counts
+        }
+
         /// Get documents for escrow
         #[ink(message)]
         pub fn get_documents(&self, escrow_id: u64) -> Vec<DocumentHash> {
@@ -881,12 +1492,18 @@ mod propchain_escrow {
             self.disputes.get(&escrow_id)
         }
 
-        /// Get audit trail
+        /// Get audit trail (the newest `max_audit_entries` entries, oldest first)
         #[ink(message)]
         pub fn get_audit_trail(&self, escrow_id: u64) -> Vec<AuditEntry> {
             self.audit_logs.get(&escrow_id).unwrap_or_default()
         }
 
+        /// Get how many of an escrow's oldest audit entries were dropped
+        #[ink(message)]
+        pub fn get_audit_truncated_count(&self, escrow_id: u64) -> u64 {
+            self.audit_truncated_counts.get(&escrow_id).unwrap_or(0)
+        }
+
         /// Get multi-sig configuration
         #[ink(message)]
         pub fn get_multi_sig_config(&self, escrow_id: u64) -> Option<MultiSigConfig> {
@@ -913,6 +1530,17 @@ mod propchain_escrow {
             Ok(conditions.iter().all(|c| c.met))
         }
 
+        /// Get number of conditions not yet met
+        #[ink(message)]
+        pub fn get_unmet_condition_count(&self, escrow_id: u64) -> u64 {
+            self.conditions
+                .get(&escrow_id)
+                .unwrap_or_default()
+                .iter()
+                .filter(|c| !c.met)
+                .count() as u64
+        }
+
         /// Set admin
         #[ink(message)]
         pub fn set_admin(&mut self, new_admin: AccountId) -> Result<(), Error> {
@@ -932,21 +1560,275 @@ mod propchain_escrow {
             self.admin
         }
 
+        /// Set whether resolving a dispute resets Release signatures (admin only)
+        #[ink(message)]
+        pub fn set_reset_signatures_on_resolve(&mut self, reset: bool) -> Result<(), Error> {
+            let caller = self.env().caller();
+
+            if caller != self.admin {
+                return Err(Error::Unauthorized);
+            }
+
+            self.reset_signatures_on_resolve = reset;
+            Ok(())
+        }
+
+        /// Get whether resolving a dispute resets Release signatures
+        #[ink(message)]
+        pub fn get_reset_signatures_on_resolve(&self) -> bool {
+            self.reset_signatures_on_resolve
+        }
+
+        /// Set the minimum signatures required for high-value escrows (admin only)
+        #[ink(message)]
+        pub fn set_high_value_min_signatures(&mut self, min_signatures: u8) -> Result<(), Error> {
+            let caller = self.env().caller();
+
+            if caller != self.admin {
+                return Err(Error::Unauthorized);
+            }
+
+            if min_signatures == 0 {
+                return Err(Error::InvalidConfiguration);
+            }
+
+            self.high_value_min_signatures = min_signatures;
+            Ok(())
+        }
+
+        /// Get the minimum signatures required for high-value escrows
+        #[ink(message)]
+        pub fn get_high_value_min_signatures(&self) -> u8 {
+            self.high_value_min_signatures
+        }
+
+        /// Set how many audit entries are kept per escrow (admin only)
+        /// Longer logs are trimmed from the oldest end on their next entry
+        #[ink(message)]
+        pub fn set_max_audit_entries(&mut self, max_entries: u32) -> Result<(), Error> {
+            let caller = self.env().caller();
+
+            if caller != self.admin {
+                return Err(Error::Unauthorized);
+            }
+
+            if max_entries == 0 {
+                return Err(Error::InvalidConfiguration);
+            }
+
+            self.max_audit_entries = max_entries;
+            Ok(())
+        }
+
+        /// Get how many audit entries are kept per escrow
+        #[ink(message)]
+        pub fn get_max_audit_entries(&self) -> u32 {
+            self.max_audit_entries
+        }
+
+        /// Add a dispute arbitrator (admin only)
+        #[ink(message)]
+        pub fn add_arbitrator(&mut self, arbitrator: AccountId) -> Result<(), Error> {
+            let caller = self.env().caller();
+
+            if caller != self.admin {
+                return Err(Error::Unauthorized);
+            }
+
+            self.arbitrators.insert(&arbitrator, &true);
+            Ok(())
+        }
+
+        /// Remove a dispute arbitrator (admin only)
+        #[ink(message)]
+        pub fn remove_arbitrator(&mut self, arbitrator: AccountId) -> Result<(), Error> {
+            let caller = self.env().caller();
+
+            if caller != self.admin {
+                return Err(Error::Unauthorized);
+            }
+
+            self.arbitrators.remove(&arbitrator);
+            Ok(())
+        }
+
+        /// Check if an account is an arbitrator
+        #[ink(message)]
+        pub fn is_arbitrator(&self, account: AccountId) -> bool {
+            self.arbitrators.get(&account).unwrap_or(false)
+        }
+
         /// Get high-value threshold
         #[ink(message)]
         pub fn get_high_value_threshold(&self) -> u128 {
             self.min_high_value_threshold
         }
 
+        /// Set overpayment policy: refund the excess instead of rejecting the deposit
+        #[ink(message)]
+        pub fn set_refund_overpayment(&mut self, refund: bool) -> Result<(), Error> {
+            let caller = self.env().caller();
+
+            if caller != self.admin {
+                return Err(Error::Unauthorized);
+            }
+
+            self.refund_overpayment = refund;
+            Ok(())
+        }
+
+        /// Get overpayment policy
+        #[ink(message)]
+        pub fn get_refund_overpayment(&self) -> bool {
+            self.refund_overpayment
+        }
+
         // Helper functions
 
+        /// Pay `amount` out of an escrow, crediting the fee share to the fee recipient
+        /// and the remainder to `recipient`. Returns the amount credited to `recipient`
+        fn pay_out_with_fee(
+            &mut self,
+            escrow: &EscrowData,
+            recipient: AccountId,
+            amount: u128,
+        ) -> u128 {
+            let fee = amount * escrow.fee_bps as u128 / 10_000;
+            let seller_amount = amount - fee;
+
+            if fee > 0 {
+                self.credit_withdrawal(escrow.fee_recipient, fee);
+
+                self.env().emit_event(FeeCollected {
+                    escrow_id: escrow.id,
+                    recipient: escrow.fee_recipient,
+                    amount: fee,
+                });
+            }
+
+            self.credit_withdrawal(recipient, seller_amount);
+
+            seller_amount
+        }
+
+        /// Record an escrow against an involved account, once per account
+        /// Must be called for any account added to an escrow's participants
+        fn index_participant(&mut self, account: AccountId, escrow_id: u64) {
+            let mut escrow_ids = self.participant_escrows.get(&account).unwrap_or_default();
+            if !escrow_ids.contains(&escrow_id) {
+                escrow_ids.push(escrow_id);
+                self.participant_escrows.insert(&account, &escrow_ids);
+            }
+        }
+
+        /// Funds the contract owes: deposits held by unsettled escrows plus pending withdrawals
+        fn allocated_funds(&self) -> u128 {
+            self.get_total_locked() + self.total_pending_withdrawals
+        }
+
+        /// Add to an account's withdrawable balance
+        fn credit_withdrawal(&mut self, account: AccountId, amount: u128) {
+            let pending = self.pending_withdrawals.get(&account).unwrap_or(0);
+            self.pending_withdrawals
+                .insert(&account, &(pending + amount));
+            self.total_pending_withdrawals += amount;
+        }
+
+        /// Check that an escrow can currently release funds
+        fn ensure_release_allowed(&self, escrow: &EscrowData) -> Result<(), Error> {
+            // Check status
+            if escrow.status != EscrowStatus::Active {
+                return Err(Error::InvalidStatus);
+            }
+
+            // Check for active dispute
+            if let Some(dispute) = self.disputes.get(&escrow.id) {
+                if !dispute.resolved {
+                    return Err(Error::DisputeActive);
+                }
+            }
+
+            // Check time lock
+            if let Some(time_lock) = escrow.release_time_lock {
+                if self.env().block_timestamp() < time_lock {
+                    return Err(Error::TimeLockActive);
+                }
+            }
+
+            // Check all conditions are met
+            if !self.check_all_conditions_met(escrow.id)? {
+                return Err(Error::ConditionsNotMet);
+            }
+
+            // Check multi-sig threshold
+            if !self.check_signature_threshold(escrow.id, ApprovalType::Release)? {
+                return Err(Error::SignatureThresholdNotMet);
+            }
+
+            Ok(())
+        }
+
+        /// Clear all signatures of an approval type for an escrow
+        fn reset_signatures(
+            &mut self,
+            escrow_id: u64,
+            approval_type: ApprovalType,
+        ) -> Result<(), Error> {
+            let config = self
+                .multi_sig_configs
+                .get(&escrow_id)
+                .ok_or(Error::EscrowNotFound)?;
+
+            for signer in config.signers.iter() {
+                self.signatures
+                    .remove(&(escrow_id, approval_type.clone(), *signer));
+            }
+            self.signature_counts
+                .remove(&(escrow_id, approval_type.clone()));
+
+            self.env().emit_event(SignaturesReset {
+                escrow_id,
+                approval_type,
+            });
+
+            Ok(())
+        }
+
         /// Check if signature threshold is met
-        fn check_signature_threshold(&self, escrow_id: u64, approval_type: ApprovalType) -> Result<bool, Error> {
-            let config = self.multi_sig_configs.get(&escrow_id).ok_or(Error::EscrowNotFound)?;
-            let count = self.signature_counts.get(&(escrow_id, approval_type)).unwrap_or(0);
+        fn check_signature_threshold(
+            &self,
+            escrow_id: u64,
+            approval_type: ApprovalType,
+        ) -> Result<bool, Error> {
+            let config = self
+                .multi_sig_configs
+                .get(&escrow_id)
+                .ok_or(Error::EscrowNotFound)?;
+            let count = self
+                .signature_counts
+                .get(&(escrow_id, approval_type))
+                .unwrap_or(0);
             Ok(count >= config.required_signatures)
         }
 
+        /// Mark a condition met on behalf of `verifier`; the caller persists the conditions
+        fn satisfy_condition(
+            &mut self,
+            escrow_id: u64,
+            condition: &mut Condition,
+            verifier: AccountId,
+        ) {
+            condition.met = true;
+            condition.verified_by = Some(verifier);
+            condition.verified_at = Some(self.env().block_timestamp());
+
+            self.env().emit_event(ConditionMet {
+                escrow_id,
+                condition_id: condition.id,
+                verified_by: verifier,
+            });
+        }
+
         /// Add audit entry
         fn add_audit_entry(&mut self, escrow_id: u64, actor: AccountId, action: String, details: String) {
             let entry = AuditEntry {
@@ -958,6 +1840,16 @@ mod propchain_escrow {
 
             let mut logs = self.audit_logs.get(&escrow_id).unwrap_or_default();
             logs.push(entry);
+
+            // Drop the oldest entries so the stored log stays bounded
+            let excess = logs.len().saturating_sub(self.max_audit_entries as usize);
+            if excess > 0 {
+                logs.drain(..excess);
+                let truncated = self.audit_truncated_counts.get(&escrow_id).unwrap_or(0);
+                self.audit_truncated_counts
+                    .insert(&escrow_id, &(truncated + excess as u64));
+            }
+
             self.audit_logs.insert(&escrow_id, &logs);
         }
     }
@@ -967,4 +1859,105 @@ mod propchain_escrow {
             Self::new(1_000_000_000_000) // Default threshold: 1 token
         }
     }
+
+    impl From<propchain_traits::ApprovalType> for ApprovalType {
+        fn from(approval_type: propchain_traits::ApprovalType) -> Self {
+            match approval_type {
+                propchain_traits::ApprovalType::Release => ApprovalType::Release,
+                propchain_traits::ApprovalType::Refund => ApprovalType::Refund,
+                propchain_traits::ApprovalType::EmergencyOverride => {
+                    ApprovalType::EmergencyOverride
+                }
+            }
+        }
+    }
+
+    impl propchain_traits::AdvancedEscrow for AdvancedEscrow {
+        type Error = Error;
+
+        fn create_escrow_advanced(
+            &mut self,
+            property_id: u64,
+            amount: u128,
+            buyer: AccountId,
+            seller: AccountId,
+            config: EscrowConfig,
+        ) -> Result<u64, Self::Error> {
+            self.create_escrow_advanced(property_id, amount, buyer, seller, config)
+        }
+
+        fn deposit_funds(&mut self, escrow_id: u64) -> Result<(), Self::Error> {
+            self.deposit_funds(escrow_id)
+        }
+
+        fn release_funds(&mut self, escrow_id: u64) -> Result<(), Self::Error> {
+            self.release_funds(escrow_id)
+        }
+
+        fn refund_funds(&mut self, escrow_id: u64) -> Result<(), Self::Error> {
+            self.refund_funds(escrow_id)
+        }
+
+        fn upload_document(
+            &mut self,
+            escrow_id: u64,
+            document_hash: Hash,
+            document_type: String,
+        ) -> Result<(), Self::Error> {
+            self.upload_document(escrow_id, document_hash, document_type)
+        }
+
+        fn verify_document(
+            &mut self,
+            escrow_id: u64,
+            document_hash: Hash,
+        ) -> Result<(), Self::Error> {
+            self.verify_document(escrow_id, document_hash)
+        }
+
+        fn add_condition(
+            &mut self,
+            escrow_id: u64,
+            description: String,
+            verifier: Option<AccountId>,
+        ) -> Result<u64, Self::Error> {
+            self.add_condition(escrow_id, description, verifier)
+        }
+
+        fn mark_condition_met(
+            &mut self,
+            escrow_id: u64,
+            condition_id: u64,
+        ) -> Result<(), Self::Error> {
+            self.mark_condition_met(escrow_id, condition_id)
+        }
+
+        fn sign_approval(
+            &mut self,
+            escrow_id: u64,
+            approval_type: propchain_traits::ApprovalType,
+        ) -> Result<(), Self::Error> {
+            self.sign_approval(escrow_id, approval_type.into())
+        }
+
+        fn raise_dispute(&mut self, escrow_id: u64, reason: String) -> Result<(), Self::Error> {
+            self.raise_dispute(escrow_id, reason)
+        }
+
+        fn resolve_dispute(
+            &mut self,
+            escrow_id: u64,
+            resolution: String,
+        ) -> Result<(), Self::Error> {
+            self.resolve_dispute(escrow_id, resolution)
+        }
+
+        fn emergency_override(
+            &mut self,
+            escrow_id: u64,
+            release_to_seller: bool,
+        ) -> Result<(), Self::Error> {
+            self.emergency_override(escrow_id, release_to_seller)
+        }
+    }
 }
@@ -1,4 +1,5 @@
 use crate::propchain_escrow::*;
+use crate::EscrowConfig;
 use ink::env::test;
 use ink::primitives::{AccountId, Hash};
 
@@ -31,16 +32,14 @@ pub mod escrow_tests {
         set_caller(accounts.alice);
 
         let mut contract = AdvancedEscrow::new(1_000_000);
-        
+
         let participants = vec![accounts.alice, accounts.bob, accounts.charlie];
         let result = contract.create_escrow_advanced(
-            1, // property_id
-            1_000_000, // amount
+            1,              // property_id
+            1_000_000,      // amount
             accounts.alice, // buyer
-            accounts.bob, // seller
-            participants,
-            2, // required_signatures
-            None, // no time lock
+            accounts.bob,   // seller
+            EscrowConfig::new(participants, 2),
         );
 
         assert!(result.is_ok());
@@ -55,6 +54,48 @@ pub mod escrow_tests {
         assert_eq!(escrow.status, EscrowStatus::Created);
     }
 
+    #[ink::test]
+    fn test_create_escrow_emits_awaiting_funding() {
+        let accounts = default_accounts();
+        set_caller(accounts.bob);
+
+        let mut contract = AdvancedEscrow::new(1_000_000);
+
+        let participants = vec![accounts.alice, accounts.bob];
+        let escrow_id = contract
+            .create_escrow_advanced(
+                1,
+                1_000_000,
+                accounts.alice,
+                accounts.bob,
+                EscrowConfig {
+                    release_time_lock: Some(5_000),
+                    ..EscrowConfig::new(participants, 2)
+                },
+            )
+            .unwrap();
+
+        // AwaitingFunding is emitted right after EscrowCreated
+        let events = test::recorded_events().collect::<Vec<_>>();
+        let awaiting_funding = events.last().unwrap();
+
+        // Buyer is an indexed topic so wallets can filter on it
+        assert!(awaiting_funding
+            .topics
+            .iter()
+            .any(|topic| topic.as_slice() == AsRef::<[u8]>::as_ref(&accounts.alice)));
+
+        let (event_escrow_id, buyer, amount, deadline) =
+            <(u64, AccountId, u128, Option<u64>) as scale::Decode>::decode(
+                &mut &awaiting_funding.data[..],
+            )
+            .unwrap();
+        assert_eq!(event_escrow_id, escrow_id);
+        assert_eq!(buyer, accounts.alice);
+        assert_eq!(amount, 1_000_000);
+        assert_eq!(deadline, Some(5_000));
+    }
+
     #[ink::test]
     fn test_create_escrow_invalid_config() {
         let accounts = default_accounts();
@@ -69,9 +110,7 @@ pub mod escrow_tests {
             1_000_000,
             accounts.alice,
             accounts.bob,
-            participants,
-            3, // More than participants
-            None,
+            EscrowConfig::new(participants, 3), // More than participants
         );
 
         assert_eq!(result, Err(Error::InvalidConfiguration));
@@ -84,17 +123,17 @@ pub mod escrow_tests {
         set_balance(accounts.alice, 2_000_000);
 
         let mut contract = AdvancedEscrow::new(1_000_000);
-        
+
         let participants = vec![accounts.alice, accounts.bob];
-        let escrow_id = contract.create_escrow_advanced(
-            1,
-            1_000_000,
-            accounts.alice,
-            accounts.bob,
-            participants,
-            2,
-            None,
-        ).unwrap();
+        let escrow_id = contract
+            .create_escrow_advanced(
+                1,
+                1_000_000,
+                accounts.alice,
+                accounts.bob,
+                EscrowConfig::new(participants, 2),
+            )
+            .unwrap();
 
         // Deposit funds
         test::set_value_transferred::<ink::env::DefaultEnvironment>(1_000_000);
@@ -106,30 +145,126 @@ pub mod escrow_tests {
         assert_eq!(escrow.status, EscrowStatus::Active);
     }
 
+    #[ink::test]
+    fn test_deposit_funds_underfunded() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+
+        let mut contract = AdvancedEscrow::new(1_000_000);
+
+        let participants = vec![accounts.alice, accounts.bob];
+        let escrow_id = contract
+            .create_escrow_advanced(
+                1,
+                1_000_000,
+                accounts.alice,
+                accounts.bob,
+                EscrowConfig::new(participants, 2),
+            )
+            .unwrap();
+
+        test::set_value_transferred::<ink::env::DefaultEnvironment>(400_000);
+        assert!(contract.deposit_funds(escrow_id).is_ok());
+
+        let escrow = contract.get_escrow(escrow_id).unwrap();
+        assert_eq!(escrow.deposited_amount, 400_000);
+        assert_eq!(escrow.status, EscrowStatus::Funded);
+    }
+
+    #[ink::test]
+    fn test_deposit_funds_overfunded_rejected() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+
+        let mut contract = AdvancedEscrow::new(1_000_000);
+        assert!(!contract.get_refund_overpayment());
+
+        let participants = vec![accounts.alice, accounts.bob];
+        let escrow_id = contract
+            .create_escrow_advanced(
+                1,
+                1_000_000,
+                accounts.alice,
+                accounts.bob,
+                EscrowConfig::new(participants, 2),
+            )
+            .unwrap();
+
+        test::set_value_transferred::<ink::env::DefaultEnvironment>(600_000);
+        assert!(contract.deposit_funds(escrow_id).is_ok());
+
+        // A second deposit pushing the total above the amount is rejected
+        test::set_value_transferred::<ink::env::DefaultEnvironment>(600_000);
+        assert_eq!(contract.deposit_funds(escrow_id), Err(Error::Overfunded));
+
+        let escrow = contract.get_escrow(escrow_id).unwrap();
+        assert_eq!(escrow.deposited_amount, 600_000);
+        assert_eq!(escrow.status, EscrowStatus::Funded);
+    }
+
+    #[ink::test]
+    fn test_deposit_funds_overfunded_refunds_excess() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+
+        let mut contract = AdvancedEscrow::new(1_000_000);
+        assert!(contract.set_refund_overpayment(true).is_ok());
+
+        let participants = vec![accounts.alice, accounts.bob];
+        let escrow_id = contract
+            .create_escrow_advanced(
+                1,
+                1_000_000,
+                accounts.bob,
+                accounts.charlie,
+                EscrowConfig::new(participants, 2),
+            )
+            .unwrap();
+
+        set_caller(accounts.bob);
+        test::set_value_transferred::<ink::env::DefaultEnvironment>(1_250_000);
+        assert!(contract.deposit_funds(escrow_id).is_ok());
+
+        let escrow = contract.get_escrow(escrow_id).unwrap();
+        assert_eq!(escrow.deposited_amount, 1_000_000);
+        assert_eq!(escrow.status, EscrowStatus::Active);
+        assert_eq!(contract.get_pending_withdrawal(accounts.bob), 250_000);
+    }
+
+    #[ink::test]
+    fn test_set_refund_overpayment_unauthorized() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+
+        let mut contract = AdvancedEscrow::new(1_000_000);
+
+        set_caller(accounts.bob);
+        assert_eq!(
+            contract.set_refund_overpayment(true),
+            Err(Error::Unauthorized)
+        );
+    }
+
     #[ink::test]
     fn test_upload_document() {
         let accounts = default_accounts();
         set_caller(accounts.alice);
 
         let mut contract = AdvancedEscrow::new(1_000_000);
-        
+
         let participants = vec![accounts.alice, accounts.bob];
-        let escrow_id = contract.create_escrow_advanced(
-            1,
-            1_000_000,
-            accounts.alice,
-            accounts.bob,
-            participants,
-            2,
-            None,
-        ).unwrap();
+        let escrow_id = contract
+            .create_escrow_advanced(
+                1,
+                1_000_000,
+                accounts.alice,
+                accounts.bob,
+                EscrowConfig::new(participants, 2),
+            )
+            .unwrap();
 
         let doc_hash = Hash::from([1u8; 32]);
-        let result = contract.upload_document(
-            escrow_id,
-            doc_hash,
-            "Title Deed".to_string(),
-        );
+        let result = contract.upload_document(escrow_id, doc_hash, "Title Deed".to_string());
 
         assert!(result.is_ok());
 
@@ -146,20 +281,22 @@ pub mod escrow_tests {
         set_caller(accounts.alice);
 
         let mut contract = AdvancedEscrow::new(1_000_000);
-        
+
         let participants = vec![accounts.alice, accounts.bob];
-        let escrow_id = contract.create_escrow_advanced(
-            1,
-            1_000_000,
-            accounts.alice,
-            accounts.bob,
-            participants,
-            2,
-            None,
-        ).unwrap();
+        let escrow_id = contract
+            .create_escrow_advanced(
+                1,
+                1_000_000,
+                accounts.alice,
+                accounts.bob,
+                EscrowConfig::new(participants, 2),
+            )
+            .unwrap();
 
         let doc_hash = Hash::from([1u8; 32]);
-        contract.upload_document(escrow_id, doc_hash, "Title Deed".to_string()).unwrap();
+        contract
+            .upload_document(escrow_id, doc_hash, "Title Deed".to_string())
+            .unwrap();
 
         // Verify document
         let result = contract.verify_document(escrow_id, doc_hash);
@@ -175,22 +312,20 @@ pub mod escrow_tests {
         set_caller(accounts.alice);
 
         let mut contract = AdvancedEscrow::new(1_000_000);
-        
-        let participants = vec![accounts.alice, accounts.bob];
-        let escrow_id = contract.create_escrow_advanced(
-            1,
-            1_000_000,
-            accounts.alice,
-            accounts.bob,
-            participants,
-            2,
-            None,
-        ).unwrap();
 
-        let result = contract.add_condition(
-            escrow_id,
-            "Property inspection completed".to_string(),
-        );
+        let participants = vec![accounts.alice, accounts.bob];
+        let escrow_id = contract
+            .create_escrow_advanced(
+                1,
+                1_000_000,
+                accounts.alice,
+                accounts.bob,
+                EscrowConfig::new(participants, 2),
+            )
+            .unwrap();
+
+        let result =
+            contract.add_condition(escrow_id, "Property inspection completed".to_string(), None);
 
         assert!(result.is_ok());
         let condition_id = result.unwrap();
@@ -208,22 +343,21 @@ pub mod escrow_tests {
         set_caller(accounts.alice);
 
         let mut contract = AdvancedEscrow::new(1_000_000);
-        
-        let participants = vec![accounts.alice, accounts.bob];
-        let escrow_id = contract.create_escrow_advanced(
-            1,
-            1_000_000,
-            accounts.alice,
-            accounts.bob,
-            participants,
-            2,
-            None,
-        ).unwrap();
 
-        let condition_id = contract.add_condition(
-            escrow_id,
-            "Property inspection completed".to_string(),
-        ).unwrap();
+        let participants = vec![accounts.alice, accounts.bob];
+        let escrow_id = contract
+            .create_escrow_advanced(
+                1,
+                1_000_000,
+                accounts.alice,
+                accounts.bob,
+                EscrowConfig::new(participants, 2),
+            )
+            .unwrap();
+
+        let condition_id = contract
+            .add_condition(escrow_id, "Property inspection completed".to_string(), None)
+            .unwrap();
 
         let result = contract.mark_condition_met(escrow_id, condition_id);
         assert!(result.is_ok());
@@ -239,17 +373,17 @@ pub mod escrow_tests {
         set_caller(accounts.alice);
 
         let mut contract = AdvancedEscrow::new(1_000_000);
-        
+
         let participants = vec![accounts.alice, accounts.bob];
-        let escrow_id = contract.create_escrow_advanced(
-            1,
-            1_000_000,
-            accounts.alice,
-            accounts.bob,
-            participants,
-            2,
-            None,
-        ).unwrap();
+        let escrow_id = contract
+            .create_escrow_advanced(
+                1,
+                1_000_000,
+                accounts.alice,
+                accounts.bob,
+                EscrowConfig::new(participants, 2),
+            )
+            .unwrap();
 
         // Alice signs
         let result = contract.sign_approval(escrow_id, ApprovalType::Release);
@@ -273,19 +407,21 @@ pub mod escrow_tests {
         set_caller(accounts.alice);
 
         let mut contract = AdvancedEscrow::new(1_000_000);
-        
-        let participants = vec![accounts.alice, accounts.bob];
-        let escrow_id = contract.create_escrow_advanced(
-            1,
-            1_000_000,
-            accounts.alice,
-            accounts.bob,
-            participants,
-            2,
-            None,
-        ).unwrap();
 
-        contract.sign_approval(escrow_id, ApprovalType::Release).unwrap();
+        let participants = vec![accounts.alice, accounts.bob];
+        let escrow_id = contract
+            .create_escrow_advanced(
+                1,
+                1_000_000,
+                accounts.alice,
+                accounts.bob,
+                EscrowConfig::new(participants, 2),
+            )
+            .unwrap();
+
+        contract
+            .sign_approval(escrow_id, ApprovalType::Release)
+            .unwrap();
 
         // Try to sign again
         let result = contract.sign_approval(escrow_id, ApprovalType::Release);
@@ -298,22 +434,20 @@ pub mod escrow_tests {
         set_caller(accounts.alice);
 
         let mut contract = AdvancedEscrow::new(1_000_000);
-        
-        let participants = vec![accounts.alice, accounts.bob];
-        let escrow_id = contract.create_escrow_advanced(
-            1,
-            1_000_000,
-            accounts.alice,
-            accounts.bob,
-            participants,
-            2,
-            None,
-        ).unwrap();
 
-        let result = contract.raise_dispute(
-            escrow_id,
-            "Property condition not as described".to_string(),
-        );
+        let participants = vec![accounts.alice, accounts.bob];
+        let escrow_id = contract
+            .create_escrow_advanced(
+                1,
+                1_000_000,
+                accounts.alice,
+                accounts.bob,
+                EscrowConfig::new(participants, 2),
+            )
+            .unwrap();
+
+        let result =
+            contract.raise_dispute(escrow_id, "Property condition not as described".to_string());
 
         assert!(result.is_ok());
 
@@ -333,32 +467,34 @@ pub mod escrow_tests {
 
         let mut contract = AdvancedEscrow::new(1_000_000);
         let admin = contract.get_admin();
-        
-        let participants = vec![accounts.alice, accounts.bob];
-        let escrow_id = contract.create_escrow_advanced(
-            1,
-            1_000_000,
-            accounts.alice,
-            accounts.bob,
-            participants,
-            2,
-            None,
-        ).unwrap();
 
-        contract.raise_dispute(escrow_id, "Issue".to_string()).unwrap();
+        let participants = vec![accounts.alice, accounts.bob];
+        let escrow_id = contract
+            .create_escrow_advanced(
+                1,
+                1_000_000,
+                accounts.alice,
+                accounts.bob,
+                EscrowConfig::new(participants, 2),
+            )
+            .unwrap();
+
+        contract
+            .raise_dispute(escrow_id, "Issue".to_string())
+            .unwrap();
 
         // Admin resolves dispute
         set_caller(admin);
-        let result = contract.resolve_dispute(
-            escrow_id,
-            "Resolved in favor of buyer".to_string(),
-        );
+        let result = contract.resolve_dispute(escrow_id, "Resolved in favor of buyer".to_string());
 
         assert!(result.is_ok());
 
         let dispute = contract.get_dispute(escrow_id).unwrap();
         assert_eq!(dispute.resolved, true);
-        assert_eq!(dispute.resolution, Some("Resolved in favor of buyer".to_string()));
+        assert_eq!(
+            dispute.resolution,
+            Some("Resolved in favor of buyer".to_string())
+        );
 
         let escrow = contract.get_escrow(escrow_id).unwrap();
         assert_eq!(escrow.status, EscrowStatus::Active);
@@ -370,19 +506,21 @@ pub mod escrow_tests {
         set_caller(accounts.alice);
 
         let mut contract = AdvancedEscrow::new(1_000_000);
-        
-        let participants = vec![accounts.alice, accounts.bob];
-        let escrow_id = contract.create_escrow_advanced(
-            1,
-            1_000_000,
-            accounts.alice,
-            accounts.bob,
-            participants,
-            2,
-            None,
-        ).unwrap();
 
-        contract.raise_dispute(escrow_id, "Issue".to_string()).unwrap();
+        let participants = vec![accounts.alice, accounts.bob];
+        let escrow_id = contract
+            .create_escrow_advanced(
+                1,
+                1_000_000,
+                accounts.alice,
+                accounts.bob,
+                EscrowConfig::new(participants, 2),
+            )
+            .unwrap();
+
+        contract
+            .raise_dispute(escrow_id, "Issue".to_string())
+            .unwrap();
 
         // Non-admin tries to resolve
         set_caller(accounts.bob);
@@ -390,31 +528,119 @@ pub mod escrow_tests {
         assert_eq!(result, Err(Error::Unauthorized));
     }
 
+    #[ink::test]
+    fn test_arbitrator_resolves_dispute_but_cannot_override() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+
+        let mut contract = AdvancedEscrow::new(1_000_000);
+        assert!(contract.add_arbitrator(accounts.eve).is_ok());
+        assert!(contract.is_arbitrator(accounts.eve));
+
+        let participants = vec![accounts.alice, accounts.bob];
+        let escrow_id = contract
+            .create_escrow_advanced(
+                1,
+                1_000_000,
+                accounts.alice,
+                accounts.bob,
+                EscrowConfig::new(participants, 2),
+            )
+            .unwrap();
+
+        contract
+            .raise_dispute(escrow_id, "Issue".to_string())
+            .unwrap();
+
+        // Non-admin arbitrator resolves the dispute
+        set_caller(accounts.eve);
+        assert!(contract
+            .resolve_dispute(escrow_id, "Split agreed".to_string())
+            .is_ok());
+        assert!(contract.get_dispute(escrow_id).unwrap().resolved);
+
+        // Resolving arbitrator is a topic on DisputeResolved
+        let events = test::recorded_events().collect::<Vec<_>>();
+        let resolved = events.last().unwrap();
+        assert!(resolved
+            .topics
+            .iter()
+            .any(|topic| topic.as_slice() == AsRef::<[u8]>::as_ref(&accounts.eve)));
+
+        // Emergency override stays admin-only
+        assert_eq!(
+            contract.emergency_override(escrow_id, true),
+            Err(Error::Unauthorized)
+        );
+    }
+
+    #[ink::test]
+    fn test_removed_arbitrator_cannot_resolve() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+
+        let mut contract = AdvancedEscrow::new(1_000_000);
+        assert!(contract.add_arbitrator(accounts.eve).is_ok());
+        assert!(contract.remove_arbitrator(accounts.eve).is_ok());
+        assert!(!contract.is_arbitrator(accounts.eve));
+
+        let participants = vec![accounts.alice, accounts.bob];
+        let escrow_id = contract
+            .create_escrow_advanced(
+                1,
+                1_000_000,
+                accounts.alice,
+                accounts.bob,
+                EscrowConfig::new(participants, 2),
+            )
+            .unwrap();
+
+        contract
+            .raise_dispute(escrow_id, "Issue".to_string())
+            .unwrap();
+
+        set_caller(accounts.eve);
+        assert_eq!(
+            contract.resolve_dispute(escrow_id, "Resolution".to_string()),
+            Err(Error::Unauthorized)
+        );
+
+        // Only admin manages arbitrators
+        assert_eq!(
+            contract.add_arbitrator(accounts.eve),
+            Err(Error::Unauthorized)
+        );
+    }
+
     #[ink::test]
     fn test_check_all_conditions_met() {
         let accounts = default_accounts();
         set_caller(accounts.alice);
 
         let mut contract = AdvancedEscrow::new(1_000_000);
-        
+
         let participants = vec![accounts.alice, accounts.bob];
-        let escrow_id = contract.create_escrow_advanced(
-            1,
-            1_000_000,
-            accounts.alice,
-            accounts.bob,
-            participants,
-            2,
-            None,
-        ).unwrap();
+        let escrow_id = contract
+            .create_escrow_advanced(
+                1,
+                1_000_000,
+                accounts.alice,
+                accounts.bob,
+                EscrowConfig::new(participants, 2),
+            )
+            .unwrap();
 
         // No conditions - should return true
         let result = contract.check_all_conditions_met(escrow_id);
         assert_eq!(result, Ok(true));
 
         // Add conditions
-        let cond1 = contract.add_condition(escrow_id, "Condition 1".to_string()).unwrap();
-        let cond2 = contract.add_condition(escrow_id, "Condition 2".to_string()).unwrap();
+        let cond1 = contract
+            .add_condition(escrow_id, "Condition 1".to_string(), None)
+            .unwrap();
+        let cond2 = contract
+            .add_condition(escrow_id, "Condition 2".to_string(), None)
+            .unwrap();
 
         // Not all met
         let result = contract.check_all_conditions_met(escrow_id);
@@ -437,27 +663,31 @@ pub mod escrow_tests {
         set_caller(accounts.alice);
 
         let mut contract = AdvancedEscrow::new(1_000_000);
-        
+
         let participants = vec![accounts.alice, accounts.bob];
-        let escrow_id = contract.create_escrow_advanced(
-            1,
-            1_000_000,
-            accounts.alice,
-            accounts.bob,
-            participants,
-            2,
-            None,
-        ).unwrap();
+        let escrow_id = contract
+            .create_escrow_advanced(
+                1,
+                1_000_000,
+                accounts.alice,
+                accounts.bob,
+                EscrowConfig::new(participants, 2),
+            )
+            .unwrap();
 
         // Perform some actions
-        contract.add_condition(escrow_id, "Test condition".to_string()).unwrap();
+        contract
+            .add_condition(escrow_id, "Test condition".to_string(), None)
+            .unwrap();
         let doc_hash = Hash::from([1u8; 32]);
-        contract.upload_document(escrow_id, doc_hash, "Test doc".to_string()).unwrap();
+        contract
+            .upload_document(escrow_id, doc_hash, "Test doc".to_string())
+            .unwrap();
 
         // Check audit trail
         let audit_trail = contract.get_audit_trail(escrow_id);
         assert!(audit_trail.len() >= 3); // Created + Condition + Document
-        
+
         // Verify audit entries contain expected actions
         let actions: Vec<String> = audit_trail.iter().map(|e| e.action.clone()).collect();
         assert!(actions.contains(&"EscrowCreated".to_string()));
@@ -465,6 +695,47 @@ pub mod escrow_tests {
         assert!(actions.contains(&"DocumentUploaded".to_string()));
     }
 
+    #[ink::test]
+    fn test_audit_trail_is_capped() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+
+        let mut contract = AdvancedEscrow::new(1_000_000);
+        assert_eq!(contract.get_max_audit_entries(), 100);
+
+        set_caller(accounts.bob);
+        assert_eq!(contract.set_max_audit_entries(3), Err(Error::Unauthorized));
+        set_caller(accounts.alice);
+        assert_eq!(
+            contract.set_max_audit_entries(0),
+            Err(Error::InvalidConfiguration)
+        );
+        contract.set_max_audit_entries(3).unwrap();
+
+        let participants = vec![accounts.alice, accounts.bob];
+        let escrow_id = contract
+            .create_escrow_advanced(
+                1,
+                1_000_000,
+                accounts.alice,
+                accounts.bob,
+                EscrowConfig::new(participants, 2),
+            )
+            .unwrap();
+
+        for i in 0..5 {
+            contract
+                .add_condition(escrow_id, format!("Condition {}", i), None)
+                .unwrap();
+        }
+
+        // Created + 5 conditions, of which only the newest 3 are kept
+        let audit_trail = contract.get_audit_trail(escrow_id);
+        assert_eq!(audit_trail.len(), 3);
+        assert!(audit_trail.iter().all(|e| e.action == "ConditionAdded"));
+        assert_eq!(contract.get_audit_truncated_count(escrow_id), 3);
+    }
+
     #[ink::test]
     fn test_set_admin() {
         let accounts = default_accounts();
@@ -500,20 +771,1224 @@ pub mod escrow_tests {
         set_caller(accounts.alice);
 
         let mut contract = AdvancedEscrow::new(1_000_000);
-        
+
         let participants = vec![accounts.alice, accounts.bob, accounts.charlie];
-        let escrow_id = contract.create_escrow_advanced(
+        let escrow_id = contract
+            .create_escrow_advanced(
+                1,
+                1_000_000,
+                accounts.alice,
+                accounts.bob,
+                EscrowConfig::new(participants.clone(), 2),
+            )
+            .unwrap();
+
+        let config = contract.get_multi_sig_config(escrow_id).unwrap();
+        assert_eq!(config.required_signatures, 2);
+        assert_eq!(config.signers, participants);
+    }
+
+    #[ink::test]
+    fn test_release_partial_milestones() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+
+        let mut contract = AdvancedEscrow::new(1_000_000);
+
+        let participants = vec![accounts.alice, accounts.bob];
+        let escrow_id = contract
+            .create_escrow_advanced(
+                1,
+                1_000_000,
+                accounts.alice,
+                accounts.bob,
+                EscrowConfig::new(participants, 2),
+            )
+            .unwrap();
+
+        test::set_value_transferred::<ink::env::DefaultEnvironment>(1_000_000);
+        contract.deposit_funds(escrow_id).unwrap();
+
+        contract
+            .sign_approval(escrow_id, ApprovalType::Release)
+            .unwrap();
+        set_caller(accounts.bob);
+        contract
+            .sign_approval(escrow_id, ApprovalType::Release)
+            .unwrap();
+
+        // First milestone keeps the escrow active
+        assert!(contract.release_partial(escrow_id, 400_000).is_ok());
+        let escrow = contract.get_escrow(escrow_id).unwrap();
+        assert_eq!(escrow.deposited_amount, 600_000);
+        assert_eq!(escrow.status, EscrowStatus::Active);
+
+        // Cannot release more than what remains
+        assert_eq!(
+            contract.release_partial(escrow_id, 600_001),
+            Err(Error::InsufficientFunds)
+        );
+
+        // Final milestone exhausts the balance
+        assert!(contract.release_partial(escrow_id, 600_000).is_ok());
+        let escrow = contract.get_escrow(escrow_id).unwrap();
+        assert_eq!(escrow.deposited_amount, 0);
+        assert_eq!(escrow.status, EscrowStatus::Released);
+        assert_eq!(contract.get_pending_withdrawal(accounts.bob), 1_000_000);
+    }
+
+    #[ink::test]
+    fn test_release_partial_requires_signatures() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+
+        let mut contract = AdvancedEscrow::new(1_000_000);
+
+        let participants = vec![accounts.alice, accounts.bob];
+        let escrow_id = contract
+            .create_escrow_advanced(
+                1,
+                1_000_000,
+                accounts.alice,
+                accounts.bob,
+                EscrowConfig::new(participants, 2),
+            )
+            .unwrap();
+
+        test::set_value_transferred::<ink::env::DefaultEnvironment>(1_000_000);
+        contract.deposit_funds(escrow_id).unwrap();
+
+        assert_eq!(
+            contract.release_partial(escrow_id, 100_000),
+            Err(Error::SignatureThresholdNotMet)
+        );
+    }
+
+    #[ink::test]
+    fn test_release_funds_splits_fee_with_rounding() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+
+        let mut contract = AdvancedEscrow::new(1_000_000);
+
+        let participants = vec![accounts.alice, accounts.bob];
+        let escrow_id = contract
+            .create_escrow_advanced(
+                1,
+                999_999,
+                accounts.alice,
+                accounts.bob,
+                EscrowConfig {
+                    fee_bps: 250, // 2.5%
+                    fee_recipient: accounts.charlie,
+                    ..EscrowConfig::new(participants, 2)
+                },
+            )
+            .unwrap();
+
+        test::set_value_transferred::<ink::env::DefaultEnvironment>(999_999);
+        contract.deposit_funds(escrow_id).unwrap();
+
+        contract
+            .sign_approval(escrow_id, ApprovalType::Release)
+            .unwrap();
+        set_caller(accounts.bob);
+        contract
+            .sign_approval(escrow_id, ApprovalType::Release)
+            .unwrap();
+
+        assert!(contract.release_funds(escrow_id).is_ok());
+
+        // 999_999 * 250 / 10_000 = 24_999.975, rounded down in the seller's favour
+        assert_eq!(contract.get_pending_withdrawal(accounts.charlie), 24_999);
+        assert_eq!(contract.get_pending_withdrawal(accounts.bob), 975_000);
+    }
+
+    #[ink::test]
+    fn test_release_funds_zero_fee() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+
+        let mut contract = AdvancedEscrow::new(1_000_000);
+
+        let participants = vec![accounts.alice, accounts.bob];
+        let escrow_id = contract
+            .create_escrow_advanced(
+                1,
+                1_000_000,
+                accounts.alice,
+                accounts.bob,
+                EscrowConfig::new(participants, 2),
+            )
+            .unwrap();
+
+        test::set_value_transferred::<ink::env::DefaultEnvironment>(1_000_000);
+        contract.deposit_funds(escrow_id).unwrap();
+
+        contract
+            .sign_approval(escrow_id, ApprovalType::Release)
+            .unwrap();
+        set_caller(accounts.bob);
+        contract
+            .sign_approval(escrow_id, ApprovalType::Release)
+            .unwrap();
+
+        assert!(contract.release_funds(escrow_id).is_ok());
+
+        assert_eq!(contract.get_pending_withdrawal(accounts.charlie), 0);
+        assert_eq!(contract.get_pending_withdrawal(accounts.bob), 1_000_000);
+    }
+
+    #[ink::test]
+    fn test_create_escrow_invalid_fee() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+
+        let mut contract = AdvancedEscrow::new(1_000_000);
+
+        let participants = vec![accounts.alice, accounts.bob];
+        let result = contract.create_escrow_advanced(
             1,
             1_000_000,
             accounts.alice,
             accounts.bob,
-            participants.clone(),
-            2,
-            None,
-        ).unwrap();
+            EscrowConfig {
+                fee_bps: 10_001,
+                fee_recipient: accounts.charlie,
+                ..EscrowConfig::new(participants, 2)
+            },
+        );
 
-        let config = contract.get_multi_sig_config(escrow_id).unwrap();
-        assert_eq!(config.required_signatures, 2);
-        assert_eq!(config.signers, participants);
+        assert_eq!(result, Err(Error::InvalidConfiguration));
+    }
+
+    #[ink::test]
+    fn test_expire_escrow_refunds_buyer_after_deadline() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+
+        let mut contract = AdvancedEscrow::new(1_000_000);
+
+        let participants = vec![accounts.alice, accounts.bob, accounts.charlie];
+        let escrow_id = contract
+            .create_escrow_advanced(
+                1,
+                1_000_000,
+                accounts.bob,
+                accounts.charlie,
+                EscrowConfig {
+                    condition_deadline: Some(10_000),
+                    ..EscrowConfig::new(participants, 2)
+                },
+            )
+            .unwrap();
+
+        set_caller(accounts.bob);
+        test::set_value_transferred::<ink::env::DefaultEnvironment>(1_000_000);
+        contract.deposit_funds(escrow_id).unwrap();
+        contract
+            .add_condition(escrow_id, "Title transfer registered".to_string(), None)
+            .unwrap();
+
+        // Deadline not reached yet
+        assert_eq!(
+            contract.expire_escrow(escrow_id),
+            Err(Error::TimeLockActive)
+        );
+
+        // Advance time past the deadline; buyer refunds unilaterally
+        test::set_block_timestamp::<ink::env::DefaultEnvironment>(10_001);
+        assert!(contract.expire_escrow(escrow_id).is_ok());
+
+        let escrow = contract.get_escrow(escrow_id).unwrap();
+        assert_eq!(escrow.status, EscrowStatus::Refunded);
+        assert_eq!(contract.get_pending_withdrawal(accounts.bob), 1_000_000);
+    }
+
+    #[ink::test]
+    fn test_expire_escrow_requires_unmet_conditions() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+
+        let mut contract = AdvancedEscrow::new(1_000_000);
+
+        let participants = vec![accounts.alice, accounts.bob];
+        let escrow_id = contract
+            .create_escrow_advanced(
+                1,
+                1_000_000,
+                accounts.alice,
+                accounts.bob,
+                EscrowConfig {
+                    condition_deadline: Some(10_000),
+                    ..EscrowConfig::new(participants.clone(), 2)
+                },
+            )
+            .unwrap();
+
+        test::set_value_transferred::<ink::env::DefaultEnvironment>(1_000_000);
+        contract.deposit_funds(escrow_id).unwrap();
+        let condition_id = contract
+            .add_condition(escrow_id, "Inspection passed".to_string(), None)
+            .unwrap();
+        contract
+            .mark_condition_met(escrow_id, condition_id)
+            .unwrap();
+
+        test::set_block_timestamp::<ink::env::DefaultEnvironment>(10_001);
+        assert_eq!(contract.expire_escrow(escrow_id), Err(Error::InvalidStatus));
+
+        // Escrows without a deadline never expire
+        let no_deadline = contract
+            .create_escrow_advanced(
+                2,
+                1_000_000,
+                accounts.alice,
+                accounts.bob,
+                EscrowConfig::new(participants, 2),
+            )
+            .unwrap();
+        test::set_value_transferred::<ink::env::DefaultEnvironment>(1_000_000);
+        contract.deposit_funds(no_deadline).unwrap();
+        assert_eq!(
+            contract.expire_escrow(no_deadline),
+            Err(Error::InvalidConfiguration)
+        );
+    }
+
+    #[ink::test]
+    fn test_expire_escrow_without_conditions_waits_for_dispute() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+
+        let mut contract = AdvancedEscrow::new(1_000_000);
+
+        let escrow_id = contract
+            .create_escrow_advanced(
+                1,
+                1_000_000,
+                accounts.bob,
+                accounts.charlie,
+                EscrowConfig {
+                    condition_deadline: Some(10_000),
+                    ..EscrowConfig::new(vec![accounts.alice, accounts.bob], 2)
+                },
+            )
+            .unwrap();
+
+        set_caller(accounts.bob);
+        test::set_value_transferred::<ink::env::DefaultEnvironment>(1_000_000);
+        contract.deposit_funds(escrow_id).unwrap();
+        contract
+            .raise_dispute(escrow_id, "Keys not handed over".to_string())
+            .unwrap();
+
+        // The deadline does not override an open dispute
+        test::set_block_timestamp::<ink::env::DefaultEnvironment>(10_001);
+        assert_eq!(contract.expire_escrow(escrow_id), Err(Error::DisputeActive));
+
+        set_caller(accounts.alice);
+        contract
+            .resolve_dispute(escrow_id, "Sale may proceed".to_string())
+            .unwrap();
+
+        // With no conditions to meet, the escrow expires once the deadline has passed
+        assert!(contract.expire_escrow(escrow_id).is_ok());
+        assert_eq!(
+            contract.get_escrow(escrow_id).unwrap().status,
+            EscrowStatus::Refunded
+        );
+        assert_eq!(contract.get_pending_withdrawal(accounts.bob), 1_000_000);
+    }
+
+    #[ink::test]
+    fn test_withdraw_pulls_released_funds() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        set_balance(test::callee::<ink::env::DefaultEnvironment>(), 1_000_000);
+        set_balance(accounts.bob, 0);
+
+        let mut contract = AdvancedEscrow::new(1_000_000);
+
+        let participants = vec![accounts.alice, accounts.bob];
+        let escrow_id = contract
+            .create_escrow_advanced(
+                1,
+                1_000_000,
+                accounts.alice,
+                accounts.bob,
+                EscrowConfig::new(participants, 2),
+            )
+            .unwrap();
+
+        test::set_value_transferred::<ink::env::DefaultEnvironment>(1_000_000);
+        contract.deposit_funds(escrow_id).unwrap();
+
+        contract
+            .sign_approval(escrow_id, ApprovalType::Release)
+            .unwrap();
+        set_caller(accounts.bob);
+        contract
+            .sign_approval(escrow_id, ApprovalType::Release)
+            .unwrap();
+        assert!(contract.release_funds(escrow_id).is_ok());
+
+        // Release only credits the seller; nothing is pushed
+        assert_eq!(contract.get_pending_withdrawal(accounts.bob), 1_000_000);
+        assert_eq!(
+            test::get_account_balance::<ink::env::DefaultEnvironment>(accounts.bob),
+            Ok(0)
+        );
+
+        // Seller pulls the funds
+        assert_eq!(contract.withdraw(), Ok(1_000_000));
+        assert_eq!(contract.get_pending_withdrawal(accounts.bob), 0);
+        assert_eq!(
+            test::get_account_balance::<ink::env::DefaultEnvironment>(accounts.bob),
+            Ok(1_000_000)
+        );
+
+        // A second withdraw of an empty balance returns zero
+        assert_eq!(contract.withdraw(), Ok(0));
+    }
+
+    #[ink::test]
+    fn test_release_to_recipients_three_way_split() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+
+        let mut contract = AdvancedEscrow::new(1_000_000);
+
+        let participants = vec![accounts.alice, accounts.bob];
+        let escrow_id = contract
+            .create_escrow_advanced(
+                1,
+                1_000_000,
+                accounts.alice,
+                accounts.bob,
+                EscrowConfig::new(participants, 2),
+            )
+            .unwrap();
+
+        test::set_value_transferred::<ink::env::DefaultEnvironment>(1_000_000);
+        contract.deposit_funds(escrow_id).unwrap();
+
+        contract
+            .sign_approval(escrow_id, ApprovalType::Release)
+            .unwrap();
+        set_caller(accounts.bob);
+        contract
+            .sign_approval(escrow_id, ApprovalType::Release)
+            .unwrap();
+
+        // Seller, agent and tax authority
+        let recipients = vec![
+            (accounts.bob, 900_000),
+            (accounts.charlie, 60_000),
+            (accounts.django, 40_000),
+        ];
+        assert!(contract
+            .release_to_recipients(escrow_id, recipients)
+            .is_ok());
+
+        assert_eq!(contract.get_pending_withdrawal(accounts.bob), 900_000);
+        assert_eq!(contract.get_pending_withdrawal(accounts.charlie), 60_000);
+        assert_eq!(contract.get_pending_withdrawal(accounts.django), 40_000);
+
+        let escrow = contract.get_escrow(escrow_id).unwrap();
+        assert_eq!(escrow.status, EscrowStatus::Released);
+
+        // One FundsReleased per recipient
+        let expected = [
+            (escrow_id, 900_000u128, accounts.bob),
+            (escrow_id, 60_000u128, accounts.charlie),
+            (escrow_id, 40_000u128, accounts.django),
+        ];
+        let released = test::recorded_events()
+            .filter_map(|e| {
+                <(u64, u128, AccountId) as scale::Decode>::decode(&mut &e.data[..]).ok()
+            })
+            .filter(|fields| expected.contains(fields))
+            .count();
+        assert_eq!(released, 3);
+    }
+
+    #[ink::test]
+    fn test_release_to_recipients_rejects_mismatched_total() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+
+        let mut contract = AdvancedEscrow::new(1_000_000);
+
+        let participants = vec![accounts.alice, accounts.bob];
+        let escrow_id = contract
+            .create_escrow_advanced(
+                1,
+                1_000_000,
+                accounts.alice,
+                accounts.bob,
+                EscrowConfig::new(participants, 2),
+            )
+            .unwrap();
+
+        test::set_value_transferred::<ink::env::DefaultEnvironment>(1_000_000);
+        contract.deposit_funds(escrow_id).unwrap();
+
+        contract
+            .sign_approval(escrow_id, ApprovalType::Release)
+            .unwrap();
+        set_caller(accounts.bob);
+        contract
+            .sign_approval(escrow_id, ApprovalType::Release)
+            .unwrap();
+
+        let recipients = vec![(accounts.bob, 900_000), (accounts.charlie, 60_000)];
+        assert_eq!(
+            contract.release_to_recipients(escrow_id, recipients),
+            Err(Error::InvalidConfiguration)
+        );
+
+        let escrow = contract.get_escrow(escrow_id).unwrap();
+        assert_eq!(escrow.status, EscrowStatus::Active);
+        assert_eq!(contract.get_pending_withdrawal(accounts.bob), 0);
+    }
+
+    #[ink::test]
+    fn test_get_escrows_for_participant() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+
+        let mut contract = AdvancedEscrow::new(1_000_000);
+
+        // Alice buys from Bob, Charlie participates
+        let first = contract
+            .create_escrow_advanced(
+                1,
+                1_000_000,
+                accounts.alice,
+                accounts.bob,
+                EscrowConfig::new(vec![accounts.alice, accounts.bob, accounts.charlie], 2),
+            )
+            .unwrap();
+
+        // Django buys from Bob, Eve participates
+        let second = contract
+            .create_escrow_advanced(
+                2,
+                1_000_000,
+                accounts.django,
+                accounts.bob,
+                EscrowConfig::new(vec![accounts.django, accounts.eve], 2),
+            )
+            .unwrap();
+
+        assert_eq!(contract.get_escrows_for(accounts.alice), vec![first]);
+        assert_eq!(contract.get_escrows_for(accounts.bob), vec![first, second]);
+        assert_eq!(contract.get_escrows_for(accounts.charlie), vec![first]);
+        assert_eq!(contract.get_escrows_for(accounts.django), vec![second]);
+        assert_eq!(contract.get_escrows_for(accounts.eve), vec![second]);
+        assert!(contract.get_escrows_for(accounts.frank).is_empty());
+    }
+
+    fn setup_refund_escrow(contract: &mut AdvancedEscrow, conditions_gate_refund: bool) -> u64 {
+        let accounts = default_accounts();
+        let escrow_id = contract
+            .create_escrow_advanced(
+                1,
+                1_000_000,
+                accounts.alice,
+                accounts.bob,
+                EscrowConfig {
+                    conditions_gate_refund,
+                    ..EscrowConfig::new(vec![accounts.alice, accounts.bob], 2)
+                },
+            )
+            .unwrap();
+
+        test::set_value_transferred::<ink::env::DefaultEnvironment>(1_000_000);
+        contract.deposit_funds(escrow_id).unwrap();
+        contract
+            .add_condition(escrow_id, "Inspection passed".to_string(), None)
+            .unwrap();
+
+        contract
+            .sign_approval(escrow_id, ApprovalType::Refund)
+            .unwrap();
+        set_caller(accounts.bob);
+        contract
+            .sign_approval(escrow_id, ApprovalType::Refund)
+            .unwrap();
+        set_caller(accounts.alice);
+
+        escrow_id
+    }
+
+    #[ink::test]
+    fn test_refund_gated_on_conditions() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+
+        let mut contract = AdvancedEscrow::new(1_000_000);
+        let escrow_id = setup_refund_escrow(&mut contract, true);
+
+        // Unmet condition blocks the refund
+        assert_eq!(
+            contract.refund_funds(escrow_id),
+            Err(Error::ConditionsNotMet)
+        );
+
+        contract.mark_condition_met(escrow_id, 1).unwrap();
+        assert!(contract.refund_funds(escrow_id).is_ok());
+        assert_eq!(
+            contract.get_escrow(escrow_id).unwrap().status,
+            EscrowStatus::Refunded
+        );
+        assert_eq!(contract.get_pending_withdrawal(accounts.alice), 1_000_000);
+    }
+
+    #[ink::test]
+    fn test_refund_ungated_ignores_conditions() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+
+        let mut contract = AdvancedEscrow::new(1_000_000);
+        let escrow_id = setup_refund_escrow(&mut contract, false);
+
+        assert!(contract.refund_funds(escrow_id).is_ok());
+        assert_eq!(
+            contract.get_escrow(escrow_id).unwrap().status,
+            EscrowStatus::Refunded
+        );
+        assert_eq!(contract.get_pending_withdrawal(accounts.alice), 1_000_000);
+    }
+
+    #[ink::test]
+    fn test_revoke_approval() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+
+        let mut contract = AdvancedEscrow::new(1_000_000);
+
+        let participants = vec![accounts.alice, accounts.bob];
+        let escrow_id = contract
+            .create_escrow_advanced(
+                1,
+                1_000_000,
+                accounts.alice,
+                accounts.bob,
+                EscrowConfig::new(participants, 2),
+            )
+            .unwrap();
+
+        test::set_value_transferred::<ink::env::DefaultEnvironment>(1_000_000);
+        contract.deposit_funds(escrow_id).unwrap();
+
+        contract
+            .sign_approval(escrow_id, ApprovalType::Release)
+            .unwrap();
+        set_caller(accounts.bob);
+        contract
+            .sign_approval(escrow_id, ApprovalType::Release)
+            .unwrap();
+        assert_eq!(
+            contract.get_signature_count(escrow_id, ApprovalType::Release),
+            2
+        );
+
+        // Bob changes his mind before release
+        assert!(contract
+            .revoke_approval(escrow_id, ApprovalType::Release)
+            .is_ok());
+        assert_eq!(
+            contract.get_signature_count(escrow_id, ApprovalType::Release),
+            1
+        );
+        assert_eq!(
+            contract.release_funds(escrow_id),
+            Err(Error::SignatureThresholdNotMet)
+        );
+
+        // Cannot revoke twice or revoke an approval never given
+        assert_eq!(
+            contract.revoke_approval(escrow_id, ApprovalType::Release),
+            Err(Error::NotSigned)
+        );
+        assert_eq!(
+            contract.revoke_approval(escrow_id, ApprovalType::Refund),
+            Err(Error::NotSigned)
+        );
+
+        // Bob may sign again afterwards
+        assert!(contract
+            .sign_approval(escrow_id, ApprovalType::Release)
+            .is_ok());
+        assert_eq!(
+            contract.get_signature_count(escrow_id, ApprovalType::Release),
+            2
+        );
+    }
+
+    #[ink::test]
+    fn test_resolve_dispute_resets_release_signatures() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+
+        let mut contract = AdvancedEscrow::new(1_000_000);
+        assert!(contract.get_reset_signatures_on_resolve());
+
+        let participants = vec![accounts.alice, accounts.bob];
+        let escrow_id = contract
+            .create_escrow_advanced(
+                1,
+                1_000_000,
+                accounts.alice,
+                accounts.bob,
+                EscrowConfig::new(participants, 2),
+            )
+            .unwrap();
+
+        test::set_value_transferred::<ink::env::DefaultEnvironment>(1_000_000);
+        contract.deposit_funds(escrow_id).unwrap();
+
+        // Sign, then dispute
+        contract
+            .sign_approval(escrow_id, ApprovalType::Release)
+            .unwrap();
+        set_caller(accounts.bob);
+        contract
+            .sign_approval(escrow_id, ApprovalType::Release)
+            .unwrap();
+        contract
+            .raise_dispute(escrow_id, "Issue".to_string())
+            .unwrap();
+
+        // Resolve discards pre-dispute approvals
+        set_caller(accounts.alice);
+        contract
+            .resolve_dispute(escrow_id, "Resolved".to_string())
+            .unwrap();
+        assert_eq!(
+            contract.get_signature_count(escrow_id, ApprovalType::Release),
+            0
+        );
+        assert_eq!(
+            contract.release_funds(escrow_id),
+            Err(Error::SignatureThresholdNotMet)
+        );
+
+        // Release works once both parties re-sign
+        contract
+            .sign_approval(escrow_id, ApprovalType::Release)
+            .unwrap();
+        set_caller(accounts.bob);
+        contract
+            .sign_approval(escrow_id, ApprovalType::Release)
+            .unwrap();
+        assert!(contract.release_funds(escrow_id).is_ok());
+    }
+
+    #[ink::test]
+    fn test_resolve_dispute_keeps_signatures_when_disabled() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+
+        let mut contract = AdvancedEscrow::new(1_000_000);
+        assert!(contract.set_reset_signatures_on_resolve(false).is_ok());
+
+        let participants = vec![accounts.alice, accounts.bob];
+        let escrow_id = contract
+            .create_escrow_advanced(
+                1,
+                1_000_000,
+                accounts.alice,
+                accounts.bob,
+                EscrowConfig::new(participants, 2),
+            )
+            .unwrap();
+
+        contract
+            .sign_approval(escrow_id, ApprovalType::Release)
+            .unwrap();
+        contract
+            .raise_dispute(escrow_id, "Issue".to_string())
+            .unwrap();
+        contract
+            .resolve_dispute(escrow_id, "Resolved".to_string())
+            .unwrap();
+
+        assert_eq!(
+            contract.get_signature_count(escrow_id, ApprovalType::Release),
+            1
+        );
+    }
+
+    #[ink::test]
+    fn test_remove_condition_unblocks_release() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+
+        let mut contract = AdvancedEscrow::new(1_000_000);
+
+        let participants = vec![accounts.alice, accounts.bob];
+        let escrow_id = contract
+            .create_escrow_advanced(
+                1,
+                1_000_000,
+                accounts.alice,
+                accounts.bob,
+                EscrowConfig::new(participants, 2),
+            )
+            .unwrap();
+
+        test::set_value_transferred::<ink::env::DefaultEnvironment>(1_000_000);
+        contract.deposit_funds(escrow_id).unwrap();
+
+        let mistaken = contract
+            .add_condition(escrow_id, "Wrong condition".to_string(), None)
+            .unwrap();
+        let inspection = contract
+            .add_condition(escrow_id, "Inspection passed".to_string(), None)
+            .unwrap();
+        assert_eq!(contract.get_unmet_condition_count(escrow_id), 2);
+
+        // Only buyer or seller may remove
+        set_caller(accounts.charlie);
+        assert_eq!(
+            contract.remove_condition(escrow_id, mistaken),
+            Err(Error::Unauthorized)
+        );
+
+        set_caller(accounts.alice);
+        assert!(contract.remove_condition(escrow_id, mistaken).is_ok());
+        assert_eq!(contract.get_unmet_condition_count(escrow_id), 1);
+        assert_eq!(
+            contract.remove_condition(escrow_id, mistaken),
+            Err(Error::EscrowNotFound)
+        );
+
+        contract.mark_condition_met(escrow_id, inspection).unwrap();
+        assert_eq!(contract.get_unmet_condition_count(escrow_id), 0);
+
+        contract
+            .sign_approval(escrow_id, ApprovalType::Release)
+            .unwrap();
+        set_caller(accounts.bob);
+        contract
+            .sign_approval(escrow_id, ApprovalType::Release)
+            .unwrap();
+        assert!(contract.release_funds(escrow_id).is_ok());
+
+        // Conditions are frozen after release
+        assert_eq!(
+            contract.remove_condition(escrow_id, inspection),
+            Err(Error::InvalidStatus)
+        );
+    }
+
+    #[ink::test]
+    fn test_settles_through_trait_object() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+
+        let mut contract = AdvancedEscrow::new(1_000_000);
+        let escrow: &mut dyn propchain_traits::AdvancedEscrow<Error = Error> = &mut contract;
+
+        let participants = vec![accounts.alice, accounts.bob];
+        let escrow_id = escrow
+            .create_escrow_advanced(
+                1,
+                1_000_000,
+                accounts.alice,
+                accounts.bob,
+                EscrowConfig::new(participants, 2),
+            )
+            .unwrap();
+
+        test::set_value_transferred::<ink::env::DefaultEnvironment>(1_000_000);
+        escrow.deposit_funds(escrow_id).unwrap();
+
+        let inspection = escrow
+            .add_condition(escrow_id, "Inspection".to_string(), None)
+            .unwrap();
+        escrow.mark_condition_met(escrow_id, inspection).unwrap();
+
+        let deed = Hash::from([7u8; 32]);
+        escrow
+            .upload_document(escrow_id, deed, "Deed".to_string())
+            .unwrap();
+        escrow.verify_document(escrow_id, deed).unwrap();
+
+        // Releasing before the multi-sig threshold surfaces the contract's own error
+        assert_eq!(
+            escrow.release_funds(escrow_id),
+            Err(Error::SignatureThresholdNotMet)
+        );
+
+        escrow
+            .sign_approval(escrow_id, propchain_traits::ApprovalType::Release)
+            .unwrap();
+        set_caller(accounts.bob);
+        escrow
+            .sign_approval(escrow_id, propchain_traits::ApprovalType::Release)
+            .unwrap();
+        escrow.release_funds(escrow_id).unwrap();
+
+        assert_eq!(
+            contract.get_escrow(escrow_id).unwrap().status,
+            EscrowStatus::Released
+        );
+        assert_eq!(contract.get_pending_withdrawal(accounts.bob), 1_000_000);
+    }
+
+    #[ink::test]
+    fn test_verifying_linked_document_satisfies_condition() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+
+        let mut contract = AdvancedEscrow::new(1_000_000);
+
+        let participants = vec![accounts.alice, accounts.bob];
+        let escrow_id = contract
+            .create_escrow_advanced(
+                1,
+                1_000_000,
+                accounts.alice,
+                accounts.bob,
+                EscrowConfig::new(participants, 2),
+            )
+            .unwrap();
+
+        let deed = Hash::from([7u8; 32]);
+        let deed_verified = contract
+            .add_condition(escrow_id, "Deed verified".to_string(), None)
+            .unwrap();
+        let inspection = contract
+            .add_condition(escrow_id, "Inspection".to_string(), None)
+            .unwrap();
+
+        // Only buyer or seller can link, and the condition must exist
+        set_caller(accounts.charlie);
+        assert_eq!(
+            contract.link_condition_to_document(escrow_id, deed_verified, deed),
+            Err(Error::Unauthorized)
+        );
+        set_caller(accounts.alice);
+        assert_eq!(
+            contract.link_condition_to_document(escrow_id, 99, deed),
+            Err(Error::EscrowNotFound)
+        );
+        contract
+            .link_condition_to_document(escrow_id, deed_verified, deed)
+            .unwrap();
+
+        set_caller(accounts.bob);
+        contract
+            .upload_document(escrow_id, deed, "Deed".to_string())
+            .unwrap();
+        assert_eq!(contract.get_unmet_condition_count(escrow_id), 2);
+
+        let events_before = test::recorded_events().count();
+        contract.verify_document(escrow_id, deed).unwrap();
+
+        let conditions = contract.get_conditions(escrow_id);
+        let linked = conditions.iter().find(|c| c.id == deed_verified).unwrap();
+        assert!(linked.met);
+        assert_eq!(linked.verified_by, Some(accounts.bob));
+        assert!(!conditions.iter().find(|c| c.id == inspection).unwrap().met);
+        assert_eq!(contract.get_unmet_condition_count(escrow_id), 1);
+
+        // DocumentVerified followed by ConditionMet for the linked condition
+        let events = test::recorded_events()
+            .skip(events_before)
+            .collect::<Vec<_>>();
+        assert_eq!(events.len(), 2);
+        let (event_escrow_id, condition_id, verified_by) =
+            <(u64, u64, AccountId) as scale::Decode>::decode(&mut &events[1].data[..]).unwrap();
+        assert_eq!(event_escrow_id, escrow_id);
+        assert_eq!(condition_id, deed_verified);
+        assert_eq!(verified_by, accounts.bob);
+
+        // Linking to an already verified document satisfies the condition immediately
+        set_caller(accounts.alice);
+        contract
+            .link_condition_to_document(escrow_id, inspection, deed)
+            .unwrap();
+        assert_eq!(contract.get_unmet_condition_count(escrow_id), 0);
+    }
+
+    #[ink::test]
+    fn test_escrow_registry_paging_and_status_counts() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+
+        let mut contract = AdvancedEscrow::new(1_000_000);
+
+        let mut ids = Vec::new();
+        for property_id in 1..=4 {
+            let escrow_id = contract
+                .create_escrow_advanced(
+                    property_id,
+                    100_000,
+                    accounts.alice,
+                    accounts.bob,
+                    EscrowConfig::new(vec![accounts.alice, accounts.bob], 1),
+                )
+                .unwrap();
+            ids.push(escrow_id);
+        }
+
+        // Below the high-value threshold, so a single signature suffices
+        // Escrow 2 partially funded, 3 fully funded, 4 funded and refunded
+        test::set_value_transferred::<ink::env::DefaultEnvironment>(50_000);
+        contract.deposit_funds(ids[1]).unwrap();
+        test::set_value_transferred::<ink::env::DefaultEnvironment>(100_000);
+        contract.deposit_funds(ids[2]).unwrap();
+        contract.deposit_funds(ids[3]).unwrap();
+        contract
+            .sign_approval(ids[3], ApprovalType::Refund)
+            .unwrap();
+        contract.refund_funds(ids[3]).unwrap();
+
+        let page = contract.get_escrows_paged(1, 2);
+        assert_eq!(page.iter().map(|e| e.id).collect::<Vec<_>>(), vec![1, 2]);
+        let page = contract.get_escrows_paged(3, 10);
+        assert_eq!(page.iter().map(|e| e.id).collect::<Vec<_>>(), vec![3, 4]);
+        assert_eq!(page[1].status, EscrowStatus::Refunded);
+        assert!(contract.get_escrows_paged(5, 10).is_empty());
+        assert!(contract.get_escrows_paged(1, 0).is_empty());
+
+        let counts = contract.count_by_status();
+        let count_of = |status: EscrowStatus| {
+            counts
+                .iter()
+                .find(|(s, _)| *s == status)
+                .map(|(_, n)| *n)
+                .unwrap()
+        };
+        assert_eq!(count_of(EscrowStatus::Created), 1);
+        assert_eq!(count_of(EscrowStatus::Funded), 1);
+        assert_eq!(count_of(EscrowStatus::Active), 1);
+        assert_eq!(count_of(EscrowStatus::Refunded), 1);
+        assert_eq!(count_of(EscrowStatus::Released), 0);
+        assert_eq!(counts.iter().map(|(_, n)| n).sum::<u64>(), 4);
+    }
+
+    #[ink::test]
+    fn test_high_value_escrow_requires_multi_sig() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+
+        let mut contract = AdvancedEscrow::new(1_000_000);
+        assert_eq!(contract.get_high_value_min_signatures(), 2);
+
+        let create = |contract: &mut AdvancedEscrow, amount: u128, required_signatures: u8| {
+            contract.create_escrow_advanced(
+                1,
+                amount,
+                accounts.alice,
+                accounts.bob,
+                EscrowConfig::new(
+                    vec![accounts.alice, accounts.bob, accounts.charlie],
+                    required_signatures,
+                ),
+            )
+        };
+
+        // Below the threshold a single signer is allowed
+        assert!(create(&mut contract, 999_999, 1).is_ok());
+
+        // At or above the threshold a single signer is rejected
+        assert_eq!(
+            create(&mut contract, 1_000_000, 1),
+            Err(Error::InvalidConfiguration)
+        );
+        assert!(create(&mut contract, 1_000_000, 2).is_ok());
+
+        // The minimum is configurable by the admin
+        set_caller(accounts.bob);
+        assert_eq!(
+            contract.set_high_value_min_signatures(3),
+            Err(Error::Unauthorized)
+        );
+        set_caller(accounts.alice);
+        assert_eq!(
+            contract.set_high_value_min_signatures(0),
+            Err(Error::InvalidConfiguration)
+        );
+        contract.set_high_value_min_signatures(3).unwrap();
+        assert_eq!(
+            create(&mut contract, 5_000_000, 2),
+            Err(Error::InvalidConfiguration)
+        );
+        assert!(create(&mut contract, 5_000_000, 3).is_ok());
+    }
+
+    #[ink::test]
+    fn test_sweep_unallocated_only_takes_surplus() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let contract_account = test::callee::<ink::env::DefaultEnvironment>();
+        set_balance(accounts.frank, 0);
+
+        let mut contract = AdvancedEscrow::new(1_000_000);
+
+        let participants = vec![accounts.alice, accounts.bob];
+        let create = |contract: &mut AdvancedEscrow| {
+            contract
+                .create_escrow_advanced(
+                    1,
+                    1_000_000,
+                    accounts.alice,
+                    accounts.bob,
+                    EscrowConfig::new(participants.clone(), 2),
+                )
+                .unwrap()
+        };
+
+        // One escrow still holding its deposit, one released but not yet withdrawn
+        let active = create(&mut contract);
+        let released = create(&mut contract);
+        test::set_value_transferred::<ink::env::DefaultEnvironment>(1_000_000);
+        contract.deposit_funds(active).unwrap();
+        contract.deposit_funds(released).unwrap();
+        contract
+            .sign_approval(released, ApprovalType::Release)
+            .unwrap();
+        set_caller(accounts.bob);
+        contract
+            .sign_approval(released, ApprovalType::Release)
+            .unwrap();
+        contract.release_funds(released).unwrap();
+
+        // 250_000 was sent to the contract outside of any escrow
+        set_balance(contract_account, 2_250_000);
+
+        assert_eq!(
+            contract.sweep_unallocated(accounts.frank),
+            Err(Error::Unauthorized)
+        );
+        set_caller(accounts.alice);
+        assert_eq!(contract.sweep_unallocated(accounts.frank), Ok(250_000));
+        assert_eq!(
+            test::get_account_balance::<ink::env::DefaultEnvironment>(accounts.frank),
+            Ok(250_000)
+        );
+        assert_eq!(
+            test::get_account_balance::<ink::env::DefaultEnvironment>(contract_account),
+            Ok(2_000_000)
+        );
+
+        // Nothing left to sweep; escrowed and owed funds are untouched
+        assert_eq!(contract.sweep_unallocated(accounts.frank), Ok(0));
+        set_caller(accounts.bob);
+        assert_eq!(contract.withdraw(), Ok(1_000_000));
+        set_caller(accounts.alice);
+        assert_eq!(contract.sweep_unallocated(accounts.frank), Ok(0));
+        assert_eq!(
+            contract.get_escrow(active).unwrap().deposited_amount,
+            1_000_000
+        );
+    }
+
+    #[ink::test]
+    fn test_total_locked_and_solvency() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let contract_account = test::callee::<ink::env::DefaultEnvironment>();
+
+        let mut contract = AdvancedEscrow::new(1_000_000);
+
+        let participants = vec![accounts.alice, accounts.bob];
+        let mut create = |amount: u128| {
+            contract
+                .create_escrow_advanced(
+                    1,
+                    amount,
+                    accounts.alice,
+                    accounts.bob,
+                    EscrowConfig::new(participants.clone(), 2),
+                )
+                .unwrap()
+        };
+        let first = create(500_000);
+        let second = create(300_000);
+        // Created but never funded
+        create(200_000);
+
+        test::set_value_transferred::<ink::env::DefaultEnvironment>(500_000);
+        contract.deposit_funds(first).unwrap();
+        test::set_value_transferred::<ink::env::DefaultEnvironment>(300_000);
+        contract.deposit_funds(second).unwrap();
+
+        // Only deposited funds count as locked
+        assert_eq!(contract.get_total_locked(), 800_000);
+
+        set_balance(contract_account, 800_000);
+        assert!(contract.is_solvent());
+        set_balance(contract_account, 799_999);
+        assert!(!contract.is_solvent());
+    }
+
+    #[ink::test]
+    fn test_condition_restricted_to_designated_verifier() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+
+        let mut contract = AdvancedEscrow::new(1_000_000);
+
+        let participants = vec![accounts.alice, accounts.bob];
+        let escrow_id = contract
+            .create_escrow_advanced(
+                1,
+                1_000_000,
+                accounts.alice,
+                accounts.bob,
+                EscrowConfig::new(participants, 2),
+            )
+            .unwrap();
+
+        // Only the inspector may attest the inspection; anyone involved may attest the general one
+        let inspection = contract
+            .add_condition(
+                escrow_id,
+                "Inspection passed".to_string(),
+                Some(accounts.django),
+            )
+            .unwrap();
+        let general = contract
+            .add_condition(escrow_id, "Keys handed over".to_string(), None)
+            .unwrap();
+        assert_eq!(
+            contract.get_conditions(escrow_id)[0].verifier,
+            Some(accounts.django)
+        );
+
+        // Participants cannot mark the restricted condition
+        assert_eq!(
+            contract.mark_condition_met(escrow_id, inspection),
+            Err(Error::Unauthorized)
+        );
+        set_caller(accounts.bob);
+        assert_eq!(
+            contract.mark_condition_met(escrow_id, inspection),
+            Err(Error::Unauthorized)
+        );
+
+        // The inspector can, even though they are not a participant
+        set_caller(accounts.django);
+        contract.mark_condition_met(escrow_id, inspection).unwrap();
+
+        // ...but the general condition still needs a participant
+        assert_eq!(
+            contract.mark_condition_met(escrow_id, general),
+            Err(Error::Unauthorized)
+        );
+        set_caller(accounts.bob);
+        contract.mark_condition_met(escrow_id, general).unwrap();
+
+        let conditions = contract.get_conditions(escrow_id);
+        assert_eq!(conditions[0].verified_by, Some(accounts.django));
+        assert_eq!(conditions[1].verified_by, Some(accounts.bob));
+        assert_eq!(contract.get_unmet_condition_count(escrow_id), 0);
     }
 }
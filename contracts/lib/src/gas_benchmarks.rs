@@ -0,0 +1,88 @@
+//! Gas benchmarks for hot PropertyRegistry messages.
+//!
+//! Each benchmark prints a `gas_bench:` line that `security-audit benchmark` collects
+//! and compares against the stored baseline. Run with:
+//!
+//! ```text
+//! security-audit benchmark -p propchain-contracts --e2e
+//! ```
+
+use crate::propchain_contracts::{PropertyRegistry, PropertyRegistryRef};
+use ink_e2e::ContractsBackend;
+use propchain_traits::PropertyMetadata;
+
+type E2EResult<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+/// Number of properties registered by the batch benchmark
+const BATCH_SIZE: u64 = 10;
+
+fn sample_metadata(index: u64) -> PropertyMetadata {
+    PropertyMetadata {
+        location: format!("{} Benchmark Ave", index),
+        size: 1000 + index,
+        legal_description: "Benchmark property".to_string(),
+        valuation: 1_000_000,
+        documents_url: "https://example.com/docs".to_string(),
+        latitude: None,
+        longitude: None,
+    }
+}
+
+/// Prints a measurement in the format `security-audit benchmark` parses
+fn report(message: &str, ref_time: u64, proof_size: u64) {
+    println!(
+        "gas_bench: {} ref_time={} proof_size={}",
+        message, ref_time, proof_size
+    );
+}
+
+#[ink_e2e::test]
+async fn bench_property_messages(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+    let mut constructor = PropertyRegistryRef::new();
+    let contract = client
+        .instantiate("propchain_contracts", &ink_e2e::alice(), &mut constructor)
+        .submit()
+        .await
+        .expect("instantiate failed");
+    let mut call_builder = contract.call_builder::<PropertyRegistry>();
+
+    let register = call_builder.register_property(sample_metadata(0));
+    let result = client
+        .call(&ink_e2e::alice(), &register)
+        .submit()
+        .await
+        .expect("register_property failed");
+    let gas = result.dry_run.exec_result.gas_consumed;
+    report("register_property", gas.ref_time(), gas.proof_size());
+    let property_id = result
+        .return_value()
+        .expect("register_property returned an error");
+
+    let transfer = call_builder.transfer_property(
+        property_id,
+        ink_e2e::account_id(ink_e2e::AccountKeyring::Bob),
+    );
+    let result = client
+        .call(&ink_e2e::alice(), &transfer)
+        .submit()
+        .await
+        .expect("transfer_property failed");
+    let gas = result.dry_run.exec_result.gas_consumed;
+    report("transfer_property", gas.ref_time(), gas.proof_size());
+
+    let batch =
+        call_builder.batch_register_properties((1..=BATCH_SIZE).map(sample_metadata).collect());
+    let result = client
+        .call(&ink_e2e::alice(), &batch)
+        .submit()
+        .await
+        .expect("batch_register_properties failed");
+    let gas = result.dry_run.exec_result.gas_consumed;
+    report(
+        "batch_register_properties",
+        gas.ref_time(),
+        gas.proof_size(),
+    );
+
+    Ok(())
+}
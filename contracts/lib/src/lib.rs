@@ -31,6 +31,23 @@ mod propchain_contracts {
         InvalidAppealStatus,
         ComplianceRegistryNotSet,
         OracleError,
+        TooManyOpenEscrows,
+        AppealAlreadyPending,
+        BadgeNotRevoked,
+        InvalidEscrowStatus,
+        EscrowAmountMismatch,
+        EscrowTransferFailed,
+        InvalidEscrowParties,
+        BadgeTypeAlreadyRegistered,
+        RequestTooSoon,
+        InvalidRecipient,
+        EscrowAmountTooLow,
+        InvalidSignature,
+        InvalidNonce,
+        InvalidVerificationStatus,
+        BadgeRevoked,
+        ContractPaused,
+        PropertyUnderContract,
     }
 
     /// Property Registry contract
@@ -38,14 +55,24 @@ mod propchain_contracts {
     pub struct PropertyRegistry {
         /// Mapping from property ID to property information
         properties: Mapping<u64, PropertyInfo>,
-        /// Mapping from owner to their properties
-        owner_properties: Mapping<AccountId, Vec<u64>>,
+        /// Owner index: property IDs per owner, split into chunks of `OWNER_INDEX_CHUNK_SIZE`
+        owner_property_chunks: Mapping<(AccountId, u32), Vec<u64>>,
+        /// Number of properties in each owner's index
+        owner_property_counts: Mapping<AccountId, u32>,
+        /// Position of each property within its owner's index
+        owner_property_positions: Mapping<u64, u32>,
         /// Reverse mapping: property ID to owner (optimization for faster lookups)
         property_owners: Mapping<u64, AccountId>,
         /// Mapping from property ID to approved account
         approvals: Mapping<u64, AccountId>,
-        /// Property counter
+        /// Operators approved to transfer all of an owner's properties: (owner, operator) -> approved
+        operator_approvals: Mapping<(AccountId, AccountId), bool>,
+        /// Next expected nonce per signer of a signed (relayed) transfer
+        transfer_nonces: Mapping<AccountId, u64>,
+        /// Number of live (registered and not burned) properties
         property_count: u64,
+        /// ID assigned to the next registered property; IDs are never reused
+        next_property_id: u64,
         /// Contract version
         version: u32,
         /// Admin for upgrades (if used directly, or for logic-level auth)
@@ -66,10 +93,52 @@ mod propchain_contracts {
         verification_requests: Mapping<u64, VerificationRequest>,
         /// Verification request counter
         verification_count: u64,
+        /// Verification request IDs per property, in filing order
+        property_verification_requests: Mapping<u64, Vec<u64>>,
+        /// Timestamp of each account's most recent verification request
+        last_request_at: Mapping<AccountId, u64>,
+        /// Minimum time in milliseconds between an account's verification requests (None = no limit)
+        request_cooldown: Option<u64>,
         /// Appeals
         appeals: Mapping<u64, Appeal>,
         /// Appeal counter
         appeal_count: u64,
+        /// Maximum number of unreleased escrows a seller may have open (None = unlimited)
+        max_open_escrows_per_seller: Option<u32>,
+        /// Smallest amount an escrow may be created for (0 = no minimum)
+        min_escrow_amount: u128,
+        /// Number of unreleased escrows per seller
+        open_escrows_per_seller: Mapping<AccountId, u32>,
+        /// Number of unreleased escrows per property
+        open_escrows_per_property: Mapping<u64, u32>,
+        /// Whether batch registration also emits a PropertyRegistered event per item
+        emit_individual_in_batch: bool,
+        /// Property type per property, used for search filtering
+        property_types: Mapping<u64, PropertyType>,
+        /// Listing status per property; absent means Unlisted
+        property_statuses: Mapping<u64, PropertyStatus>,
+        /// Gas above which a single operation emits HighGasUsage (0 = disabled)
+        gas_alert_threshold: u64,
+        /// Roles delegated by the admin
+        roles: Mapping<(AccountId, Role), bool>,
+        /// Appeal IDs per property
+        property_appeals: Mapping<u64, Vec<u64>>,
+        /// IDs of appeals awaiting resolution, by slot
+        pending_appeals: Mapping<u32, u64>,
+        /// Number of occupied slots in `pending_appeals`
+        pending_appeal_count: u32,
+        /// Slot of each pending appeal in `pending_appeals`
+        pending_appeal_positions: Mapping<u64, u32>,
+        /// Pending appeal ID per (property_id, badge_type)
+        active_appeals: Mapping<(u64, BadgeType), u64>,
+        /// Names of badge types registered at runtime, by ID
+        custom_badge_types: Mapping<BadgeTypeId, String>,
+        /// Reverse lookup from custom badge type name to ID
+        badge_type_ids: Mapping<String, BadgeTypeId>,
+        /// ID assigned to the next registered badge type
+        next_badge_type_id: BadgeTypeId,
+        /// Whether registrations, transfers and new escrows are halted
+        paused: bool,
     }
 
     /// Escrow information
@@ -83,7 +152,33 @@ mod propchain_contracts {
         pub buyer: AccountId,
         pub seller: AccountId,
         pub amount: u128,
-        pub released: bool,
+        pub status: EscrowStatus,
+    }
+
+    impl EscrowInfo {
+        /// Whether the escrow is closed, either released or refunded
+        pub fn released(&self) -> bool {
+            matches!(self.status, EscrowStatus::Released | EscrowStatus::Refunded)
+        }
+    }
+
+    /// Lifecycle of a registry escrow
+    #[derive(
+        Debug,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        scale::Encode,
+        scale::Decode,
+        ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum EscrowStatus {
+        Created,  // Opened by the seller, no funds deposited
+        Funded,   // Buyer deposited the escrow amount
+        Released, // Property transferred to the buyer
+        Refunded, // Cancelled by the seller
     }
 
     /// Portfolio summary statistics
@@ -137,6 +232,15 @@ mod propchain_contracts {
         pub unique_owners: u64,
     }
 
+    /// One page of properties from global enumeration
+    #[derive(Debug, Clone, PartialEq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct PropertyPage {
+        pub properties: Vec<PropertyInfo>,
+        /// ID to pass as `start` for the next page, or None when enumeration is complete
+        pub next_cursor: Option<u64>,
+    }
+
     /// Gas metrics for monitoring
     #[derive(
         Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
@@ -163,6 +267,26 @@ mod propchain_contracts {
         pub max_gas_used: u64,
     }
 
+    /// Operational roles that can be delegated by the admin.
+    /// The admin implicitly holds every role.
+    #[derive(
+        Debug,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        scale::Encode,
+        scale::Decode,
+        ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum Role {
+        RegistrarManager,  // Registration and escrow settings
+        VerifierManager,   // Badge verifier management
+        Pauser,            // Emergency pause
+        ComplianceManager, // Compliance registry configuration
+    }
+
     /// Badge types for property verification
     #[derive(
         Debug,
@@ -180,6 +304,52 @@ mod propchain_contracts {
         DocumentVerification, // Legal documents verified
         LegalCompliance,      // Regulatory compliance verified
         PremiumListing,       // Premium tier property
+        Custom(BadgeTypeId),  // Registered at runtime via register_badge_type
+    }
+
+    /// Identifier of a badge type in the badge-type registry
+    pub type BadgeTypeId = u16;
+
+    impl BadgeType {
+        /// Built-in badge types, pre-registered under IDs 0 to 3
+        pub const BUILT_IN: [BadgeType; 4] = [
+            BadgeType::OwnerVerification,
+            BadgeType::DocumentVerification,
+            BadgeType::LegalCompliance,
+            BadgeType::PremiumListing,
+        ];
+
+        /// First ID handed out to custom badge types
+        pub const FIRST_CUSTOM_ID: BadgeTypeId = 4;
+
+        /// Registry ID of this badge type
+        pub fn id(&self) -> BadgeTypeId {
+            match self {
+                BadgeType::OwnerVerification => 0,
+                BadgeType::DocumentVerification => 1,
+                BadgeType::LegalCompliance => 2,
+                BadgeType::PremiumListing => 3,
+                BadgeType::Custom(id) => *id,
+            }
+        }
+
+        /// Badge type for a registry ID, mapping the built-in IDs to their variants
+        pub fn from_id(id: BadgeTypeId) -> BadgeType {
+            match Self::BUILT_IN.get(id as usize) {
+                Some(built_in) => *built_in,
+                None => BadgeType::Custom(id),
+            }
+        }
+
+        fn built_in_name(&self) -> Option<&'static str> {
+            match self {
+                BadgeType::OwnerVerification => Some("OwnerVerification"),
+                BadgeType::DocumentVerification => Some("DocumentVerification"),
+                BadgeType::LegalCompliance => Some("LegalCompliance"),
+                BadgeType::PremiumListing => Some("PremiumListing"),
+                BadgeType::Custom(_) => None,
+            }
+        }
     }
 
     /// Badge information
@@ -231,6 +401,7 @@ mod propchain_contracts {
         Pending,
         Approved,
         Rejected,
+        Cancelled,
     }
 
     /// Appeal for badge revocation
@@ -279,6 +450,14 @@ mod propchain_contracts {
     // - Detailed metadata for off-chain indexing
     // ============================================================================
 
+    /// Schema version carried in the `event_version` topic of every versioned event.
+    /// Bump this when any versioned event's fields change.
+    pub const EVENT_VERSION: u8 = 1;
+
+    /// Property IDs stored per owner-index chunk, keeping every stored value bounded
+    /// however many properties one account holds
+    pub const OWNER_INDEX_CHUNK_SIZE: u32 = 64;
+
     /// Event emitted when the contract is initialized
     #[ink(event)]
     pub struct ContractInitialized {
@@ -326,6 +505,28 @@ mod propchain_contracts {
         transferred_by: AccountId, // The account that initiated the transfer
     }
 
+    /// Event emitted when a property's listing status changes
+    #[ink(event)]
+    pub struct PropertyStatusChanged {
+        #[ink(topic)]
+        property_id: u64,
+        old_status: PropertyStatus,
+        new_status: PropertyStatus,
+        timestamp: u64,
+    }
+
+    /// Event emitted when a property is burned
+    /// Indexed fields: property_id, owner for efficient filtering
+    #[ink(event)]
+    pub struct PropertyBurned {
+        #[ink(topic)]
+        property_id: u64,
+        #[ink(topic)]
+        owner: AccountId,
+        timestamp: u64,
+        block_number: u32,
+    }
+
     /// Event emitted when property metadata is updated
     /// Indexed fields: property_id, owner for efficient filtering
     #[ink(event)]
@@ -377,6 +578,20 @@ mod propchain_contracts {
         transaction_hash: Hash,
     }
 
+    /// Event emitted when an operator is approved or revoked for all of an owner's properties
+    #[ink(event)]
+    pub struct ApprovalForAll {
+        #[ink(topic)]
+        owner: AccountId,
+        #[ink(topic)]
+        operator: AccountId,
+        approved: bool,
+        #[ink(topic)]
+        event_version: u8,
+        timestamp: u64,
+        block_number: u32,
+    }
+
     /// Event emitted when an escrow is created
     /// Indexed fields: escrow_id, property_id, buyer, seller for efficient querying
     #[ink(event)]
@@ -550,6 +765,40 @@ mod propchain_contracts {
         transaction_hash: Hash,
     }
 
+    /// Event emitted when a revoked badge is reinstated without an appeal
+    #[ink(event)]
+    pub struct BadgeReinstated {
+        #[ink(topic)]
+        property_id: u64,
+        #[ink(topic)]
+        badge_type: BadgeType,
+        #[ink(topic)]
+        reinstated_by: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when a badge's metadata URL is corrected in place
+    #[ink(event)]
+    pub struct BadgeMetadataUpdated {
+        #[ink(topic)]
+        property_id: u64,
+        #[ink(topic)]
+        badge_type: BadgeType,
+        #[ink(topic)]
+        updated_by: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        old_metadata_url: String,
+        new_metadata_url: String,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
     /// Event emitted when a verification is requested
     #[ink(event)]
     pub struct VerificationRequested {
@@ -587,6 +836,22 @@ mod propchain_contracts {
         transaction_hash: Hash,
     }
 
+    /// Event emitted when a requester withdraws a pending verification request
+    #[ink(event)]
+    pub struct VerificationCancelled {
+        #[ink(topic)]
+        request_id: u64,
+        #[ink(topic)]
+        property_id: u64,
+        #[ink(topic)]
+        requester: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
     /// Event emitted when an appeal is submitted
     #[ink(event)]
     pub struct AppealSubmitted {
@@ -641,6 +906,71 @@ mod propchain_contracts {
         transaction_hash: Hash,
     }
 
+    /// Event emitted when a new badge type is registered
+    #[ink(event)]
+    pub struct BadgeTypeRegistered {
+        #[ink(topic)]
+        badge_type_id: BadgeTypeId,
+        name: String,
+        #[ink(topic)]
+        registered_by: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        timestamp: u64,
+        block_number: u32,
+    }
+
+    /// Event emitted when a single operation uses more gas than the alert threshold
+    #[ink(event)]
+    pub struct HighGasUsage {
+        #[ink(topic)]
+        operation: String,
+        gas_used: u64,
+        threshold: u64,
+        timestamp: u64,
+        block_number: u32,
+    }
+
+    /// Event emitted when a role is granted
+    #[ink(event)]
+    pub struct RoleGranted {
+        #[ink(topic)]
+        account: AccountId,
+        #[ink(topic)]
+        role: Role,
+        #[ink(topic)]
+        granted_by: AccountId,
+        timestamp: u64,
+    }
+
+    /// Event emitted when a role is revoked
+    #[ink(event)]
+    pub struct RoleRevoked {
+        #[ink(topic)]
+        account: AccountId,
+        #[ink(topic)]
+        role: Role,
+        #[ink(topic)]
+        revoked_by: AccountId,
+        timestamp: u64,
+    }
+
+    /// Event emitted when the registry is paused
+    #[ink(event)]
+    pub struct Paused {
+        #[ink(topic)]
+        by: AccountId,
+        timestamp: u64,
+    }
+
+    /// Event emitted when the registry is unpaused
+    #[ink(event)]
+    pub struct Unpaused {
+        #[ink(topic)]
+        by: AccountId,
+        timestamp: u64,
+    }
+
     impl PropertyRegistry {
         /// Creates a new PropertyRegistry contract
         #[ink(constructor)]
@@ -651,10 +981,15 @@ mod propchain_contracts {
 
             let contract = Self {
                 properties: Mapping::default(),
-                owner_properties: Mapping::default(),
+                owner_property_chunks: Mapping::default(),
+                owner_property_counts: Mapping::default(),
+                owner_property_positions: Mapping::default(),
                 property_owners: Mapping::default(),
                 approvals: Mapping::default(),
+                operator_approvals: Mapping::default(),
+                transfer_nonces: Mapping::default(),
                 property_count: 0,
+                next_property_id: 1,
                 version: 1,
                 admin: caller,
                 escrows: Mapping::default(),
@@ -671,8 +1006,29 @@ mod propchain_contracts {
                 badge_verifiers: Mapping::default(),
                 verification_requests: Mapping::default(),
                 verification_count: 0,
+                property_verification_requests: Mapping::default(),
+                last_request_at: Mapping::default(),
+                request_cooldown: None,
                 appeals: Mapping::default(),
                 appeal_count: 0,
+                max_open_escrows_per_seller: None,
+                min_escrow_amount: 0,
+                open_escrows_per_seller: Mapping::default(),
+                open_escrows_per_property: Mapping::default(),
+                emit_individual_in_batch: true,
+                property_types: Mapping::default(),
+                property_statuses: Mapping::default(),
+                gas_alert_threshold: 0,
+                roles: Mapping::default(),
+                property_appeals: Mapping::default(),
+                pending_appeals: Mapping::default(),
+                pending_appeal_count: 0,
+                pending_appeal_positions: Mapping::default(),
+                active_appeals: Mapping::default(),
+                custom_badge_types: Mapping::default(),
+                badge_type_ids: Mapping::default(),
+                next_badge_type_id: BadgeType::FIRST_CUSTOM_ID,
+                paused: false,
             };
 
             // Emit contract initialization event
@@ -692,6 +1048,12 @@ mod propchain_contracts {
             self.version
         }
 
+        /// Returns the schema version of the events this contract emits
+        #[ink(message)]
+        pub fn contract_event_version(&self) -> u8 {
+            EVENT_VERSION
+        }
+
         /// Returns the admin account
         #[ink(message)]
         pub fn admin(&self) -> AccountId {
@@ -715,7 +1077,7 @@ mod propchain_contracts {
             self.env().emit_event(AdminChanged {
                 old_admin,
                 new_admin,
-                event_version: 1,
+                event_version: EVENT_VERSION,
                 timestamp: self.env().block_timestamp(),
                 block_number: self.env().block_number(),
                 transaction_hash,
@@ -725,16 +1087,107 @@ mod propchain_contracts {
             Ok(())
         }
 
-        /// Sets the compliance registry contract address (admin only)
+        // ============================================================================
+        // ROLE MANAGEMENT
+        // ============================================================================
+
+        /// Grants `role` to `account` (admin only)
         #[ink(message)]
-        pub fn set_compliance_registry(
-            &mut self,
-            registry: Option<AccountId>,
-        ) -> Result<(), Error> {
+        pub fn grant_role(&mut self, account: AccountId, role: Role) -> Result<(), Error> {
+            let caller = self.env().caller();
+            if caller != self.admin {
+                return Err(Error::Unauthorized);
+            }
+
+            self.roles.insert((account, role), &true);
+            self.env().emit_event(RoleGranted {
+                account,
+                role,
+                granted_by: caller,
+                timestamp: self.env().block_timestamp(),
+            });
+            Ok(())
+        }
+
+        /// Revokes `role` from `account` (admin only)
+        #[ink(message)]
+        pub fn revoke_role(&mut self, account: AccountId, role: Role) -> Result<(), Error> {
             let caller = self.env().caller();
             if caller != self.admin {
                 return Err(Error::Unauthorized);
             }
+
+            self.roles.remove((account, role));
+            self.env().emit_event(RoleRevoked {
+                account,
+                role,
+                revoked_by: caller,
+                timestamp: self.env().block_timestamp(),
+            });
+            Ok(())
+        }
+
+        /// Returns whether `account` holds `role`; the admin holds every role
+        #[ink(message)]
+        pub fn has_role(&self, account: AccountId, role: Role) -> bool {
+            account == self.admin || self.roles.get((account, role)).unwrap_or(false)
+        }
+
+        /// Helper: fails with Unauthorized unless the caller holds `role`
+        fn ensure_role(&self, role: Role) -> Result<(), Error> {
+            if self.has_role(self.env().caller(), role) {
+                Ok(())
+            } else {
+                Err(Error::Unauthorized)
+            }
+        }
+
+        /// Halts registrations, transfers and new escrows (admin or Pauser)
+        /// Refunds stay available so deposits are never locked by a pause
+        #[ink(message)]
+        pub fn pause(&mut self) -> Result<(), Error> {
+            self.ensure_role(Role::Pauser)?;
+            self.paused = true;
+            self.env().emit_event(Paused {
+                by: self.env().caller(),
+                timestamp: self.env().block_timestamp(),
+            });
+            Ok(())
+        }
+
+        /// Resumes normal operation (admin or Pauser)
+        #[ink(message)]
+        pub fn unpause(&mut self) -> Result<(), Error> {
+            self.ensure_role(Role::Pauser)?;
+            self.paused = false;
+            self.env().emit_event(Unpaused {
+                by: self.env().caller(),
+                timestamp: self.env().block_timestamp(),
+            });
+            Ok(())
+        }
+
+        /// Returns whether the registry is paused
+        #[ink(message)]
+        pub fn is_paused(&self) -> bool {
+            self.paused
+        }
+
+        /// Helper: fails with ContractPaused while the registry is paused
+        fn ensure_not_paused(&self) -> Result<(), Error> {
+            if self.paused {
+                return Err(Error::ContractPaused);
+            }
+            Ok(())
+        }
+
+        /// Sets the compliance registry contract address (admin or ComplianceManager)
+        #[ink(message)]
+        pub fn set_compliance_registry(
+            &mut self,
+            registry: Option<AccountId>,
+        ) -> Result<(), Error> {
+            self.ensure_role(Role::ComplianceManager)?;
             self.compliance_registry = registry;
             Ok(())
         }
@@ -747,44 +1200,34 @@ mod propchain_contracts {
 
         /// Helper: Check compliance for an account
         /// Returns Ok if compliant or no registry set, Err otherwise
-        fn check_compliance(&self, _account: AccountId) -> Result<(), Error> {
+        fn check_compliance(&self, account: AccountId) -> Result<(), Error> {
             // If no compliance registry is set, skip check
-            if self.compliance_registry.is_none() {
+            let Some(registry) = self.compliance_registry else {
                 return Ok(());
-            }
+            };
 
-            // In a real implementation, this would make a cross-contract call
-            // to the compliance registry to check if the account is compliant.
-            // For now, we'll implement a basic check.
-            //
-            // Example cross-contract call (commented out):
-            // let registry = self.compliance_registry.unwrap();
-            // let is_compliant: bool = ink::env::call::build_call::<Environment>()
-            //     .call(registry)
-            //     .exec_input(...)
-            //     .returns::<bool>()
-            //     .invoke();
-            //
-            // if !is_compliant {
-            //     return Err(Error::NotCompliant);
-            // }
-
-            // For demonstration, we'll just return Ok
-            // In production, implement actual cross-contract call
-            Ok(())
+            // Cross-contract call to the compliance registry
+            match compliance_client::is_compliant(registry, account) {
+                Ok(true) => Ok(()),
+                Ok(false) => Err(Error::NotCompliant),
+                Err(_) => Err(Error::ComplianceCheckFailed),
+            }
         }
 
         /// Registers a new property
         /// Optionally checks compliance if compliance registry is set
         #[ink(message)]
         pub fn register_property(&mut self, metadata: PropertyMetadata) -> Result<u64, Error> {
+            self.ensure_not_paused()?;
+            let gas_at_entry = self.gas_left();
             let caller = self.env().caller();
 
             // Check compliance for property registration (optional but recommended)
             self.check_compliance(caller)?;
 
+            let property_id = self.next_property_id;
+            self.next_property_id += 1;
             self.property_count += 1;
-            let property_id = self.property_count;
 
             let property_info = PropertyInfo {
                 id: property_id,
@@ -797,12 +1240,10 @@ mod propchain_contracts {
             // Optimized: Also store reverse mapping for faster owner lookups
             self.property_owners.insert(&property_id, &caller);
 
-            let mut owner_props = self.owner_properties.get(&caller).unwrap_or_default();
-            owner_props.push(property_id);
-            self.owner_properties.insert(&caller, &owner_props);
+            self.add_to_owner_index(caller, property_id);
 
             // Track gas usage
-            self.track_gas_usage("register_property".as_bytes());
+            self.track_gas_usage("register_property".as_bytes(), gas_at_entry);
 
             // Emit enhanced property registration event
 
@@ -810,7 +1251,7 @@ mod propchain_contracts {
             self.env().emit_event(PropertyRegistered {
                 property_id,
                 owner: caller,
-                event_version: 1,
+                event_version: EVENT_VERSION,
                 location: property_info.metadata.location.clone(),
                 size: property_info.metadata.size,
                 valuation: property_info.metadata.valuation,
@@ -827,30 +1268,92 @@ mod propchain_contracts {
         #[ink(message)]
         pub fn transfer_property(&mut self, property_id: u64, to: AccountId) -> Result<(), Error> {
             let caller = self.env().caller();
+            self.transfer_property_as(caller, property_id, to)
+        }
+
+        /// Transfers property ownership on behalf of a signer, so a relayer can pay the gas
+        /// `signature` is a recoverable ECDSA signature over `transfer_message_hash(property_id, to, nonce)`;
+        /// the signer must be allowed to transfer the property and `nonce` must be their next nonce
+        #[ink(message)]
+        pub fn transfer_property_with_signature(
+            &mut self,
+            property_id: u64,
+            to: AccountId,
+            nonce: u64,
+            signature: [u8; 65],
+        ) -> Result<(), Error> {
+            let message_hash = self.transfer_message_hash(property_id, to, nonce);
+            let mut public_key = [0u8; 33];
+            self.env()
+                .ecdsa_recover(&signature, &message_hash, &mut public_key)
+                .map_err(|_| Error::InvalidSignature)?;
+
+            // ECDSA accounts are the Blake2-256 hash of the compressed public key
+            let mut signer = [0u8; 32];
+            ink::env::hash_bytes::<ink::env::hash::Blake2x256>(&public_key, &mut signer);
+            let signer = AccountId::from(signer);
+
+            let expected_nonce = self.transfer_nonces.get(&signer).unwrap_or(0);
+            if nonce != expected_nonce {
+                return Err(Error::InvalidNonce);
+            }
+
+            self.transfer_property_as(signer, property_id, to)?;
+            self.transfer_nonces.insert(&signer, &(expected_nonce + 1));
+            Ok(())
+        }
+
+        /// Hash a signer signs to authorize `transfer_property_with_signature`
+        /// Covers the property, recipient, nonce and this contract's address
+        #[ink(message)]
+        pub fn transfer_message_hash(
+            &self,
+            property_id: u64,
+            to: AccountId,
+            nonce: u64,
+        ) -> [u8; 32] {
+            let mut output = [0u8; 32];
+            ink::env::hash_encoded::<ink::env::hash::Blake2x256, _>(
+                &(property_id, to, nonce, self.env().account_id()),
+                &mut output,
+            );
+            output
+        }
+
+        /// Gets the nonce the next signed transfer from `signer` must use
+        #[ink(message)]
+        pub fn get_transfer_nonce(&self, signer: AccountId) -> u64 {
+            self.transfer_nonces.get(&signer).unwrap_or(0)
+        }
+
+        /// Helper: Transfers a property with `operator` as the acting account
+        fn transfer_property_as(
+            &mut self,
+            operator: AccountId,
+            property_id: u64,
+            to: AccountId,
+        ) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let gas_at_entry = self.gas_left();
             let mut property = self
                 .properties
                 .get(&property_id)
                 .ok_or(Error::PropertyNotFound)?;
 
-            let approved = self.approvals.get(&property_id);
-            if property.owner != caller && Some(caller) != approved {
+            if !self.can_transfer(&property, operator) {
                 return Err(Error::Unauthorized);
             }
 
+            Self::ensure_valid_recipient(to)?;
+
             // Check compliance for recipient
             self.check_compliance(to)?;
 
             let from = property.owner;
 
-            // Remove from current owner's properties
-            let mut current_owner_props = self.owner_properties.get(&from).unwrap_or_default();
-            current_owner_props.retain(|&id| id != property_id);
-            self.owner_properties.insert(&from, &current_owner_props);
-
-            // Add to new owner's properties
-            let mut new_owner_props = self.owner_properties.get(&to).unwrap_or_default();
-            new_owner_props.push(property_id);
-            self.owner_properties.insert(&to, &new_owner_props);
+            // Move the property between the owners' indexes
+            self.remove_from_owner_index(from, property_id);
+            self.add_to_owner_index(to, property_id);
 
             // Update property owner
             property.owner = to;
@@ -862,7 +1365,7 @@ mod propchain_contracts {
             self.approvals.remove(&property_id);
 
             // Track gas usage
-            self.track_gas_usage("transfer_property".as_bytes());
+            self.track_gas_usage("transfer_property".as_bytes(), gas_at_entry);
 
             // Emit enhanced property transfer event
 
@@ -871,11 +1374,11 @@ mod propchain_contracts {
                 property_id,
                 from,
                 to,
-                event_version: 1,
+                event_version: EVENT_VERSION,
                 timestamp: self.env().block_timestamp(),
                 block_number: self.env().block_number(),
                 transaction_hash,
-                transferred_by: caller,
+                transferred_by: operator,
             });
 
             Ok(())
@@ -890,24 +1393,87 @@ mod propchain_contracts {
         /// Gets properties owned by an account
         #[ink(message)]
         pub fn get_owner_properties(&self, owner: AccountId) -> Vec<u64> {
-            self.owner_properties.get(&owner).unwrap_or_default()
+            let count = self.owner_property_counts.get(&owner).unwrap_or(0);
+            let mut property_ids = Vec::with_capacity(count as usize);
+            for chunk_index in 0..(count + OWNER_INDEX_CHUNK_SIZE - 1) / OWNER_INDEX_CHUNK_SIZE {
+                if let Some(chunk) = self.owner_property_chunks.get(&(owner, chunk_index)) {
+                    property_ids.extend(chunk);
+                }
+            }
+            property_ids
+        }
+
+        /// Gets the number of properties owned by an account
+        #[ink(message)]
+        pub fn get_owner_property_count(&self, owner: AccountId) -> u32 {
+            self.owner_property_counts.get(&owner).unwrap_or(0)
         }
 
-        /// Gets total property count
+        /// Gets the number of live properties (registered and not burned)
         #[ink(message)]
         pub fn property_count(&self) -> u64 {
             self.property_count
         }
 
-        /// Updates property metadata
+        /// Gets the number of properties ever registered, including burned ones
         #[ink(message)]
-        pub fn update_metadata(
-            &mut self,
-            property_id: u64,
-            metadata: PropertyMetadata,
-        ) -> Result<(), Error> {
+        pub fn total_ever_registered(&self) -> u64 {
+            self.next_property_id - 1
+        }
+
+        /// Burns a property, removing it from the registry (owner only)
+        /// The property ID is not reused. Fails while the property is under contract or has an
+        /// unsettled escrow; its badges are removed and pending requests and appeals closed
+        #[ink(message)]
+        pub fn burn_property(&mut self, property_id: u64) -> Result<(), Error> {
             let caller = self.env().caller();
-            let mut property = self
+            let property = self
+                .properties
+                .get(&property_id)
+                .ok_or(Error::PropertyNotFound)?;
+
+            if property.owner != caller {
+                return Err(Error::Unauthorized);
+            }
+
+            let open_escrows = self
+                .open_escrows_per_property
+                .get(&property_id)
+                .unwrap_or(0);
+            let status = self.property_statuses.get(&property_id);
+            if open_escrows > 0 || status == Some(PropertyStatus::UnderContract) {
+                return Err(Error::PropertyUnderContract);
+            }
+
+            self.clear_property_records(property_id);
+            self.remove_from_owner_index(caller, property_id);
+
+            self.properties.remove(&property_id);
+            self.property_owners.remove(&property_id);
+            self.approvals.remove(&property_id);
+            self.property_types.remove(&property_id);
+            self.property_statuses.remove(&property_id);
+            self.property_count -= 1;
+
+            self.env().emit_event(PropertyBurned {
+                property_id,
+                owner: caller,
+                timestamp: self.env().block_timestamp(),
+                block_number: self.env().block_number(),
+            });
+
+            Ok(())
+        }
+
+        /// Updates property metadata
+        #[ink(message)]
+        pub fn update_metadata(
+            &mut self,
+            property_id: u64,
+            metadata: PropertyMetadata,
+        ) -> Result<(), Error> {
+            let caller = self.env().caller();
+            let mut property = self
                 .properties
                 .get(&property_id)
                 .ok_or(Error::PropertyNotFound)?;
@@ -934,7 +1500,7 @@ mod propchain_contracts {
             self.env().emit_event(PropertyMetadataUpdated {
                 property_id,
                 owner: caller,
-                event_version: 1,
+                event_version: EVENT_VERSION,
                 old_location,
                 new_location: metadata.location,
                 old_valuation,
@@ -953,16 +1519,15 @@ mod propchain_contracts {
             &mut self,
             properties: Vec<PropertyMetadata>,
         ) -> Result<Vec<u64>, Error> {
+            self.ensure_not_paused()?;
+            let gas_at_entry = self.gas_left();
             let mut results = Vec::new();
             let caller = self.env().caller();
 
             // Pre-calculate all property IDs to avoid repeated storage reads
-            let start_id = self.property_count + 1;
-            let end_id = start_id + properties.len() as u64 - 1;
-            self.property_count = end_id;
-
-            // Get existing owner properties to avoid repeated storage reads
-            let mut owner_props = self.owner_properties.get(&caller).unwrap_or_default();
+            let start_id = self.next_property_id;
+            self.next_property_id += properties.len() as u64;
+            self.property_count += properties.len() as u64;
 
             for (i, metadata) in properties.into_iter().enumerate() {
                 let property_id = start_id + i as u64;
@@ -975,20 +1540,32 @@ mod propchain_contracts {
                 };
 
                 self.properties.insert(&property_id, &property_info);
-                owner_props.push(property_id);
+                self.add_to_owner_index(caller, property_id);
+
+                // Emit per-item event so single-event subscribers see batch registrations
+                if self.emit_individual_in_batch {
+                    self.env().emit_event(PropertyRegistered {
+                        property_id,
+                        owner: caller,
+                        event_version: EVENT_VERSION,
+                        location: property_info.metadata.location.clone(),
+                        size: property_info.metadata.size,
+                        valuation: property_info.metadata.valuation,
+                        timestamp: property_info.registered_at,
+                        block_number: self.env().block_number(),
+                        transaction_hash: [0u8; 32].into(),
+                    });
+                }
 
                 results.push(property_id);
             }
 
-            // Update owner properties once at the end
-            self.owner_properties.insert(&caller, &owner_props);
-
             // Emit enhanced batch registration event
 
             let transaction_hash: Hash = [0u8; 32].into();
             self.env().emit_event(BatchPropertyRegistered {
                 owner: caller,
-                event_version: 1,
+                event_version: EVENT_VERSION,
                 property_ids: results.clone(),
                 count: results.len() as u64,
                 timestamp: self.env().block_timestamp(),
@@ -997,11 +1574,26 @@ mod propchain_contracts {
             });
 
             // Track gas usage
-            self.track_gas_usage("batch_register_properties".as_bytes());
+            self.track_gas_usage("batch_register_properties".as_bytes(), gas_at_entry);
 
             Ok(results)
         }
 
+        /// Enables or disables per-item PropertyRegistered events in batch registration (admin or RegistrarManager)
+        /// The BatchPropertyRegistered summary event is always emitted
+        #[ink(message)]
+        pub fn set_emit_individual_in_batch(&mut self, enabled: bool) -> Result<(), Error> {
+            self.ensure_role(Role::RegistrarManager)?;
+            self.emit_individual_in_batch = enabled;
+            Ok(())
+        }
+
+        /// Returns whether batch registration emits per-item events
+        #[ink(message)]
+        pub fn get_emit_individual_in_batch(&self) -> bool {
+            self.emit_individual_in_batch
+        }
+
         /// Batch transfers multiple properties to the same recipient
         #[ink(message)]
         pub fn batch_transfer_properties(
@@ -1009,8 +1601,12 @@ mod propchain_contracts {
             property_ids: Vec<u64>,
             to: AccountId,
         ) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let gas_at_entry = self.gas_left();
             let caller = self.env().caller();
 
+            Self::ensure_valid_recipient(to)?;
+
             // Validate all properties first to avoid partial transfers
             for &property_id in &property_ids {
                 let property = self
@@ -1018,8 +1614,7 @@ mod propchain_contracts {
                     .get(&property_id)
                     .ok_or(Error::PropertyNotFound)?;
 
-                let approved = self.approvals.get(&property_id);
-                if property.owner != caller && Some(caller) != approved {
+                if !self.can_transfer(&property, caller) {
                     return Err(Error::Unauthorized);
                 }
             }
@@ -1043,17 +1638,9 @@ mod propchain_contracts {
                     .ok_or(Error::PropertyNotFound)?;
                 let current_from = property.owner;
 
-                // Remove from current owner's properties
-                let mut current_owner_props =
-                    self.owner_properties.get(&current_from).unwrap_or_default();
-                current_owner_props.retain(|&id| id != *property_id);
-                self.owner_properties
-                    .insert(&current_from, &current_owner_props);
-
-                // Add to new owner's properties
-                let mut new_owner_props = self.owner_properties.get(&to).unwrap_or_default();
-                new_owner_props.push(*property_id);
-                self.owner_properties.insert(&to, &new_owner_props);
+                // Move the property between the owners' indexes
+                self.remove_from_owner_index(current_from, *property_id);
+                self.add_to_owner_index(to, *property_id);
 
                 // Update property owner
                 property.owner = to;
@@ -1071,7 +1658,7 @@ mod propchain_contracts {
                 self.env().emit_event(BatchPropertyTransferred {
                     from,
                     to,
-                    event_version: 1,
+                    event_version: EVENT_VERSION,
                     property_ids: property_ids.clone(),
                     count: property_ids.len() as u64,
                     timestamp: self.env().block_timestamp(),
@@ -1082,7 +1669,7 @@ mod propchain_contracts {
             }
 
             // Track gas usage
-            self.track_gas_usage("batch_transfer_properties".as_bytes());
+            self.track_gas_usage("batch_transfer_properties".as_bytes(), gas_at_entry);
 
             Ok(())
         }
@@ -1093,6 +1680,7 @@ mod propchain_contracts {
             &mut self,
             updates: Vec<(u64, PropertyMetadata)>,
         ) -> Result<(), Error> {
+            let gas_at_entry = self.gas_left();
             let caller = self.env().caller();
 
             // Validate all properties first to avoid partial updates
@@ -1132,7 +1720,7 @@ mod propchain_contracts {
                 let transaction_hash: Hash = [0u8; 32].into();
                 self.env().emit_event(BatchMetadataUpdated {
                     owner: caller,
-                    event_version: 1,
+                    event_version: EVENT_VERSION,
                     property_ids: updated_property_ids,
                     count,
                     timestamp: self.env().block_timestamp(),
@@ -1142,7 +1730,7 @@ mod propchain_contracts {
             }
 
             // Track gas usage
-            self.track_gas_usage("batch_update_metadata".as_bytes());
+            self.track_gas_usage("batch_update_metadata".as_bytes(), gas_at_entry);
 
             Ok(())
         }
@@ -1153,17 +1741,20 @@ mod propchain_contracts {
             &mut self,
             transfers: Vec<(u64, AccountId)>,
         ) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let gas_at_entry = self.gas_left();
             let caller = self.env().caller();
 
             // Validate all properties first to avoid partial transfers
-            for (property_id, _) in &transfers {
+            for (property_id, to) in &transfers {
+                Self::ensure_valid_recipient(*to)?;
+
                 let property = self
                     .properties
                     .get(property_id)
                     .ok_or(Error::PropertyNotFound)?;
 
-                let approved = self.approvals.get(property_id);
-                if property.owner != caller && Some(caller) != approved {
+                if !self.can_transfer(&property, caller) {
                     return Err(Error::Unauthorized);
                 }
             }
@@ -1177,15 +1768,9 @@ mod propchain_contracts {
                     .ok_or(Error::PropertyNotFound)?;
                 let from = property.owner;
 
-                // Remove from current owner's properties
-                let mut current_owner_props = self.owner_properties.get(&from).unwrap_or_default();
-                current_owner_props.retain(|&id| id != *property_id);
-                self.owner_properties.insert(&from, &current_owner_props);
-
-                // Add to new owner's properties
-                let mut new_owner_props = self.owner_properties.get(to).unwrap_or_default();
-                new_owner_props.push(*property_id);
-                self.owner_properties.insert(to, &new_owner_props);
+                // Move the property between the owners' indexes
+                self.remove_from_owner_index(from, *property_id);
+                self.add_to_owner_index(*to, *property_id);
 
                 // Update property owner
                 property.owner = *to;
@@ -1209,7 +1794,7 @@ mod propchain_contracts {
                 let transaction_hash: Hash = [0u8; 32].into();
                 self.env().emit_event(BatchPropertyTransferredToMultiple {
                     from,
-                    event_version: 1,
+                    event_version: EVENT_VERSION,
                     transfers: transfers.clone(),
                     count: transfers.len() as u64,
                     timestamp: self.env().block_timestamp(),
@@ -1220,7 +1805,10 @@ mod propchain_contracts {
             }
 
             // Track gas usage
-            self.track_gas_usage("batch_transfer_properties_to_multiple".as_bytes());
+            self.track_gas_usage(
+                "batch_transfer_properties_to_multiple".as_bytes(),
+                gas_at_entry,
+            );
 
             Ok(())
         }
@@ -1247,7 +1835,7 @@ mod propchain_contracts {
                     property_id,
                     owner: caller,
                     approved: account,
-                    event_version: 1,
+                    event_version: EVENT_VERSION,
                     timestamp: self.env().block_timestamp(),
                     block_number: self.env().block_number(),
                     transaction_hash,
@@ -1258,7 +1846,7 @@ mod propchain_contracts {
                 self.env().emit_event(ApprovalCleared {
                     property_id,
                     owner: caller,
-                    event_version: 1,
+                    event_version: EVENT_VERSION,
                     timestamp: self.env().block_timestamp(),
                     block_number: self.env().block_number(),
                     transaction_hash,
@@ -1274,6 +1862,224 @@ mod propchain_contracts {
             self.approvals.get(&property_id)
         }
 
+        /// Approves or revokes an operator for all of the caller's properties
+        #[ink(message)]
+        pub fn set_approval_for_all(
+            &mut self,
+            operator: AccountId,
+            approved: bool,
+        ) -> Result<(), Error> {
+            let caller = self.env().caller();
+
+            if approved {
+                self.operator_approvals.insert((&caller, &operator), &true);
+            } else {
+                self.operator_approvals.remove((&caller, &operator));
+            }
+
+            self.env().emit_event(ApprovalForAll {
+                owner: caller,
+                operator,
+                approved,
+                event_version: EVENT_VERSION,
+                timestamp: self.env().block_timestamp(),
+                block_number: self.env().block_number(),
+            });
+
+            Ok(())
+        }
+
+        /// Checks whether an operator may transfer all of an owner's properties
+        #[ink(message)]
+        pub fn is_approved_for_all(&self, owner: AccountId, operator: AccountId) -> bool {
+            self.operator_approvals
+                .get((&owner, &operator))
+                .unwrap_or(false)
+        }
+
+        /// Helper: Appends an appeal to the pending index
+        fn add_pending_appeal(&mut self, appeal_id: u64) {
+            let slot = self.pending_appeal_count;
+            self.pending_appeals.insert(&slot, &appeal_id);
+            self.pending_appeal_positions.insert(&appeal_id, &slot);
+            self.pending_appeal_count = slot + 1;
+        }
+
+        /// Helper: Removes an appeal from the pending index by moving the last
+        /// pending appeal into its slot
+        fn remove_pending_appeal(&mut self, appeal_id: u64) {
+            let Some(slot) = self.pending_appeal_positions.take(&appeal_id) else {
+                return;
+            };
+            let last = self.pending_appeal_count - 1;
+            if slot != last {
+                if let Some(moved) = self.pending_appeals.get(&last) {
+                    self.pending_appeals.insert(&slot, &moved);
+                    self.pending_appeal_positions.insert(&moved, &slot);
+                }
+            }
+            self.pending_appeals.remove(&last);
+            self.pending_appeal_count = last;
+        }
+
+        /// Helper: Records the outcome of a pending appeal, drops it from the
+        /// pending and active indexes and emits `AppealResolved`
+        fn close_appeal(
+            &mut self,
+            appeal_id: u64,
+            mut appeal: Appeal,
+            approved: bool,
+            resolution: String,
+        ) {
+            let caller = self.env().caller();
+            let timestamp = self.env().block_timestamp();
+
+            appeal.status = if approved {
+                AppealStatus::Approved
+            } else {
+                AppealStatus::Rejected
+            };
+            appeal.resolved_by = Some(caller);
+            appeal.resolved_at = Some(timestamp);
+            appeal.resolution = resolution.clone();
+
+            self.appeals.insert(&appeal_id, &appeal);
+            self.remove_pending_appeal(appeal_id);
+            if self
+                .active_appeals
+                .get(&(appeal.property_id, appeal.badge_type))
+                == Some(appeal_id)
+            {
+                self.active_appeals
+                    .remove(&(appeal.property_id, appeal.badge_type));
+            }
+
+            self.env().emit_event(AppealResolved {
+                appeal_id,
+                property_id: appeal.property_id,
+                resolved_by: caller,
+                approved,
+                event_version: EVENT_VERSION,
+                resolution,
+                timestamp,
+                block_number: self.env().block_number(),
+                transaction_hash: [0u8; 32].into(),
+            });
+        }
+
+        /// Helper: Drops a burned property's badges, cancels its pending verification
+        /// requests and rejects its pending appeals
+        fn clear_property_records(&mut self, property_id: u64) {
+            for id in 0..self.next_badge_type_id {
+                self.property_badges
+                    .remove(&(property_id, BadgeType::from_id(id)));
+            }
+
+            let request_ids = self
+                .property_verification_requests
+                .get(&property_id)
+                .unwrap_or_default();
+            for request_id in request_ids {
+                let Some(mut request) = self.verification_requests.get(&request_id) else {
+                    continue;
+                };
+                if request.status != VerificationStatus::Pending {
+                    continue;
+                }
+                request.status = VerificationStatus::Cancelled;
+                self.verification_requests.insert(&request_id, &request);
+                self.env().emit_event(VerificationCancelled {
+                    request_id,
+                    property_id,
+                    requester: request.requester,
+                    event_version: EVENT_VERSION,
+                    timestamp: self.env().block_timestamp(),
+                    block_number: self.env().block_number(),
+                    transaction_hash: [0u8; 32].into(),
+                });
+            }
+
+            let appeal_ids = self.property_appeals.get(&property_id).unwrap_or_default();
+            for appeal_id in appeal_ids {
+                if let Some(appeal) = self.appeals.get(&appeal_id) {
+                    if appeal.status == AppealStatus::Pending {
+                        self.close_appeal(appeal_id, appeal, false, "Property burned".to_string());
+                    }
+                }
+            }
+        }
+
+        /// Helper: Appends a property to the end of an owner's index
+        fn add_to_owner_index(&mut self, owner: AccountId, property_id: u64) {
+            let count = self.owner_property_counts.get(&owner).unwrap_or(0);
+            let key = (owner, count / OWNER_INDEX_CHUNK_SIZE);
+            let mut chunk = self.owner_property_chunks.get(&key).unwrap_or_default();
+            chunk.push(property_id);
+            self.owner_property_chunks.insert(&key, &chunk);
+            self.owner_property_positions.insert(&property_id, &count);
+            self.owner_property_counts.insert(&owner, &(count + 1));
+        }
+
+        /// Helper: Removes a property from an owner's index by moving the owner's
+        /// last property into its slot, so at most two chunks are rewritten
+        fn remove_from_owner_index(&mut self, owner: AccountId, property_id: u64) {
+            let Some(position) = self.owner_property_positions.get(&property_id) else {
+                return;
+            };
+            let count = self.owner_property_counts.get(&owner).unwrap_or(0);
+            if position >= count {
+                return;
+            }
+
+            let last = count - 1;
+            let last_key = (owner, last / OWNER_INDEX_CHUNK_SIZE);
+            let mut last_chunk = self
+                .owner_property_chunks
+                .get(&last_key)
+                .unwrap_or_default();
+            let Some(last_id) = last_chunk.pop() else {
+                return;
+            };
+
+            if position != last {
+                let key = (owner, position / OWNER_INDEX_CHUNK_SIZE);
+                let slot = (position % OWNER_INDEX_CHUNK_SIZE) as usize;
+                if key == last_key {
+                    last_chunk[slot] = last_id;
+                } else {
+                    let mut chunk = self.owner_property_chunks.get(&key).unwrap_or_default();
+                    chunk[slot] = last_id;
+                    self.owner_property_chunks.insert(&key, &chunk);
+                }
+                self.owner_property_positions.insert(&last_id, &position);
+            }
+
+            if last_chunk.is_empty() {
+                self.owner_property_chunks.remove(&last_key);
+            } else {
+                self.owner_property_chunks.insert(&last_key, &last_chunk);
+            }
+            self.owner_property_positions.remove(&property_id);
+            self.owner_property_counts.insert(&owner, &last);
+        }
+
+        /// Rejects the zero account as a transfer recipient; retiring a property
+        /// goes through `burn_property` instead
+        fn ensure_valid_recipient(to: AccountId) -> Result<(), Error> {
+            if to == AccountId::from([0u8; 32]) {
+                return Err(Error::InvalidRecipient);
+            }
+            Ok(())
+        }
+
+        /// Whether `caller` may transfer `property`: its owner, its approved account,
+        /// or an operator approved for all of the owner's properties
+        fn can_transfer(&self, property: &PropertyInfo, caller: AccountId) -> bool {
+            property.owner == caller
+                || self.approvals.get(&property.id) == Some(caller)
+                || self.is_approved_for_all(property.owner, caller)
+        }
+
         /// Creates a new escrow for property transfer
         /// Seller creates escrow and specifies the buyer
         #[ink(message)]
@@ -1283,6 +2089,7 @@ mod propchain_contracts {
             buyer: AccountId,
             amount: u128,
         ) -> Result<u64, Error> {
+            self.ensure_not_paused()?;
             let caller = self.env().caller();
             let property = self
                 .properties
@@ -1294,6 +2101,31 @@ mod propchain_contracts {
                 return Err(Error::Unauthorized);
             }
 
+            // The seller cannot escrow a property to themselves
+            if buyer == property.owner {
+                return Err(Error::InvalidEscrowParties);
+            }
+
+            if amount < self.min_escrow_amount {
+                return Err(Error::EscrowAmountTooLow);
+            }
+
+            // Enforce the per-seller open escrow limit
+            let open_escrows = self.open_escrows_per_seller.get(&caller).unwrap_or(0);
+            if let Some(max_open) = self.max_open_escrows_per_seller {
+                if open_escrows >= max_open {
+                    return Err(Error::TooManyOpenEscrows);
+                }
+            }
+            self.open_escrows_per_seller
+                .insert(&caller, &(open_escrows + 1));
+            let property_escrows = self
+                .open_escrows_per_property
+                .get(&property_id)
+                .unwrap_or(0);
+            self.open_escrows_per_property
+                .insert(&property_id, &(property_escrows + 1));
+
             self.escrow_count += 1;
             let escrow_id = self.escrow_count;
 
@@ -1303,10 +2135,11 @@ mod propchain_contracts {
                 buyer,
                 seller: property.owner,
                 amount,
-                released: false,
+                status: EscrowStatus::Created,
             };
 
             self.escrows.insert(&escrow_id, &escrow_info);
+            self.update_property_status(property_id, PropertyStatus::UnderContract);
 
             // Emit enhanced escrow created event
 
@@ -1316,7 +2149,7 @@ mod propchain_contracts {
                 property_id,
                 buyer,
                 seller: property.owner,
-                event_version: 1,
+                event_version: EVENT_VERSION,
                 amount,
                 timestamp: self.env().block_timestamp(),
                 block_number: self.env().block_number(),
@@ -1326,13 +2159,45 @@ mod propchain_contracts {
             Ok(escrow_id)
         }
 
+        /// Deposits the escrow amount (buyer only)
+        /// The transferred value must equal the escrow amount
+        #[ink(message, payable)]
+        pub fn fund_escrow(&mut self, escrow_id: u64) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            let mut escrow = self.escrows.get(&escrow_id).ok_or(Error::EscrowNotFound)?;
+
+            if escrow.released() {
+                return Err(Error::EscrowAlreadyReleased);
+            }
+            if escrow.status != EscrowStatus::Created {
+                return Err(Error::InvalidEscrowStatus);
+            }
+
+            // Only buyer can fund
+            if escrow.buyer != caller {
+                return Err(Error::Unauthorized);
+            }
+
+            if self.env().transferred_value() != escrow.amount {
+                return Err(Error::EscrowAmountMismatch);
+            }
+
+            escrow.status = EscrowStatus::Funded;
+            self.escrows.insert(&escrow_id, &escrow);
+
+            Ok(())
+        }
+
         /// Releases escrow funds and transfers property
+        /// Deposited funds are paid out to the seller. The seller agreed to the sale by creating
+        /// the escrow, so the property moves without a separate approval
         #[ink(message)]
         pub fn release_escrow(&mut self, escrow_id: u64) -> Result<(), Error> {
             let caller = self.env().caller();
             let mut escrow = self.escrows.get(&escrow_id).ok_or(Error::EscrowNotFound)?;
 
-            if escrow.released {
+            if escrow.released() {
                 return Err(Error::EscrowAlreadyReleased);
             }
 
@@ -1341,11 +2206,33 @@ mod propchain_contracts {
                 return Err(Error::Unauthorized);
             }
 
-            // Transfer property
-            self.transfer_property(escrow.property_id, escrow.buyer)?;
+            // The seller must still own the property
+            let property = self
+                .properties
+                .get(&escrow.property_id)
+                .ok_or(Error::PropertyNotFound)?;
+            if property.owner != escrow.seller {
+                return Err(Error::InvalidEscrowParties);
+            }
+
+            // The buyer must have paid before taking the property, unless nothing is owed
+            if escrow.status != EscrowStatus::Funded && escrow.amount != 0 {
+                return Err(Error::InvalidEscrowStatus);
+            }
+
+            // Transfer property on the seller's behalf
+            self.transfer_property_as(escrow.seller, escrow.property_id, escrow.buyer)?;
+
+            if escrow.status == EscrowStatus::Funded {
+                self.env()
+                    .transfer(escrow.seller, escrow.amount)
+                    .map_err(|_| Error::EscrowTransferFailed)?;
+            }
 
-            escrow.released = true;
+            escrow.status = EscrowStatus::Released;
             self.escrows.insert(&escrow_id, &escrow);
+            self.close_open_escrow(escrow.seller, escrow.property_id);
+            self.update_property_status(escrow.property_id, PropertyStatus::Sold);
 
             // Emit enhanced escrow released event
 
@@ -1354,7 +2241,7 @@ mod propchain_contracts {
                 escrow_id,
                 property_id: escrow.property_id,
                 buyer: escrow.buyer,
-                event_version: 1,
+                event_version: EVENT_VERSION,
                 amount: escrow.amount,
                 timestamp: self.env().block_timestamp(),
                 block_number: self.env().block_number(),
@@ -1366,22 +2253,39 @@ mod propchain_contracts {
         }
 
         /// Refunds escrow funds
+        /// Deposited funds are returned to the buyer. The seller can refund at any time; the
+        /// buyer can reclaim the deposit once release is impossible, i.e. the seller no longer
+        /// owns the property or the registry is paused
         #[ink(message)]
         pub fn refund_escrow(&mut self, escrow_id: u64) -> Result<(), Error> {
             let caller = self.env().caller();
             let mut escrow = self.escrows.get(&escrow_id).ok_or(Error::EscrowNotFound)?;
 
-            if escrow.released {
+            if escrow.released() {
                 return Err(Error::EscrowAlreadyReleased);
             }
 
-            // Only seller can refund
-            if escrow.seller != caller {
+            let seller_owns_property =
+                self.property_owners.get(&escrow.property_id) == Some(escrow.seller);
+            let buyer_may_reclaim =
+                escrow.buyer == caller && (!seller_owns_property || self.paused);
+            if escrow.seller != caller && !buyer_may_reclaim {
                 return Err(Error::Unauthorized);
             }
 
-            escrow.released = true;
+            if escrow.status == EscrowStatus::Funded {
+                self.env()
+                    .transfer(escrow.buyer, escrow.amount)
+                    .map_err(|_| Error::EscrowTransferFailed)?;
+            }
+
+            escrow.status = EscrowStatus::Refunded;
             self.escrows.insert(&escrow_id, &escrow);
+            self.close_open_escrow(escrow.seller, escrow.property_id);
+            // The deal fell through, so the property goes back on the market
+            if seller_owns_property {
+                self.update_property_status(escrow.property_id, PropertyStatus::Listed);
+            }
 
             // Emit enhanced escrow refunded event
 
@@ -1390,7 +2294,7 @@ mod propchain_contracts {
                 escrow_id,
                 property_id: escrow.property_id,
                 seller: escrow.seller,
-                event_version: 1,
+                event_version: EVENT_VERSION,
                 amount: escrow.amount,
                 timestamp: self.env().block_timestamp(),
                 block_number: self.env().block_number(),
@@ -1407,10 +2311,62 @@ mod propchain_contracts {
             self.escrows.get(&escrow_id)
         }
 
+        /// Sets the maximum number of open escrows per seller (admin or RegistrarManager)
+        /// `None` removes the limit
+        #[ink(message)]
+        pub fn set_max_open_escrows_per_seller(
+            &mut self,
+            max_open: Option<u32>,
+        ) -> Result<(), Error> {
+            self.ensure_role(Role::RegistrarManager)?;
+            self.max_open_escrows_per_seller = max_open;
+            Ok(())
+        }
+
+        /// Gets the maximum number of open escrows per seller
+        #[ink(message)]
+        pub fn get_max_open_escrows_per_seller(&self) -> Option<u32> {
+            self.max_open_escrows_per_seller
+        }
+
+        /// Sets the minimum escrow amount (admin or RegistrarManager)
+        /// `0` removes the minimum
+        #[ink(message)]
+        pub fn set_min_escrow_amount(&mut self, min_amount: u128) -> Result<(), Error> {
+            self.ensure_role(Role::RegistrarManager)?;
+            self.min_escrow_amount = min_amount;
+            Ok(())
+        }
+
+        /// Gets the minimum escrow amount
+        #[ink(message)]
+        pub fn get_min_escrow_amount(&self) -> u128 {
+            self.min_escrow_amount
+        }
+
+        /// Gets the number of open escrows for a seller
+        #[ink(message)]
+        pub fn get_open_escrow_count(&self, seller: AccountId) -> u32 {
+            self.open_escrows_per_seller.get(&seller).unwrap_or(0)
+        }
+
+        /// Helper: Frees a seller's open escrow slot once an escrow is settled
+        fn close_open_escrow(&mut self, seller: AccountId, property_id: u64) {
+            let open_escrows = self.open_escrows_per_seller.get(&seller).unwrap_or(0);
+            self.open_escrows_per_seller
+                .insert(&seller, &open_escrows.saturating_sub(1));
+            let property_escrows = self
+                .open_escrows_per_property
+                .get(&property_id)
+                .unwrap_or(0);
+            self.open_escrows_per_property
+                .insert(&property_id, &property_escrows.saturating_sub(1));
+        }
+
         /// Portfolio Management: Gets summary statistics for properties owned by an account
         #[ink(message)]
         pub fn get_portfolio_summary(&self, owner: AccountId) -> PortfolioSummary {
-            let property_ids = self.owner_properties.get(&owner).unwrap_or_default();
+            let property_ids = self.get_owner_properties(owner);
             let mut total_valuation = 0u128;
             let mut total_size = 0u64;
             let mut property_count = 0u64;
@@ -1446,7 +2402,7 @@ mod propchain_contracts {
         /// Portfolio Management: Gets detailed portfolio information for an owner
         #[ink(message)]
         pub fn get_portfolio_details(&self, owner: AccountId) -> PortfolioDetails {
-            let property_ids = self.owner_properties.get(&owner).unwrap_or_default();
+            let property_ids = self.get_owner_properties(owner);
             let mut properties = Vec::new();
 
             // Optimized loop with capacity pre-allocation
@@ -1486,7 +2442,7 @@ mod propchain_contracts {
             // Optimized loop with early termination possibility
             // Note: This is expensive for large datasets. Consider off-chain indexing.
             let mut i = 1u64;
-            while i <= self.property_count {
+            while i < self.next_property_id {
                 if let Some(property) = self.properties.get(&i) {
                     total_valuation += property.metadata.valuation;
                     total_size += property.metadata.size;
@@ -1518,14 +2474,40 @@ mod propchain_contracts {
             }
         }
 
+        /// Pages through all properties in ID order, skipping missing entries.
+        /// Pass the returned `next_cursor` as `start` to fetch the following page.
+        #[ink(message)]
+        pub fn get_all_properties_paged(&self, start: u64, limit: u64) -> PropertyPage {
+            let mut properties = Vec::new();
+
+            let mut i = start.max(1);
+            while i < self.next_property_id && (properties.len() as u64) < limit {
+                if let Some(property) = self.properties.get(&i) {
+                    properties.push(property);
+                }
+                i += 1;
+            }
+
+            PropertyPage {
+                properties,
+                next_cursor: if i < self.next_property_id {
+                    Some(i)
+                } else {
+                    None
+                },
+            }
+        }
+
         /// Analytics: Gets properties within a price range
+        /// Results are always returned sorted by property id ascending,
+        /// independent of how the underlying storage is iterated
         #[ink(message)]
         pub fn get_properties_by_price_range(&self, min_price: u128, max_price: u128) -> Vec<u64> {
             let mut result = Vec::new();
 
             // Optimized loop with pre-check to reduce iterations
             let mut i = 1u64;
-            while i <= self.property_count {
+            while i < self.next_property_id {
                 if let Some(property) = self.properties.get(&i) {
                     // Unrolled condition check for better performance
                     let valuation = property.metadata.valuation;
@@ -1536,17 +2518,21 @@ mod propchain_contracts {
                 i += 1;
             }
 
+            // Ids are unique, so an unstable sort still yields a deterministic order
+            result.sort_unstable();
             result
         }
 
         /// Analytics: Gets properties by size range
+        /// Results are always returned sorted by property id ascending,
+        /// independent of how the underlying storage is iterated
         #[ink(message)]
         pub fn get_properties_by_size_range(&self, min_size: u64, max_size: u64) -> Vec<u64> {
             let mut result = Vec::new();
 
             // Optimized loop with pre-check to reduce iterations
             let mut i = 1u64;
-            while i <= self.property_count {
+            while i < self.next_property_id {
                 if let Some(property) = self.properties.get(&i) {
                     // Unrolled condition check for better performance
                     let size = property.metadata.size;
@@ -1557,51 +2543,276 @@ mod propchain_contracts {
                 i += 1;
             }
 
+            // Ids are unique, so an unstable sort still yields a deterministic order
+            result.sort_unstable();
             result
         }
 
-        /// Helper method to track gas usage
-        fn track_gas_usage(&mut self, _operation: &[u8]) {
-            // In a real implementation, this would measure actual gas consumption
-            // For demonstration purposes, we increment counters
-            let gas_used = 10000; // Placeholder value
-            self.gas_tracker.operation_count += 1;
-            self.gas_tracker.last_operation_gas = gas_used;
-            self.gas_tracker.total_gas_used += gas_used;
+        /// Search: Gets up to `limit` properties near a point, sorted by property id ascending
+        /// Uses a square bounding box of +/- `radius_microdeg` on both axes rather than a true
+        /// great-circle distance, so it over-includes corners and ignores longitude convergence
+        /// towards the poles. Properties without coordinates are never returned.
+        #[ink(message)]
+        pub fn get_properties_near(
+            &self,
+            lat: i32,
+            lon: i32,
+            radius_microdeg: u32,
+            limit: u64,
+        ) -> Vec<u64> {
+            let mut result = Vec::new();
+            let radius = radius_microdeg as i64;
 
-            // Track min/max gas usage
-            if gas_used < self.gas_tracker.min_gas_used {
-                self.gas_tracker.min_gas_used = gas_used;
-            }
-            if gas_used > self.gas_tracker.max_gas_used {
-                self.gas_tracker.max_gas_used = gas_used;
+            let mut i = 1u64;
+            while i < self.next_property_id && (result.len() as u64) < limit {
+                if let Some(property) = self.properties.get(&i) {
+                    if let (Some(p_lat), Some(p_lon)) =
+                        (property.metadata.latitude, property.metadata.longitude)
+                    {
+                        let d_lat = (p_lat as i64 - lat as i64).abs();
+                        let d_lon = (p_lon as i64 - lon as i64).abs();
+                        if d_lat <= radius && d_lon <= radius {
+                            result.push(property.id);
+                        }
+                    }
+                }
+                i += 1;
             }
-        }
 
-        /// Gas Monitoring: Tracks gas usage for operations
-        #[ink(message)]
-        pub fn get_gas_metrics(&self) -> GasMetrics {
-            GasMetrics {
-                last_operation_gas: self.gas_tracker.last_operation_gas,
-                average_operation_gas: if self.gas_tracker.operation_count > 0 {
-                    self.gas_tracker.total_gas_used / self.gas_tracker.operation_count
-                } else {
-                    0
-                },
-                total_operations: self.gas_tracker.operation_count,
-                min_gas_used: if self.gas_tracker.min_gas_used == u64::MAX {
-                    0
-                } else {
-                    self.gas_tracker.min_gas_used
-                },
-                max_gas_used: self.gas_tracker.max_gas_used,
-            }
+            result
         }
 
-        /// Performance Monitoring: Gets optimization recommendations
+        /// Sets the type of a property (owner only)
         #[ink(message)]
-        pub fn get_performance_recommendations(&self) -> Vec<String> {
-            let mut recommendations = Vec::new();
+        pub fn set_property_type(
+            &mut self,
+            property_id: u64,
+            property_type: PropertyType,
+        ) -> Result<(), Error> {
+            let caller = self.env().caller();
+            let property = self
+                .properties
+                .get(&property_id)
+                .ok_or(Error::PropertyNotFound)?;
+
+            if property.owner != caller {
+                return Err(Error::Unauthorized);
+            }
+
+            self.property_types.insert(&property_id, &property_type);
+            Ok(())
+        }
+
+        /// Gets the type of a property, if set
+        #[ink(message)]
+        pub fn get_property_type(&self, property_id: u64) -> Option<PropertyType> {
+            self.property_types.get(&property_id)
+        }
+
+        /// Sets the listing status of a property (owner only)
+        /// Escrows also move the status: UnderContract on creation, Sold on release,
+        /// and back to Listed on refund
+        #[ink(message)]
+        pub fn set_property_status(
+            &mut self,
+            property_id: u64,
+            status: PropertyStatus,
+        ) -> Result<(), Error> {
+            let caller = self.env().caller();
+            let property = self
+                .properties
+                .get(&property_id)
+                .ok_or(Error::PropertyNotFound)?;
+
+            if property.owner != caller {
+                return Err(Error::Unauthorized);
+            }
+
+            self.update_property_status(property_id, status);
+            Ok(())
+        }
+
+        /// Gets the listing status of a property
+        #[ink(message)]
+        pub fn get_property_status(&self, property_id: u64) -> Option<PropertyStatus> {
+            if !self.properties.contains(&property_id) {
+                return None;
+            }
+            Some(
+                self.property_statuses
+                    .get(&property_id)
+                    .unwrap_or(PropertyStatus::Unlisted),
+            )
+        }
+
+        /// Gets properties with the given listing status, sorted by property id ascending
+        #[ink(message)]
+        pub fn get_properties_by_status(&self, status: PropertyStatus) -> Vec<u64> {
+            let mut result = Vec::new();
+
+            let mut i = 1u64;
+            while i < self.next_property_id {
+                if self.properties.contains(&i)
+                    && self
+                        .property_statuses
+                        .get(&i)
+                        .unwrap_or(PropertyStatus::Unlisted)
+                        == status
+                {
+                    result.push(i);
+                }
+                i += 1;
+            }
+
+            result
+        }
+
+        /// Records a status change and emits PropertyStatusChanged if it differs
+        fn update_property_status(&mut self, property_id: u64, new_status: PropertyStatus) {
+            let old_status = self
+                .property_statuses
+                .get(&property_id)
+                .unwrap_or(PropertyStatus::Unlisted);
+            if old_status == new_status {
+                return;
+            }
+
+            self.property_statuses.insert(&property_id, &new_status);
+            self.env().emit_event(PropertyStatusChanged {
+                property_id,
+                old_status,
+                new_status,
+                timestamp: self.env().block_timestamp(),
+            });
+        }
+
+        /// Search: Gets properties matching an optional type and price band in a single paged scan
+        /// Results are sorted by property id ascending; `offset` and `limit` apply to the matches
+        #[ink(message)]
+        pub fn search_properties(
+            &self,
+            property_type: Option<PropertyType>,
+            min_price: Option<u128>,
+            max_price: Option<u128>,
+            offset: u32,
+            limit: u32,
+        ) -> Vec<u64> {
+            let mut result = Vec::new();
+            let min_price = min_price.unwrap_or(0);
+            let max_price = max_price.unwrap_or(u128::MAX);
+            let mut skipped = 0u32;
+
+            let mut i = 1u64;
+            while i < self.next_property_id && (result.len() as u32) < limit {
+                if let Some(property) = self.properties.get(&i) {
+                    let valuation = property.metadata.valuation;
+                    let type_matches = match &property_type {
+                        Some(wanted) => self.property_types.get(&i).as_ref() == Some(wanted),
+                        None => true,
+                    };
+
+                    if type_matches && valuation >= min_price && valuation <= max_price {
+                        if skipped < offset {
+                            skipped += 1;
+                        } else {
+                            result.push(property.id);
+                        }
+                    }
+                }
+                i += 1;
+            }
+
+            result
+        }
+
+        /// Gas remaining in the current call.
+        ///
+        /// The off-chain test environment has no gas meter, so unit tests approximate
+        /// consumption from the contract's storage reads and writes instead.
+        fn gas_left(&self) -> u64 {
+            #[cfg(not(test))]
+            {
+                self.env().gas_left()
+            }
+            #[cfg(test)]
+            {
+                let (reads, writes) = ink::env::test::get_contract_storage_rw::<
+                    ink::env::DefaultEnvironment,
+                >(&self.env().account_id());
+                u64::MAX - (reads as u64 * 1_000 + writes as u64 * 10_000)
+            }
+        }
+
+        /// Helper method to track gas usage
+        ///
+        /// `gas_at_entry` is the value of `gas_left` captured when the message started.
+        fn track_gas_usage(&mut self, operation: &[u8], gas_at_entry: u64) {
+            let gas_used = gas_at_entry.saturating_sub(self.gas_left());
+            self.gas_tracker.operation_count += 1;
+            self.gas_tracker.last_operation_gas = gas_used;
+            self.gas_tracker.total_gas_used =
+                self.gas_tracker.total_gas_used.saturating_add(gas_used);
+
+            // Track min/max gas usage; min_gas_used starts at u64::MAX until the first operation
+            if gas_used < self.gas_tracker.min_gas_used {
+                self.gas_tracker.min_gas_used = gas_used;
+            }
+            if gas_used > self.gas_tracker.max_gas_used {
+                self.gas_tracker.max_gas_used = gas_used;
+            }
+
+            if self.gas_alert_threshold > 0 && gas_used > self.gas_alert_threshold {
+                self.env().emit_event(HighGasUsage {
+                    operation: String::from_utf8_lossy(operation).into_owned(),
+                    gas_used,
+                    threshold: self.gas_alert_threshold,
+                    timestamp: self.env().block_timestamp(),
+                    block_number: self.env().block_number(),
+                });
+            }
+        }
+
+        /// Sets the gas above which an operation emits HighGasUsage (admin only, 0 disables)
+        #[ink(message)]
+        pub fn set_gas_alert_threshold(&mut self, threshold: u64) -> Result<(), Error> {
+            let caller = self.env().caller();
+            if caller != self.admin {
+                return Err(Error::Unauthorized);
+            }
+            self.gas_alert_threshold = threshold;
+            Ok(())
+        }
+
+        /// Returns the high gas alert threshold (0 = disabled)
+        #[ink(message)]
+        pub fn get_gas_alert_threshold(&self) -> u64 {
+            self.gas_alert_threshold
+        }
+
+        /// Gas Monitoring: Tracks gas usage for operations
+        #[ink(message)]
+        pub fn get_gas_metrics(&self) -> GasMetrics {
+            GasMetrics {
+                last_operation_gas: self.gas_tracker.last_operation_gas,
+                average_operation_gas: if self.gas_tracker.operation_count > 0 {
+                    self.gas_tracker.total_gas_used / self.gas_tracker.operation_count
+                } else {
+                    0
+                },
+                total_operations: self.gas_tracker.operation_count,
+                min_gas_used: if self.gas_tracker.min_gas_used == u64::MAX {
+                    0
+                } else {
+                    self.gas_tracker.min_gas_used
+                },
+                max_gas_used: self.gas_tracker.max_gas_used,
+            }
+        }
+
+        /// Performance Monitoring: Gets optimization recommendations
+        #[ink(message)]
+        pub fn get_performance_recommendations(&self) -> Vec<String> {
+            let mut recommendations = Vec::new();
 
             // Check for high gas usage operations
             let avg_gas = if self.gas_tracker.operation_count > 0 {
@@ -1623,7 +2834,9 @@ mod propchain_contracts {
             }
 
             // Check for inconsistent gas usage
-            if self.gas_tracker.max_gas_used > self.gas_tracker.min_gas_used * 10 {
+            if self.gas_tracker.operation_count > 0
+                && self.gas_tracker.max_gas_used > self.gas_tracker.min_gas_used.saturating_mul(10)
+            {
                 recommendations
                     .push("Gas usage varies significantly - review operation patterns".to_string());
             }
@@ -1642,13 +2855,11 @@ mod propchain_contracts {
         // BADGE MANAGEMENT SYSTEM
         // ============================================================================
 
-        /// Adds or removes a badge verifier (admin only)
+        /// Adds or removes a badge verifier (admin or VerifierManager)
         #[ink(message)]
         pub fn set_verifier(&mut self, verifier: AccountId, authorized: bool) -> Result<(), Error> {
             let caller = self.env().caller();
-            if caller != self.admin {
-                return Err(Error::Unauthorized);
-            }
+            self.ensure_role(Role::VerifierManager)?;
 
             self.badge_verifiers.insert(&verifier, &authorized);
 
@@ -1659,7 +2870,7 @@ mod propchain_contracts {
                 verifier,
                 authorized,
                 updated_by: caller,
-                event_version: 1,
+                event_version: EVENT_VERSION,
                 timestamp,
                 block_number,
                 transaction_hash: [0u8; 32].into(),
@@ -1690,6 +2901,10 @@ mod propchain_contracts {
                 return Err(Error::NotVerifier);
             }
 
+            if !self.is_badge_type_registered(badge_type) {
+                return Err(Error::InvalidBadgeType);
+            }
+
             // Check if property exists
             self.properties
                 .get(&property_id)
@@ -1723,7 +2938,7 @@ mod propchain_contracts {
                 property_id,
                 badge_type,
                 issued_by: caller,
-                event_version: 1,
+                event_version: EVENT_VERSION,
                 expires_at,
                 metadata_url,
                 timestamp,
@@ -1772,7 +2987,7 @@ mod propchain_contracts {
                 property_id,
                 badge_type,
                 revoked_by: caller,
-                event_version: 1,
+                event_version: EVENT_VERSION,
                 reason,
                 timestamp,
                 block_number,
@@ -1782,6 +2997,102 @@ mod propchain_contracts {
             Ok(())
         }
 
+        /// Reinstates a revoked badge directly, e.g. after a mistaken revocation (verifier or admin only)
+        #[ink(message)]
+        pub fn reinstate_badge(
+            &mut self,
+            property_id: u64,
+            badge_type: BadgeType,
+        ) -> Result<(), Error> {
+            let caller = self.env().caller();
+
+            if !self.is_verifier(caller) && caller != self.admin {
+                return Err(Error::NotVerifier);
+            }
+
+            let mut badge = self
+                .property_badges
+                .get(&(property_id, badge_type))
+                .ok_or(Error::BadgeNotFound)?;
+
+            if !badge.revoked {
+                return Err(Error::BadgeNotRevoked);
+            }
+
+            badge.revoked = false;
+            badge.revoked_at = None;
+            badge.revocation_reason = String::new();
+            self.property_badges
+                .insert(&(property_id, badge_type), &badge);
+
+            // A pending appeal against the revocation has nothing left to decide
+            if let Some(appeal_id) = self.active_appeals.get(&(property_id, badge_type)) {
+                if let Some(appeal) = self.appeals.get(&appeal_id) {
+                    self.close_appeal(appeal_id, appeal, true, String::from("Badge reinstated"));
+                }
+            }
+
+            let timestamp = self.env().block_timestamp();
+            let block_number = self.env().block_number();
+            self.env().emit_event(BadgeReinstated {
+                property_id,
+                badge_type,
+                reinstated_by: caller,
+                event_version: EVENT_VERSION,
+                timestamp,
+                block_number,
+                transaction_hash: [0u8; 32].into(),
+            });
+
+            Ok(())
+        }
+
+        /// Updates the metadata URL of an active badge (verifier or admin only)
+        #[ink(message)]
+        pub fn update_badge_metadata(
+            &mut self,
+            property_id: u64,
+            badge_type: BadgeType,
+            metadata_url: String,
+        ) -> Result<(), Error> {
+            let caller = self.env().caller();
+
+            if !self.is_verifier(caller) && caller != self.admin {
+                return Err(Error::NotVerifier);
+            }
+
+            let mut badge = self
+                .property_badges
+                .get(&(property_id, badge_type))
+                .ok_or(Error::BadgeNotFound)?;
+
+            // Revoked badges are corrected through reinstatement or re-issue
+            if badge.revoked {
+                return Err(Error::BadgeRevoked);
+            }
+
+            let old_metadata_url =
+                core::mem::replace(&mut badge.metadata_url, metadata_url.clone());
+            self.property_badges
+                .insert(&(property_id, badge_type), &badge);
+
+            let timestamp = self.env().block_timestamp();
+            let block_number = self.env().block_number();
+            self.env().emit_event(BadgeMetadataUpdated {
+                property_id,
+                badge_type,
+                updated_by: caller,
+                event_version: EVENT_VERSION,
+                old_metadata_url,
+                new_metadata_url: metadata_url,
+                timestamp,
+                block_number,
+                transaction_hash: [0u8; 32].into(),
+            });
+
+            Ok(())
+        }
+
       
         #[ink(message)]
         pub fn request_verification(
@@ -1801,6 +3112,16 @@ mod propchain_contracts {
                 return Err(Error::Unauthorized);
             }
 
+            let now = self.env().block_timestamp();
+            if let (Some(cooldown), Some(last)) =
+                (self.request_cooldown, self.last_request_at.get(&caller))
+            {
+                if now < last.saturating_add(cooldown) {
+                    return Err(Error::RequestTooSoon);
+                }
+            }
+            self.last_request_at.insert(&caller, &now);
+
             self.verification_count += 1;
             let request_id = self.verification_count;
 
@@ -1818,6 +3139,14 @@ mod propchain_contracts {
 
             self.verification_requests.insert(&request_id, &request);
 
+            let mut property_requests = self
+                .property_verification_requests
+                .get(&property_id)
+                .unwrap_or_default();
+            property_requests.push(request_id);
+            self.property_verification_requests
+                .insert(&property_id, &property_requests);
+
             // Emit verification requested event
             let timestamp = self.env().block_timestamp();
             let block_number = self.env().block_number();
@@ -1826,7 +3155,7 @@ mod propchain_contracts {
                 property_id,
                 badge_type,
                 requester: caller,
-                event_version: 1,
+                event_version: EVENT_VERSION,
                 evidence_url,
                 timestamp,
                 block_number,
@@ -1857,6 +3186,16 @@ mod propchain_contracts {
                 .get(&request_id)
                 .ok_or(Error::BadgeNotFound)?;
 
+            if request.status != VerificationStatus::Pending {
+                return Err(Error::InvalidVerificationStatus);
+            }
+
+            // The property may have been burned since the request was filed
+            if self.properties.get(&request.property_id).is_none() {
+                return Err(Error::PropertyNotFound);
+            }
+
+            let previous_status = request.status;
             request.status = if approved {
                 VerificationStatus::Approved
             } else {
@@ -1867,14 +3206,20 @@ mod propchain_contracts {
 
             self.verification_requests.insert(&request_id, &request);
 
-          
             if approved {
-                self.issue_badge(
+                if let Err(e) = self.issue_badge(
                     request.property_id,
                     request.badge_type,
                     expires_at,
                     metadata_url,
-                )?;
+                ) {
+                    // Don't leave the request Approved without a badge behind it
+                    request.status = previous_status;
+                    request.reviewed_by = None;
+                    request.reviewed_at = None;
+                    self.verification_requests.insert(&request_id, &request);
+                    return Err(e);
+                }
             }
 
           
@@ -1885,7 +3230,7 @@ mod propchain_contracts {
                 property_id: request.property_id,
                 reviewer: caller,
                 approved,
-                event_version: 1,
+                event_version: EVENT_VERSION,
                 timestamp,
                 block_number,
                 transaction_hash: [0u8; 32].into(),
@@ -1894,6 +3239,38 @@ mod propchain_contracts {
             Ok(())
         }
 
+        /// Withdraws a pending verification request (original requester only)
+        #[ink(message)]
+        pub fn cancel_verification_request(&mut self, request_id: u64) -> Result<(), Error> {
+            let caller = self.env().caller();
+            let mut request = self
+                .verification_requests
+                .get(&request_id)
+                .ok_or(Error::BadgeNotFound)?;
+
+            if request.requester != caller {
+                return Err(Error::Unauthorized);
+            }
+            if request.status != VerificationStatus::Pending {
+                return Err(Error::InvalidVerificationStatus);
+            }
+
+            request.status = VerificationStatus::Cancelled;
+            self.verification_requests.insert(&request_id, &request);
+
+            self.env().emit_event(VerificationCancelled {
+                request_id,
+                property_id: request.property_id,
+                requester: caller,
+                event_version: EVENT_VERSION,
+                timestamp: self.env().block_timestamp(),
+                block_number: self.env().block_number(),
+                transaction_hash: [0u8; 32].into(),
+            });
+
+            Ok(())
+        }
+
       
         #[ink(message)]
         pub fn submit_appeal(
@@ -1923,6 +3300,11 @@ mod propchain_contracts {
                 return Err(Error::InvalidAppealStatus); 
             }
 
+            // One pending appeal per revoked badge
+            if self.active_appeals.contains(&(property_id, badge_type)) {
+                return Err(Error::AppealAlreadyPending);
+            }
+
             self.appeal_count += 1;
             let appeal_id = self.appeal_count;
 
@@ -1941,7 +3323,14 @@ mod propchain_contracts {
 
             self.appeals.insert(&appeal_id, &appeal);
 
-          
+            let mut property_appeals = self.property_appeals.get(&property_id).unwrap_or_default();
+            property_appeals.push(appeal_id);
+            self.property_appeals
+                .insert(&property_id, &property_appeals);
+            self.add_pending_appeal(appeal_id);
+            self.active_appeals
+                .insert(&(property_id, badge_type), &appeal_id);
+
             let timestamp = self.env().block_timestamp();
             let block_number = self.env().block_number();
             self.env().emit_event(AppealSubmitted {
@@ -1949,7 +3338,7 @@ mod propchain_contracts {
                 property_id,
                 badge_type,
                 appellant: caller,
-                event_version: 1,
+                event_version: EVENT_VERSION,
                 reason,
                 timestamp,
                 block_number,
@@ -1972,48 +3361,25 @@ mod propchain_contracts {
                 return Err(Error::Unauthorized);
             }
 
-            let mut appeal = self.appeals.get(&appeal_id).ok_or(Error::AppealNotFound)?;
-
-            appeal.status = if approved {
-                AppealStatus::Approved
-            } else {
-                AppealStatus::Rejected
-            };
-            appeal.resolved_by = Some(caller);
-            appeal.resolved_at = Some(self.env().block_timestamp());
-            appeal.resolution = resolution.clone();
+            let appeal = self.appeals.get(&appeal_id).ok_or(Error::AppealNotFound)?;
+            if appeal.status != AppealStatus::Pending {
+                return Err(Error::InvalidAppealStatus);
+            }
 
-            self.appeals.insert(&appeal_id, &appeal);
+            let (property_id, badge_type) = (appeal.property_id, appeal.badge_type);
+            self.close_appeal(appeal_id, appeal, approved, resolution);
 
             // If approved, reinstate the badge
             if approved {
-                if let Some(mut badge) = self
-                    .property_badges
-                    .get(&(appeal.property_id, appeal.badge_type))
-                {
+                if let Some(mut badge) = self.property_badges.get(&(property_id, badge_type)) {
                     badge.revoked = false;
                     badge.revoked_at = None;
                     badge.revocation_reason = String::new();
                     self.property_badges
-                        .insert(&(appeal.property_id, appeal.badge_type), &badge);
+                        .insert(&(property_id, badge_type), &badge);
                 }
             }
 
-            // Emit appeal resolved event
-            let timestamp = self.env().block_timestamp();
-            let block_number = self.env().block_number();
-            self.env().emit_event(AppealResolved {
-                appeal_id,
-                property_id: appeal.property_id,
-                resolved_by: caller,
-                approved,
-                event_version: 1,
-                resolution,
-                timestamp,
-                block_number,
-                transaction_hash: [0u8; 32].into(),
-            });
-
             Ok(())
         }
 
@@ -2022,18 +3388,12 @@ mod propchain_contracts {
         pub fn get_property_badges(&self, property_id: u64) -> Vec<(BadgeType, Badge)> {
             let mut badges = Vec::new();
 
-            // Check all badge types
-            let badge_types = [
-                BadgeType::OwnerVerification,
-                BadgeType::DocumentVerification,
-                BadgeType::LegalCompliance,
-                BadgeType::PremiumListing,
-            ];
-
-            for badge_type in badge_types.iter() {
-                if let Some(badge) = self.property_badges.get(&(property_id, *badge_type)) {
+            // Check all registered badge types, built-in first
+            for id in 0..self.next_badge_type_id {
+                let badge_type = BadgeType::from_id(id);
+                if let Some(badge) = self.property_badges.get(&(property_id, badge_type)) {
                     if !badge.revoked {
-                        badges.push((*badge_type, badge));
+                        badges.push((badge_type, badge));
                     }
                 }
             }
@@ -2041,6 +3401,75 @@ mod propchain_contracts {
             badges
         }
 
+        /// Registers a new badge type and returns its ID (admin only)
+        #[ink(message)]
+        pub fn register_badge_type(&mut self, name: String) -> Result<BadgeTypeId, Error> {
+            let caller = self.env().caller();
+            if caller != self.admin {
+                return Err(Error::Unauthorized);
+            }
+
+            let taken_by_built_in = BadgeType::BUILT_IN
+                .iter()
+                .any(|badge_type| badge_type.built_in_name() == Some(name.as_str()));
+            if taken_by_built_in || self.badge_type_ids.contains(&name) {
+                return Err(Error::BadgeTypeAlreadyRegistered);
+            }
+
+            let badge_type_id = self.next_badge_type_id;
+            self.next_badge_type_id = badge_type_id
+                .checked_add(1)
+                .ok_or(Error::InvalidBadgeType)?;
+            self.custom_badge_types.insert(badge_type_id, &name);
+            self.badge_type_ids.insert(&name, &badge_type_id);
+
+            self.env().emit_event(BadgeTypeRegistered {
+                badge_type_id,
+                name,
+                registered_by: caller,
+                event_version: EVENT_VERSION,
+                timestamp: self.env().block_timestamp(),
+                block_number: self.env().block_number(),
+            });
+
+            Ok(badge_type_id)
+        }
+
+        /// Gets the name of a registered badge type
+        #[ink(message)]
+        pub fn get_badge_type_name(&self, badge_type_id: BadgeTypeId) -> Option<String> {
+            match BadgeType::from_id(badge_type_id) {
+                BadgeType::Custom(id) => self.custom_badge_types.get(id),
+                built_in => built_in.built_in_name().map(String::from),
+            }
+        }
+
+        /// Gets every registered badge type as (ID, name), built-in types first
+        #[ink(message)]
+        pub fn get_badge_types(&self) -> Vec<(BadgeTypeId, String)> {
+            (0..self.next_badge_type_id)
+                .filter_map(|id| self.get_badge_type_name(id).map(|name| (id, name)))
+                .collect()
+        }
+
+        /// Whether a badge type is built in or has been registered
+        fn is_badge_type_registered(&self, badge_type: BadgeType) -> bool {
+            match badge_type {
+                BadgeType::Custom(id) => self.custom_badge_types.contains(id),
+                _ => true,
+            }
+        }
+
+        /// Gets a property together with its active badges in a single read
+        #[ink(message)]
+        pub fn get_property_with_badges(
+            &self,
+            property_id: u64,
+        ) -> Option<(PropertyInfo, Vec<(BadgeType, Badge)>)> {
+            let property = self.properties.get(&property_id)?;
+            Some((property, self.get_property_badges(property_id)))
+        }
+
      
         #[ink(message)]
         pub fn has_badge(&self, property_id: u64, badge_type: BadgeType) -> bool {
@@ -2063,11 +3492,63 @@ mod propchain_contracts {
             self.verification_requests.get(&request_id)
         }
 
+        /// Sets the minimum time between an account's verification requests (admin only)
+        /// `None` removes the limit
+        #[ink(message)]
+        pub fn set_request_cooldown(&mut self, cooldown: Option<u64>) -> Result<(), Error> {
+            if self.env().caller() != self.admin {
+                return Err(Error::Unauthorized);
+            }
+            self.request_cooldown = cooldown;
+            Ok(())
+        }
+
+        /// Gets the minimum time between an account's verification requests
+        #[ink(message)]
+        pub fn get_request_cooldown(&self) -> Option<u64> {
+            self.request_cooldown
+        }
+
+        /// Gets all verification requests filed for a property, oldest first
+        #[ink(message)]
+        pub fn get_verification_requests_for_property(
+            &self,
+            property_id: u64,
+        ) -> Vec<VerificationRequest> {
+            self.property_verification_requests
+                .get(&property_id)
+                .unwrap_or_default()
+                .into_iter()
+                .filter_map(|request_id| self.verification_requests.get(&request_id))
+                .collect()
+        }
+
       
         #[ink(message)]
         pub fn get_appeal(&self, appeal_id: u64) -> Option<Appeal> {
             self.appeals.get(&appeal_id)
         }
+
+        /// Gets the IDs of all appeals submitted for a property
+        #[ink(message)]
+        pub fn get_appeals_for_property(&self, property_id: u64) -> Vec<u64> {
+            self.property_appeals.get(&property_id).unwrap_or_default()
+        }
+
+        /// Gets up to `limit` pending appeal IDs, starting at index `start`
+        #[ink(message)]
+        pub fn get_pending_appeals(&self, start: u32, limit: u32) -> Vec<u64> {
+            let end = start.saturating_add(limit).min(self.pending_appeal_count);
+            (start..end)
+                .filter_map(|slot| self.pending_appeals.get(&slot))
+                .collect()
+        }
+
+        /// Gets the number of appeals awaiting resolution
+        #[ink(message)]
+        pub fn get_pending_appeal_count(&self) -> u32 {
+            self.pending_appeal_count
+        }
     }
 
     #[cfg(kani)]
@@ -2104,12 +3585,13 @@ mod propchain_contracts {
     impl Escrow for PropertyRegistry {
         type Error = Error;
 
-        fn create_escrow(&mut self, property_id: u64, amount: u128) -> Result<u64, Self::Error> {
-            // For trait compatibility, use caller as buyer
-            // In production, use the direct create_escrow method with explicit buyer
-            use ink::codegen::Env;
-            let caller = self.env().caller();
-            self.create_escrow(property_id, caller, amount)
+        fn create_escrow(
+            &mut self,
+            property_id: u64,
+            buyer: AccountId,
+            amount: u128,
+        ) -> Result<u64, Self::Error> {
+            self.create_escrow(property_id, buyer, amount)
         }
 
         fn release_escrow(&mut self, escrow_id: u64) -> Result<(), Self::Error> {
@@ -2125,3 +3607,6 @@ mod propchain_contracts {
 
 #[cfg(test)]
 mod tests;
+
+#[cfg(all(test, feature = "e2e-tests"))]
+mod gas_benchmarks;
@@ -1,7 +1,9 @@
 #[cfg(test)]
 mod tests {
     use crate::propchain_contracts::Error;
+    use crate::propchain_contracts::EscrowStatus;
     use crate::propchain_contracts::PropertyRegistry;
+    use crate::propchain_contracts::Role;
     use ink::primitives::AccountId;
     use propchain_traits::*;
 
@@ -23,6 +25,8 @@ mod tests {
             legal_description: "Test property legal description".to_string(),
             valuation: 1000000,
             documents_url: "https://example.com/docs".to_string(),
+            latitude: None,
+            longitude: None,
         }
     }
 
@@ -40,6 +44,8 @@ mod tests {
             legal_description: legal_description.to_string(),
             valuation,
             documents_url: documents_url.to_string(),
+            latitude: None,
+            longitude: None,
         }
     }
 
@@ -283,6 +289,192 @@ mod tests {
         assert_eq!(contract.property_count(), 2);
     }
 
+    #[ink::test]
+    fn test_burn_keeps_ids_increasing_and_updates_live_count() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+
+        let mut contract = PropertyRegistry::new();
+        let first = contract
+            .register_property(create_sample_metadata())
+            .unwrap();
+        let second = contract
+            .register_property(create_sample_metadata())
+            .unwrap();
+        assert_eq!(contract.property_count(), 2);
+        assert_eq!(contract.total_ever_registered(), 2);
+
+        // Only the owner can burn
+        set_caller(accounts.bob);
+        assert_eq!(contract.burn_property(first), Err(Error::Unauthorized));
+        set_caller(accounts.alice);
+        contract.burn_property(first).unwrap();
+        assert_eq!(contract.burn_property(first), Err(Error::PropertyNotFound));
+
+        assert!(contract.get_property(first).is_none());
+        assert_eq!(contract.get_owner_properties(accounts.alice), vec![second]);
+        assert_eq!(contract.property_count(), 1);
+        assert_eq!(contract.total_ever_registered(), 2);
+
+        // New registrations never reuse a burned ID
+        let third = contract
+            .register_property(create_sample_metadata())
+            .unwrap();
+        assert_eq!(third, 3);
+        let batch = contract
+            .batch_register_properties(vec![create_sample_metadata(), create_sample_metadata()])
+            .unwrap();
+        assert_eq!(batch, vec![4, 5]);
+        assert_eq!(contract.property_count(), 4);
+        assert_eq!(contract.total_ever_registered(), 5);
+
+        // Enumeration still reaches the highest ID and skips the burned one
+        let page = contract.get_all_properties_paged(1, 10);
+        let ids: Vec<u64> = page.properties.iter().map(|p| p.id).collect();
+        assert_eq!(ids, vec![2, 3, 4, 5]);
+        assert_eq!(page.next_cursor, None);
+    }
+
+    #[ink::test]
+    fn test_burn_rejected_while_escrow_is_open() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .unwrap();
+        let escrow_id = contract
+            .create_escrow(property_id, accounts.bob, 1000)
+            .unwrap();
+        fund_as_buyer(&mut contract, escrow_id);
+
+        set_caller(accounts.alice);
+        assert_eq!(
+            contract.burn_property(property_id),
+            Err(Error::PropertyUnderContract)
+        );
+
+        // Relisting does not get around an unsettled escrow
+        assert!(contract
+            .set_property_status(property_id, PropertyStatus::Listed)
+            .is_ok());
+        assert_eq!(
+            contract.burn_property(property_id),
+            Err(Error::PropertyUnderContract)
+        );
+        assert!(contract.get_property(property_id).is_some());
+        assert_eq!(
+            contract.get_escrow(escrow_id).unwrap().status,
+            EscrowStatus::Funded
+        );
+
+        // Once the escrow is refunded the property can be burned
+        assert!(contract.refund_escrow(escrow_id).is_ok());
+        assert!(contract.burn_property(property_id).is_ok());
+    }
+
+    #[ink::test]
+    fn test_burn_clears_badges_and_pending_appeals() {
+        use crate::propchain_contracts::{AppealStatus, BadgeType};
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .unwrap();
+
+        contract
+            .issue_badge(
+                property_id,
+                BadgeType::OwnerVerification,
+                None,
+                "ipfs://badge".to_string(),
+            )
+            .unwrap();
+        contract
+            .revoke_badge(
+                property_id,
+                BadgeType::OwnerVerification,
+                "Expired documents".to_string(),
+            )
+            .unwrap();
+        let appeal_id = contract
+            .submit_appeal(
+                property_id,
+                BadgeType::OwnerVerification,
+                "Documents renewed".to_string(),
+            )
+            .unwrap();
+        contract
+            .issue_badge(
+                property_id,
+                BadgeType::LegalCompliance,
+                None,
+                "ipfs://badge".to_string(),
+            )
+            .unwrap();
+
+        assert!(contract.burn_property(property_id).is_ok());
+
+        assert!(contract.get_property_badges(property_id).is_empty());
+        assert!(contract
+            .get_badge(property_id, BadgeType::OwnerVerification)
+            .is_none());
+        assert_eq!(
+            contract.get_appeal(appeal_id).unwrap().status,
+            AppealStatus::Rejected
+        );
+        assert!(contract.get_pending_appeals(0, 10).is_empty());
+    }
+
+    #[ink::test]
+    fn test_get_properties_near_uses_bounding_box() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+
+        let mut contract = PropertyRegistry::new();
+        let at = |lat: Option<i32>, lon: Option<i32>| PropertyMetadata {
+            latitude: lat,
+            longitude: lon,
+            ..create_sample_metadata()
+        };
+
+        // Centre of the search: 40.0, -74.0
+        let centre = contract
+            .register_property(at(Some(40_000_000), Some(-74_000_000)))
+            .unwrap();
+        let inside = contract
+            .register_property(at(Some(40_009_000), Some(-73_991_000)))
+            .unwrap();
+        let corner = contract
+            .register_property(at(Some(40_010_000), Some(-74_010_000)))
+            .unwrap();
+        let outside_lat = contract
+            .register_property(at(Some(40_010_001), Some(-74_000_000)))
+            .unwrap();
+        let outside_lon = contract
+            .register_property(at(Some(40_000_000), Some(-73_980_000)))
+            .unwrap();
+        let unknown = contract.register_property(at(None, None)).unwrap();
+
+        let near = contract.get_properties_near(40_000_000, -74_000_000, 10_000, 10);
+        assert_eq!(near, vec![centre, inside, corner]);
+        assert!(!near.contains(&outside_lat));
+        assert!(!near.contains(&outside_lon));
+        assert!(!near.contains(&unknown));
+
+        // Limit caps the number of results
+        assert_eq!(
+            contract.get_properties_near(40_000_000, -74_000_000, 10_000, 2),
+            vec![centre, inside]
+        );
+        // A zero radius only matches the exact point
+        assert_eq!(
+            contract.get_properties_near(40_000_000, -74_000_000, 0, 10),
+            vec![centre]
+        );
+    }
+
     #[ink::test]
     fn test_ownership_verification_after_multiple_transfers() {
         let accounts = default_accounts();
@@ -551,6 +743,149 @@ mod tests {
         );
     }
 
+    #[ink::test]
+    fn test_transfer_to_zero_address_fails() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+
+        let mut contract = PropertyRegistry::new();
+        let first = contract
+            .register_property(create_sample_metadata())
+            .unwrap();
+        let second = contract
+            .register_property(create_sample_metadata())
+            .unwrap();
+        let zero = AccountId::from([0u8; 32]);
+
+        assert_eq!(
+            contract.transfer_property(first, zero),
+            Err(Error::InvalidRecipient)
+        );
+        assert_eq!(
+            contract.batch_transfer_properties(vec![first, second], zero),
+            Err(Error::InvalidRecipient)
+        );
+        assert_eq!(
+            contract
+                .batch_transfer_properties_to_multiple(vec![(first, accounts.bob), (second, zero)]),
+            Err(Error::InvalidRecipient)
+        );
+
+        // Nothing moved
+        assert_eq!(contract.get_property(first).unwrap().owner, accounts.alice);
+        assert_eq!(contract.get_property(second).unwrap().owner, accounts.alice);
+        assert!(contract.get_owner_properties(zero).is_empty());
+    }
+
+    fn signing_key(seed: u8) -> (secp256k1::SecretKey, AccountId) {
+        let secret = secp256k1::SecretKey::from_slice(&[seed; 32]).unwrap();
+        let public = secp256k1::PublicKey::from_secret_key(secp256k1::SECP256K1, &secret);
+        let mut account = [0u8; 32];
+        ink::env::hash_bytes::<ink::env::hash::Blake2x256>(&public.serialize(), &mut account);
+        (secret, AccountId::from(account))
+    }
+
+    fn sign_transfer(
+        contract: &PropertyRegistry,
+        secret: &secp256k1::SecretKey,
+        property_id: u64,
+        to: AccountId,
+        nonce: u64,
+    ) -> [u8; 65] {
+        let hash = contract.transfer_message_hash(property_id, to, nonce);
+        let message = secp256k1::Message::from_digest(hash);
+        let (recovery_id, compact) = secp256k1::SECP256K1
+            .sign_ecdsa_recoverable(&message, secret)
+            .serialize_compact();
+        let mut signature = [0u8; 65];
+        signature[..64].copy_from_slice(&compact);
+        signature[64] = recovery_id.to_i32() as u8;
+        signature
+    }
+
+    #[ink::test]
+    fn test_transfer_property_with_signature() {
+        let accounts = default_accounts();
+        let (secret, signer) = signing_key(0x42);
+        set_caller(signer);
+        let mut contract = PropertyRegistry::new();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .unwrap();
+
+        // A relayer without any stake in the property submits the owner's signature
+        let signature = sign_transfer(&contract, &secret, property_id, accounts.bob, 0);
+        set_caller(accounts.charlie);
+        assert!(contract
+            .transfer_property_with_signature(property_id, accounts.bob, 0, signature)
+            .is_ok());
+        assert_eq!(
+            contract.get_property(property_id).unwrap().owner,
+            accounts.bob
+        );
+        assert_eq!(contract.get_transfer_nonce(signer), 1);
+    }
+
+    #[ink::test]
+    fn test_transfer_property_with_signature_rejects_replay() {
+        let accounts = default_accounts();
+        let (secret, signer) = signing_key(0x42);
+        set_caller(signer);
+        let mut contract = PropertyRegistry::new();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .unwrap();
+        let other_id = contract
+            .register_property(create_sample_metadata())
+            .unwrap();
+
+        let signature = sign_transfer(&contract, &secret, property_id, accounts.bob, 0);
+        set_caller(accounts.charlie);
+        assert!(contract
+            .transfer_property_with_signature(property_id, accounts.bob, 0, signature)
+            .is_ok());
+        assert_eq!(
+            contract.transfer_property_with_signature(property_id, accounts.bob, 0, signature),
+            Err(Error::InvalidNonce)
+        );
+
+        // Signing ahead of the current nonce is rejected as well
+        let skipped = sign_transfer(&contract, &secret, other_id, accounts.bob, 5);
+        assert_eq!(
+            contract.transfer_property_with_signature(other_id, accounts.bob, 5, skipped),
+            Err(Error::InvalidNonce)
+        );
+        assert_eq!(contract.get_property(other_id).unwrap().owner, signer);
+    }
+
+    #[ink::test]
+    fn test_transfer_property_with_signature_rejects_wrong_signer() {
+        let accounts = default_accounts();
+        let (_, owner) = signing_key(0x42);
+        let (stranger_secret, stranger) = signing_key(0x43);
+        set_caller(owner);
+        let mut contract = PropertyRegistry::new();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .unwrap();
+
+        let signature = sign_transfer(&contract, &stranger_secret, property_id, accounts.bob, 0);
+        set_caller(accounts.charlie);
+        assert_eq!(
+            contract.transfer_property_with_signature(property_id, accounts.bob, 0, signature),
+            Err(Error::Unauthorized)
+        );
+
+        // A corrupted signature recovers some other key, or none, and is rejected too
+        let mut tampered = sign_transfer(&contract, &stranger_secret, property_id, accounts.bob, 0);
+        tampered[0] ^= 0xff;
+        assert!(contract
+            .transfer_property_with_signature(property_id, accounts.bob, 0, tampered)
+            .is_err());
+        assert_eq!(contract.get_property(property_id).unwrap().owner, owner);
+        assert_eq!(contract.get_transfer_nonce(stranger), 0);
+    }
+
     #[ink::test]
     fn test_register_property_with_special_characters() {
         let accounts = default_accounts();
@@ -704,6 +1039,53 @@ mod tests {
         }
     }
 
+    #[ink::test]
+    fn test_owner_index_spans_multiple_chunks() {
+        use crate::propchain_contracts::OWNER_INDEX_CHUNK_SIZE;
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+
+        let mut contract = PropertyRegistry::new();
+        let count = OWNER_INDEX_CHUNK_SIZE * 2 + 5;
+        let mut ids = Vec::new();
+        for _ in 0..count {
+            ids.push(
+                contract
+                    .register_property(create_sample_metadata())
+                    .unwrap(),
+            );
+        }
+        assert_eq!(contract.get_owner_property_count(accounts.alice), count);
+        assert_eq!(contract.get_owner_properties(accounts.alice), ids);
+
+        // Moving properties out of the first and middle chunks keeps the index intact
+        let moved = vec![ids[0], ids[OWNER_INDEX_CHUNK_SIZE as usize + 3]];
+        contract.transfer_property(moved[0], accounts.bob).unwrap();
+        contract.transfer_property(moved[1], accounts.bob).unwrap();
+        contract.burn_property(ids[1]).unwrap();
+
+        let mut remaining = contract.get_owner_properties(accounts.alice);
+        assert_eq!(remaining.len() as u32, count - 3);
+        assert_eq!(contract.get_owner_property_count(accounts.alice), count - 3);
+        remaining.sort();
+        let mut expected: Vec<u64> = ids
+            .iter()
+            .copied()
+            .filter(|id| !moved.contains(id) && *id != ids[1])
+            .collect();
+        expected.sort();
+        assert_eq!(remaining, expected);
+        assert_eq!(contract.get_owner_properties(accounts.bob), moved);
+
+        // New registrations append after the compacted entries
+        let extra = contract
+            .register_property(create_sample_metadata())
+            .unwrap();
+        let alice_properties = contract.get_owner_properties(accounts.alice);
+        assert_eq!(alice_properties.len() as u32, count - 2);
+        assert_eq!(alice_properties.last(), Some(&extra));
+    }
+
     #[ink::test]
     fn test_property_count_accuracy_under_load() {
         let accounts = default_accounts();
@@ -925,6 +1307,8 @@ mod tests {
             legal_description: "Test property".to_string(),
             valuation: 1000000,
             documents_url: "https://example.com/docs".to_string(),
+            latitude: None,
+            longitude: None,
         };
 
         let property_id = contract
@@ -937,6 +1321,8 @@ mod tests {
             legal_description: "Test property updated".to_string(),
             valuation: 1100000,
             documents_url: "https://example.com/docs/new".to_string(),
+            latitude: None,
+            longitude: None,
         };
 
         assert!(contract
@@ -963,6 +1349,8 @@ mod tests {
             legal_description: "Test property".to_string(),
             valuation: 1000000,
             documents_url: "https://example.com/docs".to_string(),
+            latitude: None,
+            longitude: None,
         };
         let property_id = contract
             .register_property(metadata)
@@ -975,6 +1363,8 @@ mod tests {
             legal_description: "Test property updated".to_string(),
             valuation: 1100000,
             documents_url: "https://example.com/docs/new".to_string(),
+            latitude: None,
+            longitude: None,
         };
         assert_eq!(
             contract.update_metadata(property_id, new_metadata),
@@ -994,6 +1384,8 @@ mod tests {
             legal_description: "Test property".to_string(),
             valuation: 1000000,
             documents_url: "https://example.com/docs".to_string(),
+            latitude: None,
+            longitude: None,
         };
         let property_id = contract
             .register_property(metadata)
@@ -1018,6 +1410,109 @@ mod tests {
 
     // Batch Operations Tests
 
+    #[ink::test]
+    fn batch_register_emits_individual_and_summary_events() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        assert!(contract.get_emit_individual_in_batch());
+
+        // Capture the PropertyRegistered signature topic from a single registration
+        contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let events = ink::env::test::recorded_events().collect::<Vec<_>>();
+        let registered_signature = events.last().unwrap().topics[0].clone();
+
+        let properties = vec![
+            create_custom_metadata(
+                "Property 1",
+                1000,
+                "Test property 1",
+                100000,
+                "https://example.com/docs1",
+            ),
+            create_custom_metadata(
+                "Property 2",
+                1500,
+                "Test property 2",
+                150000,
+                "https://example.com/docs2",
+            ),
+            create_custom_metadata(
+                "Property 3",
+                2000,
+                "Test property 3",
+                200000,
+                "https://example.com/docs3",
+            ),
+        ];
+        contract
+            .batch_register_properties(properties)
+            .expect("Failed to batch register");
+
+        // ContractInitialized + single PropertyRegistered + 3 per-item + 1 summary
+        let events = ink::env::test::recorded_events().collect::<Vec<_>>();
+        assert_eq!(events.len(), 6);
+        let per_item = events[2..]
+            .iter()
+            .filter(|e| e.topics[0] == registered_signature)
+            .count();
+        assert_eq!(per_item, 3);
+        let summary = events[2..]
+            .iter()
+            .filter(|e| e.topics[0] != registered_signature)
+            .count();
+        assert_eq!(summary, 1);
+    }
+
+    #[ink::test]
+    fn batch_register_without_individual_events() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+
+        contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let events = ink::env::test::recorded_events().collect::<Vec<_>>();
+        let registered_signature = events.last().unwrap().topics[0].clone();
+
+        // Only admin may toggle the flag
+        set_caller(accounts.bob);
+        assert_eq!(
+            contract.set_emit_individual_in_batch(false),
+            Err(Error::Unauthorized)
+        );
+        set_caller(accounts.alice);
+        assert!(contract.set_emit_individual_in_batch(false).is_ok());
+
+        let properties = vec![
+            create_custom_metadata(
+                "Property 1",
+                1000,
+                "Test property 1",
+                100000,
+                "https://example.com/docs1",
+            ),
+            create_custom_metadata(
+                "Property 2",
+                1500,
+                "Test property 2",
+                150000,
+                "https://example.com/docs2",
+            ),
+        ];
+        contract
+            .batch_register_properties(properties)
+            .expect("Failed to batch register");
+
+        // Only the summary event is added
+        let events = ink::env::test::recorded_events().collect::<Vec<_>>();
+        assert_eq!(events.len(), 3);
+        assert_ne!(events[2].topics[0], registered_signature);
+    }
+
     #[ink::test]
     fn batch_register_properties_works() {
         let accounts = default_accounts();
@@ -1031,6 +1526,8 @@ mod tests {
                 legal_description: "Test property 1".to_string(),
                 valuation: 100000,
                 documents_url: "https://example.com/docs1".to_string(),
+                latitude: None,
+                longitude: None,
             },
             PropertyMetadata {
                 location: "Property 2".to_string(),
@@ -1038,6 +1535,8 @@ mod tests {
                 legal_description: "Test property 2".to_string(),
                 valuation: 150000,
                 documents_url: "https://example.com/docs2".to_string(),
+                latitude: None,
+                longitude: None,
             },
             PropertyMetadata {
                 location: "Property 3".to_string(),
@@ -1045,6 +1544,8 @@ mod tests {
                 legal_description: "Test property 3".to_string(),
                 valuation: 200000,
                 documents_url: "https://example.com/docs3".to_string(),
+                latitude: None,
+                longitude: None,
             },
         ];
 
@@ -1085,6 +1586,8 @@ mod tests {
                 legal_description: "Test property 1".to_string(),
                 valuation: 100000,
                 documents_url: "https://example.com/docs1".to_string(),
+                latitude: None,
+                longitude: None,
             },
             PropertyMetadata {
                 location: "Property 2".to_string(),
@@ -1092,6 +1595,8 @@ mod tests {
                 legal_description: "Test property 2".to_string(),
                 valuation: 150000,
                 documents_url: "https://example.com/docs2".to_string(),
+                latitude: None,
+                longitude: None,
             },
         ];
 
@@ -1135,6 +1640,8 @@ mod tests {
                 legal_description: "Test property 1".to_string(),
                 valuation: 100000,
                 documents_url: "https://example.com/docs1".to_string(),
+                latitude: None,
+                longitude: None,
             },
             PropertyMetadata {
                 location: "Property 2".to_string(),
@@ -1142,6 +1649,8 @@ mod tests {
                 legal_description: "Test property 2".to_string(),
                 valuation: 150000,
                 documents_url: "https://example.com/docs2".to_string(),
+                latitude: None,
+                longitude: None,
             },
         ];
 
@@ -1159,6 +1668,8 @@ mod tests {
                     legal_description: "Updated test property 1".to_string(),
                     valuation: 120000,
                     documents_url: "https://example.com/docs1_updated".to_string(),
+                    latitude: None,
+                    longitude: None,
                 },
             ),
             (
@@ -1169,6 +1680,8 @@ mod tests {
                     legal_description: "Updated test property 2".to_string(),
                     valuation: 170000,
                     documents_url: "https://example.com/docs2_updated".to_string(),
+                    latitude: None,
+                    longitude: None,
                 },
             ),
         ];
@@ -1201,6 +1714,8 @@ mod tests {
                 legal_description: "Test property 1".to_string(),
                 valuation: 100000,
                 documents_url: "https://example.com/docs1".to_string(),
+                latitude: None,
+                longitude: None,
             },
             PropertyMetadata {
                 location: "Property 2".to_string(),
@@ -1208,6 +1723,8 @@ mod tests {
                 legal_description: "Test property 2".to_string(),
                 valuation: 150000,
                 documents_url: "https://example.com/docs2".to_string(),
+                latitude: None,
+                longitude: None,
             },
             PropertyMetadata {
                 location: "Property 3".to_string(),
@@ -1215,6 +1732,8 @@ mod tests {
                 legal_description: "Test property 3".to_string(),
                 valuation: 200000,
                 documents_url: "https://example.com/docs3".to_string(),
+                latitude: None,
+                longitude: None,
             },
         ];
 
@@ -1264,6 +1783,8 @@ mod tests {
                 legal_description: "Test property 1".to_string(),
                 valuation: 100000,
                 documents_url: "https://example.com/docs1".to_string(),
+                latitude: None,
+                longitude: None,
             },
             PropertyMetadata {
                 location: "Property 2".to_string(),
@@ -1271,6 +1792,8 @@ mod tests {
                 legal_description: "Test property 2".to_string(),
                 valuation: 150000,
                 documents_url: "https://example.com/docs2".to_string(),
+                latitude: None,
+                longitude: None,
             },
         ];
 
@@ -1301,6 +1824,8 @@ mod tests {
                 legal_description: "Test property 1".to_string(),
                 valuation: 100000,
                 documents_url: "https://example.com/docs1".to_string(),
+                latitude: None,
+                longitude: None,
             },
             PropertyMetadata {
                 location: "Property 2".to_string(),
@@ -1308,6 +1833,8 @@ mod tests {
                 legal_description: "Test property 2".to_string(),
                 valuation: 150000,
                 documents_url: "https://example.com/docs2".to_string(),
+                latitude: None,
+                longitude: None,
             },
         ];
 
@@ -1335,6 +1862,42 @@ mod tests {
         assert_eq!(prop2.valuation, 150000);
     }
 
+    #[ink::test]
+    fn get_all_properties_paged_reassembles_all_properties() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+
+        for _ in 0..25 {
+            contract
+                .register_property(create_sample_metadata())
+                .expect("Failed to register property");
+        }
+
+        let mut collected = Vec::new();
+        let mut cursor = Some(1);
+        let mut pages = 0;
+        while let Some(start) = cursor {
+            let page = contract.get_all_properties_paged(start, 10);
+            assert!(page.properties.len() <= 10);
+            collected.extend(page.properties);
+            cursor = page.next_cursor;
+            pages += 1;
+        }
+
+        assert_eq!(pages, 3);
+        assert_eq!(
+            collected.iter().map(|p| p.id).collect::<Vec<_>>(),
+            (1..=25).collect::<Vec<u64>>()
+        );
+        assert!(collected.iter().all(|p| p.owner == accounts.alice));
+
+        // Past the end yields an empty final page
+        let page = contract.get_all_properties_paged(26, 10);
+        assert!(page.properties.is_empty());
+        assert_eq!(page.next_cursor, None);
+    }
+
     // Analytics Tests
 
     #[ink::test]
@@ -1350,6 +1913,8 @@ mod tests {
             legal_description: "Test property".to_string(),
             valuation: 100000,
             documents_url: "https://example.com/docs".to_string(),
+            latitude: None,
+            longitude: None,
         }];
         contract
             .batch_register_properties(alice_properties)
@@ -1364,6 +1929,8 @@ mod tests {
                 legal_description: "Test property".to_string(),
                 valuation: 150000,
                 documents_url: "https://example.com/docs".to_string(),
+                latitude: None,
+                longitude: None,
             },
             PropertyMetadata {
                 location: "Bob Property 2".to_string(),
@@ -1371,6 +1938,8 @@ mod tests {
                 legal_description: "Test property".to_string(),
                 valuation: 200000,
                 documents_url: "https://example.com/docs".to_string(),
+                latitude: None,
+                longitude: None,
             },
         ];
         contract
@@ -1401,6 +1970,8 @@ mod tests {
                 legal_description: "Test property".to_string(),
                 valuation: 50000,
                 documents_url: "https://example.com/docs".to_string(),
+                latitude: None,
+                longitude: None,
             },
             PropertyMetadata {
                 location: "Medium Property".to_string(),
@@ -1408,6 +1979,8 @@ mod tests {
                 legal_description: "Test property".to_string(),
                 valuation: 150000,
                 documents_url: "https://example.com/docs".to_string(),
+                latitude: None,
+                longitude: None,
             },
             PropertyMetadata {
                 location: "Expensive Property".to_string(),
@@ -1415,6 +1988,8 @@ mod tests {
                 legal_description: "Test property".to_string(),
                 valuation: 250000,
                 documents_url: "https://example.com/docs".to_string(),
+                latitude: None,
+                longitude: None,
             },
         ];
 
@@ -1454,6 +2029,8 @@ mod tests {
                 legal_description: "Test property".to_string(),
                 valuation: 100000,
                 documents_url: "https://example.com/docs".to_string(),
+                latitude: None,
+                longitude: None,
             },
             PropertyMetadata {
                 location: "Medium Property".to_string(),
@@ -1461,6 +2038,8 @@ mod tests {
                 legal_description: "Test property".to_string(),
                 valuation: 150000,
                 documents_url: "https://example.com/docs".to_string(),
+                latitude: None,
+                longitude: None,
             },
             PropertyMetadata {
                 location: "Large Property".to_string(),
@@ -1468,6 +2047,8 @@ mod tests {
                 legal_description: "Test property".to_string(),
                 valuation: 200000,
                 documents_url: "https://example.com/docs".to_string(),
+                latitude: None,
+                longitude: None,
             },
         ];
 
@@ -1493,34 +2074,290 @@ mod tests {
         assert!(all_properties.contains(&3));
     }
 
-    // Gas Monitoring Tests
-
     #[ink::test]
-    fn gas_metrics_tracking_works() {
+    fn range_queries_return_sorted_ids() {
         let accounts = default_accounts();
-        set_caller(accounts.alice);
         let mut contract = PropertyRegistry::new();
 
-        // Perform some operations
-        let metadata = PropertyMetadata {
-            location: "Test Property".to_string(),
-            size: 1000,
-            legal_description: "Test property".to_string(),
-            valuation: 100000,
-            documents_url: "https://example.com/docs".to_string(),
-        };
+        // Register from different owners with descending values and sizes
+        for (i, owner) in [
+            accounts.alice,
+            accounts.bob,
+            accounts.charlie,
+            accounts.alice,
+        ]
+        .iter()
+        .enumerate()
+        {
+            set_caller(*owner);
+            let step = 4 - i as u64;
+            contract
+                .register_property(create_custom_metadata(
+                    "Range Property",
+                    step * 1000,
+                    "Test property",
+                    step as u128 * 100000,
+                    "https://example.com/docs",
+                ))
+                .expect("Failed to register property");
+        }
 
+        // Reshuffle ownership and metadata so owner indices no longer follow id order
+        set_caller(accounts.alice);
         contract
-            .register_property(metadata)
-            .expect("Failed to register");
+            .transfer_property(1, accounts.charlie)
+            .expect("Failed to transfer property");
+        set_caller(accounts.bob);
+        contract
+            .update_metadata(
+                2,
+                create_custom_metadata(
+                    "Range Property",
+                    500,
+                    "Test property",
+                    50000,
+                    "https://example.com/docs",
+                ),
+            )
+            .expect("Failed to update metadata");
 
-        // Get gas metrics
-        let metrics = contract.get_gas_metrics();
+        let by_price = contract.get_properties_by_price_range(0, u128::MAX);
+        assert_eq!(by_price, vec![1, 2, 3, 4]);
+
+        let by_size = contract.get_properties_by_size_range(0, u64::MAX);
+        assert_eq!(by_size, vec![1, 2, 3, 4]);
+
+        let mid_range = contract.get_properties_by_price_range(50000, 300000);
+        assert_eq!(mid_range, vec![2, 3, 4]);
+    }
+
+    #[ink::test]
+    fn search_properties_by_type_and_price_works() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+
+        // (valuation, type) for ids 1..=6
+        let listings = [
+            (100000, PropertyType::Residential),
+            (250000, PropertyType::Residential),
+            (260000, PropertyType::Commercial),
+            (300000, PropertyType::Residential),
+            (350000, PropertyType::Residential),
+            (900000, PropertyType::Residential),
+        ];
+        for (valuation, property_type) in listings.iter() {
+            let property_id = contract
+                .register_property(create_custom_metadata(
+                    "Search Property",
+                    1000,
+                    "Test property",
+                    *valuation,
+                    "https://example.com/docs",
+                ))
+                .expect("Failed to register property");
+            contract
+                .set_property_type(property_id, property_type.clone())
+                .expect("Failed to set property type");
+        }
+
+        // Residential within the 200k-400k band
+        let all = contract.search_properties(
+            Some(PropertyType::Residential),
+            Some(200000),
+            Some(400000),
+            0,
+            10,
+        );
+        assert_eq!(all, vec![2, 4, 5]);
+
+        // Paged over the same filter
+        let first_page = contract.search_properties(
+            Some(PropertyType::Residential),
+            Some(200000),
+            Some(400000),
+            0,
+            2,
+        );
+        assert_eq!(first_page, vec![2, 4]);
+        let second_page = contract.search_properties(
+            Some(PropertyType::Residential),
+            Some(200000),
+            Some(400000),
+            2,
+            2,
+        );
+        assert_eq!(second_page, vec![5]);
+
+        // No type filter falls back to the price band only
+        let any_type = contract.search_properties(None, Some(200000), Some(400000), 0, 10);
+        assert_eq!(any_type, vec![2, 3, 4, 5]);
+    }
+
+    #[ink::test]
+    fn set_property_type_unauthorized_fails() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+
+        set_caller(accounts.bob);
+        assert_eq!(
+            contract.set_property_type(property_id, PropertyType::Land),
+            Err(Error::Unauthorized)
+        );
+        assert_eq!(contract.get_property_type(property_id), None);
+    }
+
+    // Gas Monitoring Tests
+
+    #[ink::test]
+    fn gas_metrics_tracking_works() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+
+        // Perform some operations
+        let metadata = PropertyMetadata {
+            location: "Test Property".to_string(),
+            size: 1000,
+            legal_description: "Test property".to_string(),
+            valuation: 100000,
+            documents_url: "https://example.com/docs".to_string(),
+            latitude: None,
+            longitude: None,
+        };
+
+        contract
+            .register_property(metadata)
+            .expect("Failed to register");
+
+        // Get gas metrics
+        let metrics = contract.get_gas_metrics();
         assert_eq!(metrics.total_operations, 1);
-        assert_eq!(metrics.last_operation_gas, 10000);
-        assert_eq!(metrics.average_operation_gas, 10000);
-        assert_eq!(metrics.min_gas_used, 10000);
-        assert_eq!(metrics.max_gas_used, 10000);
+        assert!(metrics.last_operation_gas > 0);
+        assert_eq!(metrics.average_operation_gas, metrics.last_operation_gas);
+        // The first operation replaces the u64::MAX sentinel
+        assert_eq!(metrics.min_gas_used, metrics.last_operation_gas);
+        assert_eq!(metrics.max_gas_used, metrics.last_operation_gas);
+    }
+
+    #[ink::test]
+    fn gas_tracking_records_every_operation() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+
+        let metadata = PropertyMetadata {
+            location: "Test Property".to_string(),
+            size: 1000,
+            legal_description: "Test property".to_string(),
+            valuation: 100000,
+            documents_url: "https://example.com/docs".to_string(),
+            latitude: None,
+            longitude: None,
+        };
+
+        let property_id = contract
+            .register_property(metadata.clone())
+            .expect("Failed to register");
+        let first = contract.get_gas_metrics();
+
+        contract
+            .transfer_property(property_id, accounts.bob)
+            .expect("Failed to transfer");
+        let second = contract.get_gas_metrics();
+
+        contract
+            .batch_register_properties(vec![metadata; 20])
+            .expect("Failed to batch register");
+        let third = contract.get_gas_metrics();
+
+        let mut previous: Option<crate::propchain_contracts::GasMetrics> = None;
+        for (count, metrics) in [first, second, third].into_iter().enumerate() {
+            // Every operation is recorded with a non-zero measurement
+            assert_eq!(metrics.total_operations, count as u64 + 1);
+            assert!(metrics.last_operation_gas > 0);
+            assert!(metrics.min_gas_used <= metrics.last_operation_gas);
+            assert!(metrics.last_operation_gas <= metrics.max_gas_used);
+            assert!(metrics.min_gas_used <= metrics.average_operation_gas);
+            assert!(metrics.average_operation_gas <= metrics.max_gas_used);
+
+            // The observed range only ever widens
+            if let Some(previous) = previous {
+                assert!(metrics.min_gas_used <= previous.min_gas_used);
+                assert!(metrics.max_gas_used >= previous.max_gas_used);
+            }
+            previous = Some(metrics);
+        }
+    }
+
+    fn high_gas_events() -> usize {
+        use crate::propchain_contracts::HighGasUsage;
+        let signature = <HighGasUsage as ink::env::Event>::SIGNATURE_TOPIC.unwrap();
+        ink::env::test::recorded_events()
+            .filter(|event| event.topics.first().map(|t| t.as_slice()) == Some(&signature[..]))
+            .count()
+    }
+
+    #[ink::test]
+    fn high_gas_usage_event_fires_above_threshold() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        assert_eq!(contract.get_gas_alert_threshold(), 0);
+
+        let metadata = PropertyMetadata {
+            location: "Test Property".to_string(),
+            size: 1000,
+            legal_description: "Test property".to_string(),
+            valuation: 100000,
+            documents_url: "https://example.com/docs".to_string(),
+            latitude: None,
+            longitude: None,
+        };
+
+        // Disabled by default
+        contract
+            .register_property(metadata.clone())
+            .expect("Failed to register");
+        assert_eq!(high_gas_events(), 0);
+
+        // Any recorded consumption crosses a threshold of one
+        assert_eq!(contract.set_gas_alert_threshold(1), Ok(()));
+
+        contract
+            .batch_register_properties(vec![metadata; 10])
+            .expect("Failed to batch register");
+        assert_eq!(high_gas_events(), 1);
+        assert!(contract.get_gas_metrics().last_operation_gas > 1);
+    }
+
+    #[ink::test]
+    fn set_gas_alert_threshold_requires_admin() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+
+        set_caller(accounts.bob);
+        assert_eq!(
+            contract.set_gas_alert_threshold(1),
+            Err(Error::Unauthorized)
+        );
+        assert_eq!(contract.get_gas_alert_threshold(), 0);
+    }
+
+    #[ink::test]
+    fn performance_recommendations_without_operations() {
+        let contract = PropertyRegistry::new();
+
+        assert_eq!(contract.get_gas_metrics().min_gas_used, 0);
+        // No spread warning before any operation has been tracked
+        assert!(!contract
+            .get_performance_recommendations()
+            .contains(&"Gas usage varies significantly - review operation patterns".to_string()));
     }
 
     #[ink::test]
@@ -1536,6 +2373,8 @@ mod tests {
             legal_description: "Test property".to_string(),
             valuation: 100000,
             documents_url: "https://example.com/docs".to_string(),
+            latitude: None,
+            longitude: None,
         };
 
         // Register multiple properties
@@ -1576,6 +2415,8 @@ mod tests {
             legal_description: "Test property".to_string(),
             valuation: 100000,
             documents_url: "https://example.com/docs".to_string(),
+            latitude: None,
+            longitude: None,
         }];
 
         let property_ids = contract
@@ -1603,6 +2444,8 @@ mod tests {
             legal_description: "Test property".to_string(),
             valuation: 100000,
             documents_url: "https://example.com/docs".to_string(),
+            latitude: None,
+            longitude: None,
         }];
 
         let property_ids = contract
@@ -1619,6 +2462,8 @@ mod tests {
                 legal_description: "Updated test property".to_string(),
                 valuation: 120000,
                 documents_url: "https://example.com/docs_updated".to_string(),
+                latitude: None,
+                longitude: None,
             },
         )];
 
@@ -1658,150 +2503,1680 @@ mod tests {
     }
 
     // ============================================================================
-    // BADGE SYSTEM TESTS
+    // ESCROW TESTS
     // ============================================================================
 
     #[ink::test]
-    fn test_badge_verifier_management() {
+    fn test_max_open_escrows_per_seller() {
         let accounts = default_accounts();
         set_caller(accounts.alice);
         let mut contract = PropertyRegistry::new();
-        assert!(contract.set_verifier(accounts.bob, true).is_ok());
-        assert!(contract.is_verifier(accounts.bob));
-        assert!(contract.set_verifier(accounts.bob, false).is_ok());
-        assert!(!contract.is_verifier(accounts.bob));
-        set_caller(accounts.charlie);
-        assert_eq!(
-            contract.set_verifier(accounts.bob, true),
-            Err(Error::Unauthorized)
-        );
+
+        assert!(contract.set_max_open_escrows_per_seller(Some(2)).is_ok());
+
+        let mut escrow_ids = Vec::new();
+        for _ in 0..3 {
+            let property_id = contract
+                .register_property(create_sample_metadata())
+                .expect("Failed to register property");
+            escrow_ids.push(contract.create_escrow(property_id, accounts.bob, 1000));
+        }
+
+        // Escrows up to the limit succeed, the next one fails
+        assert!(escrow_ids[0].is_ok());
+        assert!(escrow_ids[1].is_ok());
+        assert_eq!(escrow_ids[2], Err(Error::TooManyOpenEscrows));
+        assert_eq!(contract.get_open_escrow_count(accounts.alice), 2);
+
+        // Releasing an escrow frees a slot
+        fund_as_buyer(&mut contract, *escrow_ids[0].as_ref().unwrap());
+        set_caller(accounts.alice);
+        assert!(contract.approve(1, Some(accounts.bob)).is_ok());
+        set_caller(accounts.bob);
+        assert!(contract
+            .release_escrow(*escrow_ids[0].as_ref().unwrap())
+            .is_ok());
+        assert_eq!(contract.get_open_escrow_count(accounts.alice), 1);
+
+        set_caller(accounts.alice);
+        assert!(contract.create_escrow(3, accounts.bob, 1000).is_ok());
     }
 
     #[ink::test]
-    fn test_badge_issuance_and_query() {
-        use crate::propchain_contracts::BadgeType;
+    fn test_set_max_open_escrows_unauthorized() {
         let accounts = default_accounts();
         set_caller(accounts.alice);
         let mut contract = PropertyRegistry::new();
-        let property_id = contract
-            .register_property(create_sample_metadata())
-            .expect("Failed to register property");
-        assert!(contract.set_verifier(accounts.bob, true).is_ok());
+
         set_caller(accounts.bob);
-        assert!(contract
-            .issue_badge(
-                property_id,
-                BadgeType::DocumentVerification,
-                None,
-                "https://metadata.example.com/badge.json".to_string()
-            )
-            .is_ok());
-        assert!(contract.has_badge(property_id, BadgeType::DocumentVerification));
-        let badge = contract.get_badge(property_id, BadgeType::DocumentVerification);
-        assert!(badge.is_some());
-        assert_eq!(badge.unwrap().issued_by, accounts.bob);
+        assert_eq!(
+            contract.set_max_open_escrows_per_seller(Some(1)),
+            Err(Error::Unauthorized)
+        );
     }
 
     #[ink::test]
-    fn test_verification_request_workflow() {
-        use crate::propchain_contracts::BadgeType;
+    fn test_min_escrow_amount() {
         let accounts = default_accounts();
         set_caller(accounts.alice);
         let mut contract = PropertyRegistry::new();
-        let property_id = contract
+        let first = contract
             .register_property(create_sample_metadata())
-            .expect("Failed to register property");
-        let request_id = contract
-            .request_verification(
-                property_id,
-                BadgeType::LegalCompliance,
-                "https://evidence.example.com/docs.pdf".to_string(),
-            )
-            .expect("Failed to request verification");
-        assert_eq!(request_id, 1);
-        assert!(contract.set_verifier(accounts.bob, true).is_ok());
+            .unwrap();
+        let second = contract
+            .register_property(create_sample_metadata())
+            .unwrap();
+
+        // No minimum by default, so even a zero-value escrow is accepted
+        assert_eq!(contract.get_min_escrow_amount(), 0);
+        assert!(contract.create_escrow(first, accounts.bob, 0).is_ok());
+
         set_caller(accounts.bob);
-        assert!(contract
-            .review_verification(
-                request_id,
-                true,
-                Some(1000000),
-                "https://metadata.example.com/badge.json".to_string()
-            )
-            .is_ok());
-        assert!(contract.has_badge(property_id, BadgeType::LegalCompliance));
+        assert_eq!(
+            contract.set_min_escrow_amount(500),
+            Err(Error::Unauthorized)
+        );
+        set_caller(accounts.alice);
+        assert!(contract.set_min_escrow_amount(500).is_ok());
+
+        assert_eq!(
+            contract.create_escrow(second, accounts.bob, 499),
+            Err(Error::EscrowAmountTooLow)
+        );
+        assert_eq!(contract.get_open_escrow_count(accounts.alice), 1);
+        assert!(contract.create_escrow(second, accounts.bob, 500).is_ok());
+    }
+
+    fn set_value_transferred(value: u128) {
+        ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(value);
+    }
+
+    fn balance_of(account: AccountId) -> u128 {
+        ink::env::test::get_account_balance::<ink::env::DefaultEnvironment>(account)
+            .expect("Account should have a balance")
+    }
+
+    /// Funds an escrow as its buyer, leaving the buyer as the caller.
+    /// The off-chain environment does not move the deposit, so the contract is seeded with it.
+    fn fund_as_buyer(contract: &mut PropertyRegistry, escrow_id: u64) {
+        let escrow = contract.get_escrow(escrow_id).expect("Escrow should exist");
+        set_caller(escrow.buyer);
+        set_value_transferred(escrow.amount);
+        contract
+            .fund_escrow(escrow_id)
+            .expect("Failed to fund escrow");
+        set_value_transferred(0);
+
+        let contract_id = ink::env::test::callee::<ink::env::DefaultEnvironment>();
+        let balance =
+            ink::env::test::get_account_balance::<ink::env::DefaultEnvironment>(contract_id)
+                .unwrap_or(0);
+        ink::env::test::set_account_balance::<ink::env::DefaultEnvironment>(
+            contract_id,
+            balance + escrow.amount,
+        );
     }
 
     #[ink::test]
-    fn test_badge_revocation() {
-        use crate::propchain_contracts::BadgeType;
+    fn escrow_status_follows_fund_and_release() {
         let accounts = default_accounts();
         set_caller(accounts.alice);
         let mut contract = PropertyRegistry::new();
         let property_id = contract
             .register_property(create_sample_metadata())
             .expect("Failed to register property");
-        assert!(contract.set_verifier(accounts.bob, true).is_ok());
+
+        let escrow_id = contract
+            .create_escrow(property_id, accounts.bob, 1000)
+            .expect("Failed to create escrow");
+        let escrow = contract.get_escrow(escrow_id).unwrap();
+        assert_eq!(escrow.status, EscrowStatus::Created);
+        assert!(!escrow.released());
+
+        // Funding requires the exact amount
         set_caller(accounts.bob);
-        assert!(contract
-            .issue_badge(
-                property_id,
-                BadgeType::OwnerVerification,
-                None,
-                "https://metadata.example.com/badge.json".to_string()
-            )
-            .is_ok());
-        assert!(contract
-            .revoke_badge(
-                property_id,
-                BadgeType::OwnerVerification,
-                "Failed KYC".to_string()
-            )
-            .is_ok());
-        assert!(!contract.has_badge(property_id, BadgeType::OwnerVerification));
-        let badge = contract.get_badge(property_id, BadgeType::OwnerVerification);
-        assert!(badge.is_some());
-        assert!(badge.unwrap().revoked);
+        set_value_transferred(999);
+        assert_eq!(
+            contract.fund_escrow(escrow_id),
+            Err(Error::EscrowAmountMismatch)
+        );
+        set_value_transferred(1000);
+        assert!(contract.fund_escrow(escrow_id).is_ok());
+        assert_eq!(
+            contract.get_escrow(escrow_id).unwrap().status,
+            EscrowStatus::Funded
+        );
+        assert_eq!(
+            contract.fund_escrow(escrow_id),
+            Err(Error::InvalidEscrowStatus)
+        );
+
+        // The off-chain environment does not move the deposit, so seed the contract
+        let contract_id = ink::env::test::callee::<ink::env::DefaultEnvironment>();
+        ink::env::test::set_account_balance::<ink::env::DefaultEnvironment>(contract_id, 1000);
+        let seller_balance = balance_of(accounts.alice);
+
+        set_caller(accounts.alice);
+        assert!(contract.approve(property_id, Some(accounts.bob)).is_ok());
+        set_caller(accounts.bob);
+        assert!(contract.release_escrow(escrow_id).is_ok());
+
+        let escrow = contract.get_escrow(escrow_id).unwrap();
+        assert_eq!(escrow.status, EscrowStatus::Released);
+        assert!(escrow.released());
+        assert_eq!(balance_of(accounts.alice), seller_balance + 1000);
+        assert_eq!(
+            contract.release_escrow(escrow_id),
+            Err(Error::EscrowAlreadyReleased)
+        );
     }
 
     #[ink::test]
-    fn test_badge_appeal_process() {
-        use crate::propchain_contracts::BadgeType;
+    fn escrow_status_follows_refund() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        let unfunded_property = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let funded_property = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+
+        // Refunding an unfunded escrow just closes it
+        let unfunded = contract
+            .create_escrow(unfunded_property, accounts.bob, 1000)
+            .expect("Failed to create escrow");
+        assert!(contract.refund_escrow(unfunded).is_ok());
+        let escrow = contract.get_escrow(unfunded).unwrap();
+        assert_eq!(escrow.status, EscrowStatus::Refunded);
+        assert!(escrow.released());
+
+        set_caller(accounts.bob);
+        set_value_transferred(1000);
+        assert_eq!(
+            contract.fund_escrow(unfunded),
+            Err(Error::EscrowAlreadyReleased)
+        );
+
+        // Refunding a funded escrow returns the deposit to the buyer
+        set_caller(accounts.alice);
+        let funded = contract
+            .create_escrow(funded_property, accounts.bob, 1000)
+            .expect("Failed to create escrow");
+        set_caller(accounts.bob);
+        assert!(contract.fund_escrow(funded).is_ok());
+
+        let contract_id = ink::env::test::callee::<ink::env::DefaultEnvironment>();
+        ink::env::test::set_account_balance::<ink::env::DefaultEnvironment>(contract_id, 1000);
+        let buyer_balance = balance_of(accounts.bob);
+
+        set_caller(accounts.alice);
+        assert!(contract.refund_escrow(funded).is_ok());
+        assert_eq!(
+            contract.get_escrow(funded).unwrap().status,
+            EscrowStatus::Refunded
+        );
+        assert_eq!(balance_of(accounts.bob), buyer_balance + 1000);
+    }
+
+    #[ink::test]
+    fn create_escrow_rejects_self_escrow() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+
+        assert_eq!(
+            contract.create_escrow(property_id, accounts.alice, 1000),
+            Err(Error::InvalidEscrowParties)
+        );
+        assert_eq!(contract.get_open_escrow_count(accounts.alice), 0);
+    }
+
+    #[ink::test]
+    fn escrow_trait_creates_escrow_for_buyer() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+
+        let escrow_id = Escrow::create_escrow(&mut contract, property_id, accounts.bob, 1000)
+            .expect("Failed to create escrow");
+        let escrow = contract.get_escrow(escrow_id).unwrap();
+        assert_eq!(escrow.seller, accounts.alice);
+        assert_eq!(escrow.buyer, accounts.bob);
+        assert_eq!(escrow.amount, 1000);
+    }
+
+    #[ink::test]
+    fn release_escrow_requires_funding() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let escrow_id = contract
+            .create_escrow(property_id, accounts.bob, 1000)
+            .expect("Failed to create escrow");
+        assert!(contract.approve(property_id, Some(accounts.bob)).is_ok());
+
+        // The buyer cannot take the property without paying
+        set_caller(accounts.bob);
+        assert_eq!(
+            contract.release_escrow(escrow_id),
+            Err(Error::InvalidEscrowStatus)
+        );
+        assert_eq!(
+            contract.get_property(property_id).unwrap().owner,
+            accounts.alice
+        );
+        assert_eq!(
+            contract.get_escrow(escrow_id).unwrap().status,
+            EscrowStatus::Created
+        );
+
+        // A zero-amount escrow has nothing to fund
+        set_caller(accounts.alice);
+        let free_property = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let free_escrow = contract
+            .create_escrow(free_property, accounts.bob, 0)
+            .expect("Failed to create escrow");
+        assert!(contract.approve(free_property, Some(accounts.bob)).is_ok());
+        set_caller(accounts.bob);
+        assert!(contract.release_escrow(free_escrow).is_ok());
+        assert_eq!(
+            contract.get_property(free_property).unwrap().owner,
+            accounts.bob
+        );
+    }
+
+    #[ink::test]
+    fn release_escrow_fails_after_property_transferred_away() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+
+        let escrow_id = contract
+            .create_escrow(property_id, accounts.bob, 1000)
+            .expect("Failed to create escrow");
+
+        // The seller sells the property elsewhere while the escrow is open
+        assert!(contract
+            .transfer_property(property_id, accounts.charlie)
+            .is_ok());
+
+        set_caller(accounts.bob);
+        assert_eq!(
+            contract.release_escrow(escrow_id),
+            Err(Error::InvalidEscrowParties)
+        );
+        assert_eq!(
+            contract.get_property(property_id).unwrap().owner,
+            accounts.charlie
+        );
+        assert_eq!(
+            contract.get_escrow(escrow_id).unwrap().status,
+            EscrowStatus::Created
+        );
+    }
+
+    #[ink::test]
+    fn release_escrow_does_not_need_separate_approval() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let escrow_id = contract
+            .create_escrow(property_id, accounts.bob, 1000)
+            .expect("Failed to create escrow");
+        fund_as_buyer(&mut contract, escrow_id);
+
+        // The seller never calls approve
+        set_caller(accounts.bob);
+        assert!(contract.release_escrow(escrow_id).is_ok());
+        assert_eq!(
+            contract.get_property(property_id).unwrap().owner,
+            accounts.bob
+        );
+        assert_eq!(
+            contract.get_escrow(escrow_id).unwrap().status,
+            EscrowStatus::Released
+        );
+    }
+
+    #[ink::test]
+    fn buyer_reclaims_deposit_after_seller_moves_property() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let escrow_id = contract
+            .create_escrow(property_id, accounts.bob, 1000)
+            .expect("Failed to create escrow");
+        fund_as_buyer(&mut contract, escrow_id);
+
+        // While the sale can still complete, only the seller may refund
+        assert_eq!(contract.refund_escrow(escrow_id), Err(Error::Unauthorized));
+
+        // The seller sells the property elsewhere, so release can no longer succeed
+        set_caller(accounts.alice);
+        assert!(contract
+            .transfer_property(property_id, accounts.charlie)
+            .is_ok());
+        set_caller(accounts.bob);
+        assert_eq!(
+            contract.release_escrow(escrow_id),
+            Err(Error::InvalidEscrowParties)
+        );
+
+        let buyer_balance = balance_of(accounts.bob);
+        set_caller(accounts.django);
+        assert_eq!(contract.refund_escrow(escrow_id), Err(Error::Unauthorized));
+        set_caller(accounts.bob);
+        assert!(contract.refund_escrow(escrow_id).is_ok());
+        assert_eq!(balance_of(accounts.bob), buyer_balance + 1000);
+        assert_eq!(
+            contract.get_escrow(escrow_id).unwrap().status,
+            EscrowStatus::Refunded
+        );
+        assert_eq!(contract.get_open_escrow_count(accounts.alice), 0);
+    }
+
+    #[ink::test]
+    fn buyer_reclaims_deposit_while_paused() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let escrow_id = contract
+            .create_escrow(property_id, accounts.bob, 1000)
+            .expect("Failed to create escrow");
+        fund_as_buyer(&mut contract, escrow_id);
+
+        set_caller(accounts.alice);
+        assert!(contract.pause().is_ok());
+        set_caller(accounts.bob);
+        assert_eq!(
+            contract.release_escrow(escrow_id),
+            Err(Error::ContractPaused)
+        );
+        assert!(contract.refund_escrow(escrow_id).is_ok());
+        assert_eq!(
+            contract.get_property(property_id).unwrap().owner,
+            accounts.alice
+        );
+    }
+
+    // ============================================================================
+    // BADGE SYSTEM TESTS
+    // ============================================================================
+
+    #[ink::test]
+    fn test_badge_verifier_management() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        assert!(contract.set_verifier(accounts.bob, true).is_ok());
+        assert!(contract.is_verifier(accounts.bob));
+        assert!(contract.set_verifier(accounts.bob, false).is_ok());
+        assert!(!contract.is_verifier(accounts.bob));
+        set_caller(accounts.charlie);
+        assert_eq!(
+            contract.set_verifier(accounts.bob, true),
+            Err(Error::Unauthorized)
+        );
+    }
+
+    #[ink::test]
+    fn test_badge_issuance_and_query() {
+        use crate::propchain_contracts::BadgeType;
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        assert!(contract.set_verifier(accounts.bob, true).is_ok());
+        set_caller(accounts.bob);
+        assert!(contract
+            .issue_badge(
+                property_id,
+                BadgeType::DocumentVerification,
+                None,
+                "https://metadata.example.com/badge.json".to_string()
+            )
+            .is_ok());
+        assert!(contract.has_badge(property_id, BadgeType::DocumentVerification));
+        let badge = contract.get_badge(property_id, BadgeType::DocumentVerification);
+        assert!(badge.is_some());
+        assert_eq!(badge.unwrap().issued_by, accounts.bob);
+    }
+
+    #[ink::test]
+    fn test_property_with_badges_combined_view() {
+        use crate::propchain_contracts::BadgeType;
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        assert!(contract.get_property_with_badges(property_id + 1).is_none());
+
+        for badge_type in [BadgeType::OwnerVerification, BadgeType::LegalCompliance] {
+            contract
+                .issue_badge(
+                    property_id,
+                    badge_type,
+                    None,
+                    "https://metadata.example.com/badge.json".to_string(),
+                )
+                .unwrap();
+        }
+
+        let (property, badges) = contract.get_property_with_badges(property_id).unwrap();
+        assert_eq!(Some(property), contract.get_property(property_id));
+        assert_eq!(badges, contract.get_property_badges(property_id));
+        let types: Vec<BadgeType> = badges.iter().map(|(badge_type, _)| *badge_type).collect();
+        assert_eq!(
+            types,
+            vec![BadgeType::OwnerVerification, BadgeType::LegalCompliance]
+        );
+    }
+
+    #[ink::test]
+    fn test_register_and_issue_custom_badge_type() {
+        use crate::propchain_contracts::BadgeType;
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+
+        // Built-in types are pre-registered
+        assert_eq!(contract.get_badge_types().len(), 4);
+        assert_eq!(
+            contract.get_badge_type_name(BadgeType::LegalCompliance.id()),
+            Some("LegalCompliance".to_string())
+        );
+
+        // Only the admin registers types, and names are unique
+        set_caller(accounts.bob);
+        assert_eq!(
+            contract.register_badge_type("EnergyRating".to_string()),
+            Err(Error::Unauthorized)
+        );
+        set_caller(accounts.alice);
+        assert_eq!(
+            contract.register_badge_type("PremiumListing".to_string()),
+            Err(Error::BadgeTypeAlreadyRegistered)
+        );
+        let energy_id = contract
+            .register_badge_type("EnergyRating".to_string())
+            .unwrap();
+        assert_eq!(energy_id, BadgeType::FIRST_CUSTOM_ID);
+        assert_eq!(
+            contract.register_badge_type("EnergyRating".to_string()),
+            Err(Error::BadgeTypeAlreadyRegistered)
+        );
+        assert_eq!(
+            contract.get_badge_types().last(),
+            Some(&(energy_id, "EnergyRating".to_string()))
+        );
+
+        // Unregistered custom IDs cannot be issued
+        let url = "https://metadata.example.com/energy.json".to_string();
+        assert_eq!(
+            contract.issue_badge(
+                property_id,
+                BadgeType::Custom(energy_id + 1),
+                None,
+                url.clone()
+            ),
+            Err(Error::InvalidBadgeType)
+        );
+
+        let energy = BadgeType::from_id(energy_id);
+        assert_eq!(energy, BadgeType::Custom(energy_id));
+        contract
+            .issue_badge(property_id, BadgeType::OwnerVerification, None, url.clone())
+            .unwrap();
+        contract
+            .issue_badge(property_id, energy, None, url)
+            .unwrap();
+        assert!(contract.has_badge(property_id, energy));
+
+        let types: Vec<BadgeType> = contract
+            .get_property_badges(property_id)
+            .iter()
+            .map(|(badge_type, _)| *badge_type)
+            .collect();
+        assert_eq!(types, vec![BadgeType::OwnerVerification, energy]);
+    }
+
+    #[ink::test]
+    fn test_verification_requests_for_property() {
+        use crate::propchain_contracts::BadgeType;
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .unwrap();
+        let other_id = contract
+            .register_property(create_sample_metadata())
+            .unwrap();
+
+        let first = contract
+            .request_verification(
+                property_id,
+                BadgeType::DocumentVerification,
+                "ipfs://deed".to_string(),
+            )
+            .unwrap();
+        let unrelated = contract
+            .request_verification(
+                other_id,
+                BadgeType::OwnerVerification,
+                "ipfs://id".to_string(),
+            )
+            .unwrap();
+        let second = contract
+            .request_verification(
+                property_id,
+                BadgeType::LegalCompliance,
+                "ipfs://permit".to_string(),
+            )
+            .unwrap();
+
+        let requests = contract.get_verification_requests_for_property(property_id);
+        let ids: Vec<u64> = requests.iter().map(|r| r.id).collect();
+        assert_eq!(ids, vec![first, second]);
+        assert_eq!(requests[1].badge_type, BadgeType::LegalCompliance);
+        assert_eq!(
+            contract
+                .get_verification_requests_for_property(other_id)
+                .iter()
+                .map(|r| r.id)
+                .collect::<Vec<_>>(),
+            vec![unrelated]
+        );
+        assert!(contract
+            .get_verification_requests_for_property(99)
+            .is_empty());
+    }
+
+    #[ink::test]
+    fn test_verification_request_cooldown() {
+        use crate::propchain_contracts::BadgeType;
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1000);
+        let mut contract = PropertyRegistry::new();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .unwrap();
+        assert_eq!(contract.get_request_cooldown(), None);
+
+        set_caller(accounts.bob);
+        assert_eq!(
+            contract.set_request_cooldown(Some(500)),
+            Err(Error::Unauthorized)
+        );
+        set_caller(accounts.alice);
+        assert!(contract.set_request_cooldown(Some(500)).is_ok());
+
+        assert!(contract
+            .request_verification(
+                property_id,
+                BadgeType::DocumentVerification,
+                "ipfs://deed".to_string()
+            )
+            .is_ok());
+        ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1499);
+        assert_eq!(
+            contract.request_verification(
+                property_id,
+                BadgeType::LegalCompliance,
+                "ipfs://permit".to_string()
+            ),
+            Err(Error::RequestTooSoon)
+        );
+
+        ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1500);
+        assert!(contract
+            .request_verification(
+                property_id,
+                BadgeType::LegalCompliance,
+                "ipfs://permit".to_string()
+            )
+            .is_ok());
+    }
+
+    #[ink::test]
+    fn test_verification_requests_without_cooldown() {
+        use crate::propchain_contracts::BadgeType;
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .unwrap();
+        assert!(contract
+            .request_verification(
+                property_id,
+                BadgeType::DocumentVerification,
+                "ipfs://deed".to_string()
+            )
+            .is_ok());
+        assert!(contract
+            .request_verification(
+                property_id,
+                BadgeType::LegalCompliance,
+                "ipfs://permit".to_string()
+            )
+            .is_ok());
+    }
+
+    #[ink::test]
+    fn test_verification_request_workflow() {
+        use crate::propchain_contracts::BadgeType;
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let request_id = contract
+            .request_verification(
+                property_id,
+                BadgeType::LegalCompliance,
+                "https://evidence.example.com/docs.pdf".to_string(),
+            )
+            .expect("Failed to request verification");
+        assert_eq!(request_id, 1);
+        assert!(contract.set_verifier(accounts.bob, true).is_ok());
+        set_caller(accounts.bob);
+        assert!(contract
+            .review_verification(
+                request_id,
+                true,
+                Some(1000000),
+                "https://metadata.example.com/badge.json".to_string()
+            )
+            .is_ok());
+        assert!(contract.has_badge(property_id, BadgeType::LegalCompliance));
+    }
+
+    #[ink::test]
+    fn test_review_verification_rejects_burned_property() {
+        use crate::propchain_contracts::{BadgeType, VerificationStatus};
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let request_id = contract
+            .request_verification(
+                property_id,
+                BadgeType::LegalCompliance,
+                "https://evidence.example.com/docs.pdf".to_string(),
+            )
+            .expect("Failed to request verification");
+        assert!(contract.burn_property(property_id).is_ok());
+
+        // Burning cancels the pending request, so it can no longer be reviewed
+        assert_eq!(
+            contract.review_verification(
+                request_id,
+                true,
+                None,
+                "https://metadata.example.com/badge.json".to_string()
+            ),
+            Err(Error::InvalidVerificationStatus)
+        );
+        let request = contract.get_verification_request(request_id).unwrap();
+        assert_eq!(request.status, VerificationStatus::Cancelled);
+        assert_eq!(request.reviewed_by, None);
+        assert!(!contract.has_badge(property_id, BadgeType::LegalCompliance));
+    }
+
+    #[ink::test]
+    fn test_review_verification_rolls_back_when_badge_issue_fails() {
+        use crate::propchain_contracts::{BadgeType, VerificationStatus};
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let request_id = contract
+            .request_verification(
+                property_id,
+                BadgeType::LegalCompliance,
+                "https://evidence.example.com/docs.pdf".to_string(),
+            )
+            .expect("Failed to request verification");
+        assert!(contract
+            .issue_badge(
+                property_id,
+                BadgeType::LegalCompliance,
+                None,
+                "https://metadata.example.com/badge.json".to_string()
+            )
+            .is_ok());
+
+        assert_eq!(
+            contract.review_verification(
+                request_id,
+                true,
+                None,
+                "https://metadata.example.com/badge.json".to_string()
+            ),
+            Err(Error::BadgeAlreadyIssued)
+        );
+        let request = contract.get_verification_request(request_id).unwrap();
+        assert_eq!(request.status, VerificationStatus::Pending);
+        assert_eq!(request.reviewed_at, None);
+    }
+
+    #[ink::test]
+    fn test_cancel_verification_request() {
+        use crate::propchain_contracts::{BadgeType, VerificationStatus};
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let request_id = contract
+            .request_verification(
+                property_id,
+                BadgeType::LegalCompliance,
+                "https://evidence.example.com/docs.pdf".to_string(),
+            )
+            .expect("Failed to request verification");
+
+        set_caller(accounts.bob);
+        assert_eq!(
+            contract.cancel_verification_request(request_id),
+            Err(Error::Unauthorized)
+        );
+
+        set_caller(accounts.alice);
+        assert!(contract.cancel_verification_request(request_id).is_ok());
+        assert_eq!(
+            contract
+                .get_verification_request(request_id)
+                .unwrap()
+                .status,
+            VerificationStatus::Cancelled
+        );
+        assert_eq!(
+            contract.cancel_verification_request(request_id),
+            Err(Error::InvalidVerificationStatus)
+        );
+
+        // A cancelled request can no longer be reviewed
+        assert_eq!(
+            contract.review_verification(
+                request_id,
+                true,
+                None,
+                "https://metadata.example.com/badge.json".to_string()
+            ),
+            Err(Error::InvalidVerificationStatus)
+        );
+        assert!(!contract.has_badge(property_id, BadgeType::LegalCompliance));
+    }
+
+    #[ink::test]
+    fn test_cancel_reviewed_verification_request_fails() {
+        use crate::propchain_contracts::BadgeType;
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let request_id = contract
+            .request_verification(
+                property_id,
+                BadgeType::LegalCompliance,
+                "https://evidence.example.com/docs.pdf".to_string(),
+            )
+            .expect("Failed to request verification");
+        assert!(contract
+            .review_verification(
+                request_id,
+                false,
+                None,
+                "https://metadata.example.com/badge.json".to_string()
+            )
+            .is_ok());
+
+        assert_eq!(
+            contract.cancel_verification_request(request_id),
+            Err(Error::InvalidVerificationStatus)
+        );
+    }
+
+    #[ink::test]
+    fn test_badge_revocation() {
+        use crate::propchain_contracts::BadgeType;
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        assert!(contract.set_verifier(accounts.bob, true).is_ok());
+        set_caller(accounts.bob);
+        assert!(contract
+            .issue_badge(
+                property_id,
+                BadgeType::OwnerVerification,
+                None,
+                "https://metadata.example.com/badge.json".to_string()
+            )
+            .is_ok());
+        assert!(contract
+            .revoke_badge(
+                property_id,
+                BadgeType::OwnerVerification,
+                "Failed KYC".to_string()
+            )
+            .is_ok());
+        assert!(!contract.has_badge(property_id, BadgeType::OwnerVerification));
+        let badge = contract.get_badge(property_id, BadgeType::OwnerVerification);
+        assert!(badge.is_some());
+        assert!(badge.unwrap().revoked);
+    }
+
+    #[ink::test]
+    fn test_badge_appeal_process() {
+        use crate::propchain_contracts::BadgeType;
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        assert!(contract.set_verifier(accounts.bob, true).is_ok());
+        set_caller(accounts.bob);
+        assert!(contract
+            .issue_badge(
+                property_id,
+                BadgeType::DocumentVerification,
+                None,
+                "https://metadata.example.com/badge.json".to_string()
+            )
+            .is_ok());
+        assert!(contract
+            .revoke_badge(
+                property_id,
+                BadgeType::DocumentVerification,
+                "Documents expired".to_string()
+            )
+            .is_ok());
+        set_caller(accounts.alice);
+        let appeal_id = contract
+            .submit_appeal(
+                property_id,
+                BadgeType::DocumentVerification,
+                "Documents renewed".to_string(),
+            )
+            .expect("Failed to submit appeal");
+        assert_eq!(appeal_id, 1);
+        assert!(contract
+            .resolve_appeal(appeal_id, true, "Reinstating badge".to_string())
+            .is_ok());
+        assert!(contract.has_badge(property_id, BadgeType::DocumentVerification));
+    }
+
+    #[ink::test]
+    fn appeals_are_indexed_by_property_and_pending_status() {
+        use crate::propchain_contracts::BadgeType;
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        assert!(contract.set_verifier(accounts.bob, true).is_ok());
+
+        let first = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let second = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+
+        // Issue and revoke two badges on the first property and one on the second
+        set_caller(accounts.bob);
+        for (property_id, badge_type) in [
+            (first, BadgeType::DocumentVerification),
+            (first, BadgeType::LegalCompliance),
+            (second, BadgeType::DocumentVerification),
+        ] {
+            assert!(contract
+                .issue_badge(
+                    property_id,
+                    badge_type,
+                    None,
+                    "https://metadata.example.com/badge.json".to_string()
+                )
+                .is_ok());
+            assert!(contract
+                .revoke_badge(property_id, badge_type, "Documents expired".to_string())
+                .is_ok());
+        }
+
+        set_caller(accounts.alice);
+        let appeal_a = contract
+            .submit_appeal(
+                first,
+                BadgeType::DocumentVerification,
+                "Renewed".to_string(),
+            )
+            .expect("Failed to submit appeal");
+        let appeal_b = contract
+            .submit_appeal(
+                second,
+                BadgeType::DocumentVerification,
+                "Renewed".to_string(),
+            )
+            .expect("Failed to submit appeal");
+        let appeal_c = contract
+            .submit_appeal(first, BadgeType::LegalCompliance, "Compliant".to_string())
+            .expect("Failed to submit appeal");
+
+        assert_eq!(
+            contract.get_appeals_for_property(first),
+            vec![appeal_a, appeal_c]
+        );
+        assert_eq!(contract.get_appeals_for_property(second), vec![appeal_b]);
+        assert_eq!(contract.get_appeals_for_property(99), Vec::<u64>::new());
+        assert_eq!(
+            contract.get_pending_appeals(0, 10),
+            vec![appeal_a, appeal_b, appeal_c]
+        );
+        assert_eq!(contract.get_pending_appeals(1, 1), vec![appeal_b]);
+
+        // Resolving removes the appeal from the pending list but not the property history
+        assert!(contract
+            .resolve_appeal(appeal_b, false, "Rejected".to_string())
+            .is_ok());
+        assert_eq!(
+            contract.get_pending_appeals(0, 10),
+            vec![appeal_a, appeal_c]
+        );
+        assert_eq!(contract.get_appeals_for_property(second), vec![appeal_b]);
+        assert_eq!(contract.get_pending_appeal_count(), 2);
+
+        // The last pending appeal takes over the freed slot
+        assert!(contract
+            .resolve_appeal(appeal_a, true, "Approved".to_string())
+            .is_ok());
+        assert_eq!(contract.get_pending_appeals(0, 10), vec![appeal_c]);
+        assert_eq!(contract.get_pending_appeal_count(), 1);
+    }
+
+    #[ink::test]
+    fn duplicate_pending_appeal_rejected_until_resolved() {
+        use crate::propchain_contracts::BadgeType;
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        assert!(contract.set_verifier(accounts.bob, true).is_ok());
+
+        set_caller(accounts.bob);
+        assert!(contract
+            .issue_badge(
+                property_id,
+                BadgeType::DocumentVerification,
+                None,
+                "https://metadata.example.com/badge.json".to_string()
+            )
+            .is_ok());
+        assert!(contract
+            .revoke_badge(
+                property_id,
+                BadgeType::DocumentVerification,
+                "Documents expired".to_string()
+            )
+            .is_ok());
+
+        set_caller(accounts.alice);
+        let first = contract
+            .submit_appeal(
+                property_id,
+                BadgeType::DocumentVerification,
+                "Documents renewed".to_string(),
+            )
+            .expect("First appeal should succeed");
+        assert_eq!(
+            contract.submit_appeal(
+                property_id,
+                BadgeType::DocumentVerification,
+                "Duplicate".to_string(),
+            ),
+            Err(Error::AppealAlreadyPending)
+        );
+
+        // Once rejected, the owner may appeal again
+        assert!(contract
+            .resolve_appeal(first, false, "Insufficient evidence".to_string())
+            .is_ok());
+        let second = contract
+            .submit_appeal(
+                property_id,
+                BadgeType::DocumentVerification,
+                "New evidence".to_string(),
+            )
+            .expect("Appeal after resolution should succeed");
+        assert_ne!(first, second);
+    }
+
+    #[ink::test]
+    fn verifier_can_reinstate_revoked_badge() {
+        use crate::propchain_contracts::BadgeType;
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        assert!(contract.set_verifier(accounts.bob, true).is_ok());
+
+        set_caller(accounts.bob);
+        assert!(contract
+            .issue_badge(
+                property_id,
+                BadgeType::OwnerVerification,
+                None,
+                "https://metadata.example.com/badge.json".to_string()
+            )
+            .is_ok());
+        assert!(contract
+            .revoke_badge(
+                property_id,
+                BadgeType::OwnerVerification,
+                "Revoked by mistake".to_string()
+            )
+            .is_ok());
+        assert!(!contract.has_badge(property_id, BadgeType::OwnerVerification));
+
+        assert!(contract
+            .reinstate_badge(property_id, BadgeType::OwnerVerification)
+            .is_ok());
+        assert!(contract.has_badge(property_id, BadgeType::OwnerVerification));
+        let badge = contract
+            .get_badge(property_id, BadgeType::OwnerVerification)
+            .expect("Badge should exist");
+        assert!(!badge.revoked);
+        assert_eq!(badge.revoked_at, None);
+        assert!(badge.revocation_reason.is_empty());
+    }
+
+    #[ink::test]
+    fn reinstating_badge_closes_pending_appeal() {
+        use crate::propchain_contracts::{AppealStatus, BadgeType};
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        assert!(contract.set_verifier(accounts.bob, true).is_ok());
+
+        set_caller(accounts.bob);
+        assert!(contract
+            .issue_badge(
+                property_id,
+                BadgeType::OwnerVerification,
+                None,
+                "https://metadata.example.com/badge.json".to_string()
+            )
+            .is_ok());
+        assert!(contract
+            .revoke_badge(
+                property_id,
+                BadgeType::OwnerVerification,
+                "Revoked by mistake".to_string()
+            )
+            .is_ok());
+
+        set_caller(accounts.alice);
+        let appeal_id = contract
+            .submit_appeal(
+                property_id,
+                BadgeType::OwnerVerification,
+                "Mistake".to_string(),
+            )
+            .expect("Failed to submit appeal");
+
+        set_caller(accounts.bob);
+        assert!(contract
+            .reinstate_badge(property_id, BadgeType::OwnerVerification)
+            .is_ok());
+
+        let appeal = contract.get_appeal(appeal_id).expect("Appeal should exist");
+        assert_eq!(appeal.status, AppealStatus::Approved);
+        assert_eq!(appeal.resolved_by, Some(accounts.bob));
+        assert_eq!(contract.get_pending_appeals(0, 10), Vec::<u64>::new());
+        assert_eq!(contract.get_pending_appeal_count(), 0);
+
+        // The closed appeal cannot be resolved against the reinstated badge
+        set_caller(accounts.alice);
+        assert_eq!(
+            contract.resolve_appeal(appeal_id, false, "Stale".to_string()),
+            Err(Error::InvalidAppealStatus)
+        );
+        assert!(contract.has_badge(property_id, BadgeType::OwnerVerification));
+
+        // A later revocation can be appealed again
+        set_caller(accounts.bob);
+        assert!(contract
+            .revoke_badge(
+                property_id,
+                BadgeType::OwnerVerification,
+                "Documents expired".to_string()
+            )
+            .is_ok());
+        set_caller(accounts.alice);
+        assert!(contract
+            .submit_appeal(
+                property_id,
+                BadgeType::OwnerVerification,
+                "Renewed".to_string()
+            )
+            .is_ok());
+    }
+
+    #[ink::test]
+    fn reinstate_badge_rejects_active_or_unauthorized() {
+        use crate::propchain_contracts::BadgeType;
         let accounts = default_accounts();
         set_caller(accounts.alice);
         let mut contract = PropertyRegistry::new();
         let property_id = contract
             .register_property(create_sample_metadata())
             .expect("Failed to register property");
-        assert!(contract.set_verifier(accounts.bob, true).is_ok());
-        set_caller(accounts.bob);
+
+        assert_eq!(
+            contract.reinstate_badge(property_id, BadgeType::OwnerVerification),
+            Err(Error::BadgeNotFound)
+        );
+
         assert!(contract
             .issue_badge(
                 property_id,
-                BadgeType::DocumentVerification,
+                BadgeType::OwnerVerification,
                 None,
                 "https://metadata.example.com/badge.json".to_string()
             )
             .is_ok());
+        assert_eq!(
+            contract.reinstate_badge(property_id, BadgeType::OwnerVerification),
+            Err(Error::BadgeNotRevoked)
+        );
+
+        set_caller(accounts.charlie);
+        assert_eq!(
+            contract.reinstate_badge(property_id, BadgeType::OwnerVerification),
+            Err(Error::NotVerifier)
+        );
+    }
+
+    #[ink::test]
+    fn update_badge_metadata_in_place() {
+        use crate::propchain_contracts::BadgeType;
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+
+        assert_eq!(
+            contract.update_badge_metadata(
+                property_id,
+                BadgeType::PremiumListing,
+                "https://metadata.example.com/new.json".to_string()
+            ),
+            Err(Error::BadgeNotFound)
+        );
+
         assert!(contract
-            .revoke_badge(
+            .issue_badge(
                 property_id,
-                BadgeType::DocumentVerification,
-                "Documents expired".to_string()
+                BadgeType::PremiumListing,
+                None,
+                "https://metadata.example.com/typo.json".to_string()
             )
             .is_ok());
-        set_caller(accounts.alice);
-        let appeal_id = contract
-            .submit_appeal(
+        let issued = contract
+            .get_badge(property_id, BadgeType::PremiumListing)
+            .expect("Badge should exist");
+
+        assert!(contract
+            .update_badge_metadata(
                 property_id,
-                BadgeType::DocumentVerification,
-                "Documents renewed".to_string(),
+                BadgeType::PremiumListing,
+                "https://metadata.example.com/badge.json".to_string()
             )
-            .expect("Failed to submit appeal");
-        assert_eq!(appeal_id, 1);
+            .is_ok());
+        let updated = contract
+            .get_badge(property_id, BadgeType::PremiumListing)
+            .expect("Badge should exist");
+        assert_eq!(
+            updated.metadata_url,
+            "https://metadata.example.com/badge.json"
+        );
+        assert_eq!(updated.issued_at, issued.issued_at);
+        assert_eq!(updated.issued_by, issued.issued_by);
+
+        // Revoked badges cannot be edited
         assert!(contract
-            .resolve_appeal(appeal_id, true, "Reinstating badge".to_string())
+            .revoke_badge(
+                property_id,
+                BadgeType::PremiumListing,
+                "Listing ended".to_string()
+            )
             .is_ok());
-        assert!(contract.has_badge(property_id, BadgeType::DocumentVerification));
+        assert_eq!(
+            contract.update_badge_metadata(
+                property_id,
+                BadgeType::PremiumListing,
+                "https://metadata.example.com/other.json".to_string()
+            ),
+            Err(Error::BadgeRevoked)
+        );
+    }
+
+    // Role Management Tests
+
+    #[ink::test]
+    fn verifier_manager_can_set_verifier_but_not_resolve_appeals() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+
+        assert!(!contract.has_role(accounts.bob, Role::VerifierManager));
+        assert!(contract
+            .grant_role(accounts.bob, Role::VerifierManager)
+            .is_ok());
+        assert!(contract.has_role(accounts.bob, Role::VerifierManager));
+        assert!(!contract.has_role(accounts.bob, Role::ComplianceManager));
+
+        set_caller(accounts.bob);
+        assert!(contract.set_verifier(accounts.charlie, true).is_ok());
+        assert!(contract.is_verifier(accounts.charlie));
+        assert_eq!(
+            contract.resolve_appeal(1, true, "Not allowed".to_string()),
+            Err(Error::Unauthorized)
+        );
+        assert_eq!(
+            contract.set_compliance_registry(Some(accounts.django)),
+            Err(Error::Unauthorized)
+        );
+    }
+
+    #[ink::test]
+    fn revoked_role_loses_access() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+
+        assert!(contract
+            .grant_role(accounts.bob, Role::VerifierManager)
+            .is_ok());
+        assert!(contract
+            .revoke_role(accounts.bob, Role::VerifierManager)
+            .is_ok());
+
+        set_caller(accounts.bob);
+        assert_eq!(
+            contract.set_verifier(accounts.charlie, true),
+            Err(Error::Unauthorized)
+        );
+    }
+
+    #[ink::test]
+    fn pauser_halts_registrations_transfers_and_escrows() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register");
+        let escrow_id = contract
+            .create_escrow(property_id, accounts.charlie, 1000)
+            .expect("Failed to create escrow");
+        fund_as_buyer(&mut contract, escrow_id);
+
+        set_caller(accounts.bob);
+        assert_eq!(contract.pause(), Err(Error::Unauthorized));
+
+        set_caller(accounts.alice);
+        assert!(contract.grant_role(accounts.bob, Role::Pauser).is_ok());
+        set_caller(accounts.bob);
+        assert_eq!(contract.pause(), Ok(()));
+        assert!(contract.is_paused());
+
+        set_caller(accounts.alice);
+        assert_eq!(
+            contract.register_property(create_sample_metadata()),
+            Err(Error::ContractPaused)
+        );
+        assert_eq!(
+            contract.batch_register_properties(vec![create_sample_metadata()]),
+            Err(Error::ContractPaused)
+        );
+        assert_eq!(
+            contract.transfer_property(property_id, accounts.django),
+            Err(Error::ContractPaused)
+        );
+        assert_eq!(
+            contract.create_escrow(property_id, accounts.django, 1000),
+            Err(Error::ContractPaused)
+        );
+        assert!(contract
+            .approve(property_id, Some(accounts.charlie))
+            .is_ok());
+        set_caller(accounts.charlie);
+        assert_eq!(
+            contract.release_escrow(escrow_id),
+            Err(Error::ContractPaused)
+        );
+
+        // Refunds stay available while paused
+        set_caller(accounts.alice);
+        assert!(contract.refund_escrow(escrow_id).is_ok());
+
+        set_caller(accounts.bob);
+        assert_eq!(contract.unpause(), Ok(()));
+        assert!(!contract.is_paused());
+        set_caller(accounts.alice);
+        assert!(contract
+            .transfer_property(property_id, accounts.django)
+            .is_ok());
+    }
+
+    #[ink::test]
+    fn only_admin_manages_roles() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+
+        // The admin holds every role implicitly
+        assert!(contract.has_role(accounts.alice, Role::Pauser));
+
+        set_caller(accounts.bob);
+        assert_eq!(
+            contract.grant_role(accounts.bob, Role::RegistrarManager),
+            Err(Error::Unauthorized)
+        );
+        assert_eq!(
+            contract.revoke_role(accounts.alice, Role::RegistrarManager),
+            Err(Error::Unauthorized)
+        );
+    }
+
+    #[ink::test]
+    fn property_status_follows_escrow_lifecycle() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+
+        let sold = contract
+            .register_property(create_sample_metadata())
+            .unwrap();
+        let fell_through = contract
+            .register_property(create_sample_metadata())
+            .unwrap();
+        assert_eq!(
+            contract.get_property_status(sold),
+            Some(PropertyStatus::Unlisted)
+        );
+        assert_eq!(contract.get_property_status(99), None);
+
+        // Only the owner lists a property
+        set_caller(accounts.bob);
+        assert_eq!(
+            contract.set_property_status(sold, PropertyStatus::Listed),
+            Err(Error::Unauthorized)
+        );
+        set_caller(accounts.alice);
+        contract
+            .set_property_status(sold, PropertyStatus::Listed)
+            .unwrap();
+        contract
+            .set_property_status(fell_through, PropertyStatus::Listed)
+            .unwrap();
+        assert_eq!(
+            contract.get_properties_by_status(PropertyStatus::Listed),
+            vec![sold, fell_through]
+        );
+
+        // Creating an escrow puts the property under contract
+        let escrow_id = contract.create_escrow(sold, accounts.bob, 1000).unwrap();
+        let refunded_escrow = contract
+            .create_escrow(fell_through, accounts.charlie, 1000)
+            .unwrap();
+        assert_eq!(
+            contract.get_property_status(sold),
+            Some(PropertyStatus::UnderContract)
+        );
+        assert_eq!(
+            contract.get_properties_by_status(PropertyStatus::UnderContract),
+            vec![sold, fell_through]
+        );
+
+        // Releasing marks it sold
+        fund_as_buyer(&mut contract, escrow_id);
+        set_caller(accounts.alice);
+        assert!(contract.approve(sold, Some(accounts.bob)).is_ok());
+        set_caller(accounts.bob);
+        contract.release_escrow(escrow_id).unwrap();
+        assert_eq!(
+            contract.get_property_status(sold),
+            Some(PropertyStatus::Sold)
+        );
+
+        // A refunded deal returns the property to the market
+        set_caller(accounts.alice);
+        contract.refund_escrow(refunded_escrow).unwrap();
+        assert_eq!(
+            contract.get_property_status(fell_through),
+            Some(PropertyStatus::Listed)
+        );
+
+        assert_eq!(
+            contract.get_properties_by_status(PropertyStatus::Sold),
+            vec![sold]
+        );
+        assert_eq!(
+            contract.get_properties_by_status(PropertyStatus::Listed),
+            vec![fell_through]
+        );
+        assert!(contract
+            .get_properties_by_status(PropertyStatus::UnderContract)
+            .is_empty());
+    }
+
+    #[ink::test]
+    fn operator_approved_for_all_transfers_whole_portfolio() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+
+        let ids: Vec<u64> = (0..4)
+            .map(|_| {
+                contract
+                    .register_property(create_sample_metadata())
+                    .unwrap()
+            })
+            .collect();
+
+        // Without approval the agent cannot move anything
+        set_caller(accounts.bob);
+        assert_eq!(
+            contract.transfer_property(ids[0], accounts.charlie),
+            Err(Error::Unauthorized)
+        );
+
+        // One blanket approval covers every property
+        set_caller(accounts.alice);
+        contract.set_approval_for_all(accounts.bob, true).unwrap();
+        assert!(contract.is_approved_for_all(accounts.alice, accounts.bob));
+        assert!(!contract.is_approved_for_all(accounts.bob, accounts.alice));
+
+        set_caller(accounts.bob);
+        contract
+            .transfer_property(ids[0], accounts.charlie)
+            .unwrap();
+        contract
+            .batch_transfer_properties(vec![ids[1], ids[2]], accounts.charlie)
+            .unwrap();
+        assert_eq!(
+            contract.get_owner_properties(accounts.charlie),
+            vec![ids[0], ids[1], ids[2]]
+        );
+
+        // The approval is per owner: charlie's new properties are out of reach
+        assert_eq!(
+            contract.batch_transfer_properties_to_multiple(vec![(ids[0], accounts.django)]),
+            Err(Error::Unauthorized)
+        );
+
+        // Revoking removes the operator's rights
+        set_caller(accounts.alice);
+        contract.set_approval_for_all(accounts.bob, false).unwrap();
+        assert!(!contract.is_approved_for_all(accounts.alice, accounts.bob));
+        set_caller(accounts.bob);
+        assert_eq!(
+            contract.batch_transfer_properties_to_multiple(vec![(ids[3], accounts.django)]),
+            Err(Error::Unauthorized)
+        );
+        assert_eq!(contract.get_property(ids[3]).unwrap().owner, accounts.alice);
+    }
+
+    #[ink::test]
+    fn emitted_events_carry_contract_event_version() {
+        use crate::propchain_contracts::{
+            ApprovalCleared, ApprovalForAll, ApprovalGranted, BadgeIssued, BadgeType,
+            BadgeTypeRegistered, EscrowCreated, EscrowReleased, PropertyRegistered,
+            PropertyTransferred, VerifierUpdated, EVENT_VERSION,
+        };
+        use ink::env::Event;
+
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        assert_eq!(contract.contract_event_version(), EVENT_VERSION);
+
+        // Exercise a spread of versioned events
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .unwrap();
+        let other_id = contract
+            .register_property(create_sample_metadata())
+            .unwrap();
+        contract
+            .transfer_property(other_id, accounts.charlie)
+            .unwrap();
+        contract
+            .approve(property_id, Some(accounts.django))
+            .unwrap();
+        contract.approve(property_id, None).unwrap();
+        contract.set_approval_for_all(accounts.eve, true).unwrap();
+        contract.set_verifier(accounts.bob, true).unwrap();
+        contract
+            .issue_badge(
+                property_id,
+                BadgeType::OwnerVerification,
+                None,
+                "ipfs://badge".to_string(),
+            )
+            .unwrap();
+        contract
+            .register_badge_type("EnergyRating".to_string())
+            .unwrap();
+        let escrow_id = contract
+            .create_escrow(property_id, accounts.bob, 1000)
+            .unwrap();
+        fund_as_buyer(&mut contract, escrow_id);
+        set_caller(accounts.alice);
+        contract.approve(property_id, Some(accounts.bob)).unwrap();
+        set_caller(accounts.bob);
+        contract.release_escrow(escrow_id).unwrap();
+
+        // Signature topic and the topic index of `event_version`: topics follow the
+        // signature in field declaration order, so index 0 is the signature itself
+        let versioned = [
+            (PropertyRegistered::SIGNATURE_TOPIC, 3),
+            (PropertyTransferred::SIGNATURE_TOPIC, 4),
+            (ApprovalGranted::SIGNATURE_TOPIC, 4),
+            (ApprovalCleared::SIGNATURE_TOPIC, 3),
+            (ApprovalForAll::SIGNATURE_TOPIC, 3),
+            (VerifierUpdated::SIGNATURE_TOPIC, 4),
+            (BadgeIssued::SIGNATURE_TOPIC, 4),
+            (BadgeTypeRegistered::SIGNATURE_TOPIC, 3),
+            (EscrowCreated::SIGNATURE_TOPIC, 5),
+            (EscrowReleased::SIGNATURE_TOPIC, 4),
+        ];
+
+        // Small values are used as topics directly, zero-padded to 32 bytes
+        let mut version_topic = [0u8; 32];
+        version_topic[0] = EVENT_VERSION;
+
+        let mut seen = Vec::new();
+        for event in ink::env::test::recorded_events() {
+            let signature = event.topics.first().map(|t| t.as_slice());
+            let Some(kind) = versioned
+                .iter()
+                .position(|(topic, _)| signature == topic.as_ref().map(|t| t.as_slice()))
+            else {
+                continue;
+            };
+            let version_index = versioned[kind].1;
+            assert_eq!(
+                event.topics.len(),
+                version_index + 1,
+                "event {} has an unexpected topic layout",
+                kind
+            );
+            assert_eq!(
+                event.topics[version_index].as_slice(),
+                version_topic,
+                "event {} carries a stale event_version",
+                kind
+            );
+            if !seen.contains(&kind) {
+                seen.push(kind);
+            }
+        }
+        assert_eq!(seen.len(), versioned.len());
     }
 }
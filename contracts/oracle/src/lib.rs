@@ -15,6 +15,18 @@ mod propchain_oracle {
     use super::*;
     use ink::prelude::{string::{String, ToString}, vec::Vec};
 
+    /// Largest location adjustment applied to a valuation, in either direction (percentage)
+    pub const MAX_LOCATION_ADJUSTMENT_PCT: i32 = 50;
+
+    /// Largest upward adjustment accepted on a comparable sale (percentage)
+    pub const MAX_COMPARABLE_ADJUSTMENT_PCT: i32 = 100;
+
+    /// Largest price per sqm accepted on a comparable sale
+    pub const MAX_COMPARABLE_PRICE_PER_SQM: u128 = 1_000_000_000_000;
+
+    /// Largest number of price alerts kept per property; every valuation update walks them
+    pub const MAX_PRICE_ALERTS_PER_PROPERTY: u32 = 20;
+
     /// Error types for the Property Valuation Oracle
     #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
     #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
@@ -27,6 +39,9 @@ mod propchain_oracle {
         InvalidParameters,
         PriceFeedError,
         AlertNotFound,
+        PriceJumpExceeded,
+        NoActiveSources,
+        AlertLimitReached,
     }
 
     /// Property Valuation Oracle storage
@@ -53,20 +68,47 @@ mod propchain_oracle {
         /// Location-based adjustments
         pub location_adjustments: Mapping<String, LocationAdjustment>,
 
-        /// Market trends data
-        market_trends: Mapping<String, MarketTrend>,
+        /// Location code per property, selecting its location adjustment
+        property_locations: Mapping<u64, String>,
+
+        /// Market trends per (property type, location)
+        market_trends: Mapping<(PropertyType, String), MarketTrend>,
+
+        /// Published volatility metrics per (property type, location)
+        market_volatility: Mapping<(PropertyType, String), VolatilityMetrics>,
 
         /// Comparable properties cache
         comparable_cache: Mapping<u64, Vec<ComparableProperty>>,
 
-        /// Maximum staleness for price feeds (in seconds)
-        max_price_staleness: u64,
+        /// Accounts allowed to submit comparable sales alongside the admin
+        appraisers: Mapping<AccountId, bool>,
+
+        /// Maximum age of a price feed entry before it is ignored (milliseconds)
+        max_price_age_ms: u64,
 
         /// Minimum sources required for valuation
         pub min_sources_required: u32,
 
         /// Outlier detection threshold (standard deviations)
         outlier_threshold: u32,
+
+        /// Property type per property, used for type-specific adjustments
+        property_types: Mapping<u64, PropertyType>,
+
+        /// Confidence multiplier per property type (percentage, 100 = unchanged)
+        confidence_multipliers: Mapping<PropertyType, u32>,
+
+        /// Latest price submitted by each source per property: (source_id, property_id)
+        source_prices: Mapping<(String, u64), PriceData>,
+
+        /// Maximum allowed deviation from a source's prior price (basis points, 0 = unlimited)
+        max_price_jump_bps: u32,
+
+        /// Maximum deviation from the median source price before a source counts as an outlier (percentage)
+        max_source_deviation_pct: u32,
+
+        /// Volatility index of the source prices behind each stored valuation
+        valuation_volatility: Mapping<u64, u32>,
     }
 
     /// Events emitted by the oracle
@@ -86,6 +128,7 @@ mod propchain_oracle {
         old_valuation: u128,
         new_valuation: u128,
         change_percentage: u32,
+        #[ink(topic)]
         alert_address: AccountId,
     }
 
@@ -109,11 +152,20 @@ mod propchain_oracle {
                 active_sources: Vec::new(),
                 price_alerts: Mapping::default(),
                 location_adjustments: Mapping::default(),
+                property_locations: Mapping::default(),
                 market_trends: Mapping::default(),
+                market_volatility: Mapping::default(),
                 comparable_cache: Mapping::default(),
-                max_price_staleness: 3600, // 1 hour
+                appraisers: Mapping::default(),
+                max_price_age_ms: 60 * 60 * 1000, // 1 hour
                 min_sources_required: 2,
                 outlier_threshold: 2, // 2 standard deviations
+                property_types: Mapping::default(),
+                confidence_multipliers: Mapping::default(),
+                source_prices: Mapping::default(),
+                max_price_jump_bps: 0,
+                max_source_deviation_pct: 20,
+                valuation_volatility: Mapping::default(),
             }
         }
 
@@ -124,29 +176,118 @@ mod propchain_oracle {
                 .ok_or(OracleError::PropertyNotFound)
         }
 
+        /// Get the current aggregate valuation for a property, if one has been computed
+        #[ink(message)]
+        pub fn get_valuation(&self, property_id: u64) -> Option<PropertyValuation> {
+            self.property_valuations.get(&property_id)
+        }
+
         /// Get property valuation with confidence metrics
+        /// When enough sources have reported, the valuation is computed from their prices the same way
+        /// `update_valuation_from_sources` computes the stored one: sources deviating from the median by
+        /// more than `max_source_deviation_pct` are excluded and counted as outliers, and the remaining
+        /// min/max prices form the confidence interval
         #[ink(message)]
-        pub fn get_valuation_with_confidence(&self, property_id: u64) -> Result<ValuationWithConfidence, OracleError> {
-            let valuation = self.get_property_valuation(property_id)?;
+        pub fn get_valuation_with_confidence(
+            &self,
+            property_id: u64,
+        ) -> Result<ValuationWithConfidence, OracleError> {
+            let prices = self.collect_prices_from_sources(property_id)?;
+            match self.valuation_from_source_prices(property_id, &prices) {
+                Ok(mut result) => {
+                    result.valuation.confidence_score = self.apply_confidence_multiplier(
+                        property_id,
+                        result.valuation.confidence_score,
+                    );
+                    return Ok(result);
+                }
+                Err(OracleError::InsufficientSources) => {}
+                Err(e) => return Err(e),
+            }
+
+            let mut valuation = self.get_property_valuation(property_id)?;
+
+            // Discount confidence for less liquid property types
+            valuation.confidence_score =
+                self.apply_confidence_multiplier(property_id, valuation.confidence_score);
 
-            // Calculate volatility and confidence interval
-            let volatility = self.calculate_volatility(property_id)?;
+            // Prefer the volatility recorded with the stored valuation
+            let volatility = match self.valuation_volatility.get(&property_id) {
+                Some(volatility) => volatility,
+                None => self.calculate_volatility(property_id)?,
+            };
             let confidence_interval = self.calculate_confidence_interval(&valuation)?;
-            let outlier_sources = self.detect_outliers(property_id)?;
 
             Ok(ValuationWithConfidence {
                 valuation,
                 volatility_index: volatility,
                 confidence_interval,
-                outlier_sources,
+                outlier_sources: 0,
             })
         }
 
         /// Update property valuation (admin only)
         #[ink(message)]
-        pub fn update_property_valuation(&mut self, property_id: u64, valuation: PropertyValuation) -> Result<(), OracleError> {
+        pub fn update_property_valuation(
+            &mut self,
+            property_id: u64,
+            valuation: PropertyValuation,
+        ) -> Result<(), OracleError> {
             self.ensure_admin()?;
+            self.apply_valuation(property_id, valuation)
+        }
+
+        /// Update property valuation from oracle sources (admin only)
+        #[ink(message)]
+        pub fn update_valuation_from_sources(
+            &mut self,
+            property_id: u64,
+        ) -> Result<(), OracleError> {
+            self.ensure_admin()?;
+            self.refresh_valuation_from_sources(property_id)
+        }
+
+        /// Push a price from a registered source (source address only) and refresh the
+        /// weighted valuation once at least `min_sources_required` sources have reported
+        #[ink(message)]
+        pub fn update_price(
+            &mut self,
+            source_id: String,
+            property_id: u64,
+            price_data: PriceData,
+        ) -> Result<(), OracleError> {
+            let price_data = PriceData {
+                source: source_id,
+                ..price_data
+            };
+            self.submit_price_data(property_id, price_data)?;
+
+            match self.refresh_valuation_from_sources(property_id) {
+                Ok(()) | Err(OracleError::InsufficientSources) => Ok(()),
+                Err(e) => Err(e),
+            }
+        }
+
+        fn refresh_valuation_from_sources(&mut self, property_id: u64) -> Result<(), OracleError> {
+            if self.active_sources.is_empty() {
+                return Err(OracleError::NoActiveSources);
+            }
 
+            // Collect prices from all active sources
+            let prices = self.collect_prices_from_sources(property_id)?;
+            let result = self.valuation_from_source_prices(property_id, &prices)?;
+
+            self.apply_valuation(property_id, result.valuation)?;
+            self.valuation_volatility
+                .insert(&property_id, &result.volatility_index);
+            Ok(())
+        }
+
+        fn apply_valuation(
+            &mut self,
+            property_id: u64,
+            valuation: PropertyValuation,
+        ) -> Result<(), OracleError> {
             // Validate valuation
             if valuation.valuation == 0 {
                 return Err(OracleError::InvalidValuation);
@@ -172,32 +313,6 @@ mod propchain_oracle {
             Ok(())
         }
 
-        /// Update property valuation from oracle sources
-        #[ink(message)]
-        pub fn update_valuation_from_sources(&mut self, property_id: u64) -> Result<(), OracleError> {
-            // Collect prices from all active sources
-            let prices = self.collect_prices_from_sources(property_id)?;
-
-            if prices.len() < self.min_sources_required as usize {
-                return Err(OracleError::InsufficientSources);
-            }
-
-            // Aggregate prices with outlier detection
-            let aggregated_price = self.aggregate_prices(&prices)?;
-            let confidence_score = self.calculate_confidence_score(&prices)?;
-
-            let valuation = PropertyValuation {
-                property_id,
-                valuation: aggregated_price,
-                confidence_score,
-                sources_used: prices.len() as u32,
-                last_updated: self.env().block_timestamp(),
-                valuation_method: ValuationMethod::MarketData,
-            };
-
-            self.update_property_valuation(property_id, valuation)
-        }
-
         /// Get historical valuations for a property
         #[ink(message)]
         pub fn get_historical_valuations(&self, property_id: u64, limit: u32) -> Vec<PropertyValuation> {
@@ -210,10 +325,19 @@ mod propchain_oracle {
         }
 
         /// Get market volatility metrics
+        /// Returns the published metrics if any, otherwise estimates them from the market trend
         #[ink(message)]
-        pub fn get_market_volatility(&self, property_type: PropertyType, location: String) -> Result<VolatilityMetrics, OracleError> {
-            let key = format!("{:?}_{}", property_type, location);
-            self.market_trends.get(&key)
+        pub fn get_market_volatility(
+            &self,
+            property_type: PropertyType,
+            location: String,
+        ) -> Result<VolatilityMetrics, OracleError> {
+            if let Some(metrics) = self.get_volatility(property_type.clone(), location.clone()) {
+                return Ok(metrics);
+            }
+
+            self.market_trends
+                .get(&(property_type, location))
                 .map(|trend| VolatilityMetrics {
                     property_type: trend.property_type,
                     location: trend.location,
@@ -225,20 +349,41 @@ mod propchain_oracle {
                 .ok_or(OracleError::InvalidParameters)
         }
 
-        /// Set price alert for a property
+        /// Set price alert for a property (admin only)
         #[ink(message)]
-        pub fn set_price_alert(&mut self, property_id: u64, threshold_percentage: u32, alert_address: AccountId) -> Result<(), OracleError> {
-            let alert = PriceAlert {
+        pub fn set_price_alert(
+            &mut self,
+            property_id: u64,
+            threshold_percentage: u32,
+            alert_address: AccountId,
+        ) -> Result<(), OracleError> {
+            self.register_price_alert(PriceAlert {
                 property_id,
                 threshold_percentage,
                 alert_address,
                 last_triggered: 0,
                 is_active: true,
-            };
+            })
+        }
 
-            let mut alerts = self.price_alerts.get(&property_id).unwrap_or_default();
-            alerts.push(alert);
-            self.price_alerts.insert(&property_id, &alerts);
+        /// Register a price alert fired when a valuation update moves by at least `threshold_percentage`
+        /// (admin only). At most `MAX_PRICE_ALERTS_PER_PROPERTY` alerts are kept per property.
+        #[ink(message)]
+        pub fn register_price_alert(&mut self, alert: PriceAlert) -> Result<(), OracleError> {
+            self.ensure_admin()?;
+            if alert.threshold_percentage == 0 {
+                return Err(OracleError::InvalidParameters);
+            }
+
+            let mut alerts = self
+                .price_alerts
+                .get(&alert.property_id)
+                .unwrap_or_default();
+            if alerts.len() >= MAX_PRICE_ALERTS_PER_PROPERTY as usize {
+                return Err(OracleError::AlertLimitReached);
+            }
+            alerts.push(alert.clone());
+            self.price_alerts.insert(&alert.property_id, &alerts);
 
             Ok(())
         }
@@ -253,10 +398,7 @@ mod propchain_oracle {
             }
 
             self.oracle_sources.insert(&source.id, &source);
-
-            if source.is_active && !self.active_sources.contains(&source.id) {
-                self.active_sources.push(source.id.clone());
-            }
+            self.sync_active_source(&source.id, source.is_active);
 
             self.env().emit_event(OracleSourceAdded {
                 source_id: source.id,
@@ -267,7 +409,47 @@ mod propchain_oracle {
             Ok(())
         }
 
+        /// Activate or deactivate an oracle source (admin only)
+        /// Inactive sources are left out of aggregation and the remaining weights renormalized
+        #[ink(message)]
+        pub fn set_oracle_active(
+            &mut self,
+            source_id: String,
+            active: bool,
+        ) -> Result<(), OracleError> {
+            self.ensure_admin()?;
+            let mut source = self
+                .oracle_sources
+                .get(&source_id)
+                .ok_or(OracleError::OracleSourceNotFound)?;
+
+            source.is_active = active;
+            self.oracle_sources.insert(&source_id, &source);
+            self.sync_active_source(&source_id, active);
+            Ok(())
+        }
+
+        /// Remove an oracle source (admin only)
+        #[ink(message)]
+        pub fn remove_oracle_source(&mut self, source_id: String) -> Result<(), OracleError> {
+            self.ensure_admin()?;
+            if !self.oracle_sources.contains(&source_id) {
+                return Err(OracleError::OracleSourceNotFound);
+            }
+
+            self.oracle_sources.remove(&source_id);
+            self.sync_active_source(&source_id, false);
+            Ok(())
+        }
+
+        /// Get an oracle source's configuration
+        #[ink(message)]
+        pub fn get_oracle_source(&self, source_id: String) -> Option<OracleSource> {
+            self.oracle_sources.get(&source_id)
+        }
+
         /// Set location adjustment factor (admin only)
+        /// Valuations apply at most `MAX_LOCATION_ADJUSTMENT_PCT` in either direction
         #[ink(message)]
         pub fn set_location_adjustment(&mut self, adjustment: LocationAdjustment) -> Result<(), OracleError> {
             self.ensure_admin()?;
@@ -275,15 +457,228 @@ mod propchain_oracle {
             Ok(())
         }
 
-        /// Update market trend data (admin only)
+        /// Get the adjustment configured for a location code
+        #[ink(message)]
+        pub fn get_location_adjustment(&self, location_code: String) -> Option<LocationAdjustment> {
+            self.location_adjustments.get(&location_code)
+        }
+
+        /// Set the location code whose adjustment applies to a property's valuations (admin only)
+        #[ink(message)]
+        pub fn set_property_location(
+            &mut self,
+            property_id: u64,
+            location_code: String,
+        ) -> Result<(), OracleError> {
+            self.ensure_admin()?;
+            self.property_locations.insert(&property_id, &location_code);
+            Ok(())
+        }
+
+        /// Get the location code assigned to a property
+        #[ink(message)]
+        pub fn get_property_location(&self, property_id: u64) -> Option<String> {
+            self.property_locations.get(&property_id)
+        }
+
+        /// Update market trend data (admin only), same as `set_market_trend`
         #[ink(message)]
         pub fn update_market_trend(&mut self, trend: MarketTrend) -> Result<(), OracleError> {
+            self.set_market_trend(trend)
+        }
+
+        /// Set the market trend for the trend's property type and location (admin only)
+        #[ink(message)]
+        pub fn set_market_trend(&mut self, trend: MarketTrend) -> Result<(), OracleError> {
             self.ensure_admin()?;
-            let key = format!("{:?}_{}", trend.property_type, trend.location);
+            let key = (trend.property_type.clone(), trend.location.clone());
             self.market_trends.insert(&key, &trend);
             Ok(())
         }
 
+        /// Get the market trend for a property type in a location
+        #[ink(message)]
+        pub fn get_market_trend(
+            &self,
+            property_type: PropertyType,
+            location: String,
+        ) -> Option<MarketTrend> {
+            self.market_trends.get(&(property_type, location))
+        }
+
+        /// Publish volatility metrics for a property type in a location (admin only)
+        #[ink(message)]
+        pub fn set_volatility(&mut self, metrics: VolatilityMetrics) -> Result<(), OracleError> {
+            self.ensure_admin()?;
+
+            if metrics.volatility_index > 100 {
+                return Err(OracleError::InvalidParameters);
+            }
+
+            let key = (metrics.property_type.clone(), metrics.location.clone());
+            self.market_volatility.insert(&key, &metrics);
+            Ok(())
+        }
+
+        /// Get the volatility metrics published for a property type in a location
+        #[ink(message)]
+        pub fn get_volatility(
+            &self,
+            property_type: PropertyType,
+            location: String,
+        ) -> Option<VolatilityMetrics> {
+            self.market_volatility.get(&(property_type, location))
+        }
+
+        /// Set the property type used for type-specific adjustments (admin only)
+        #[ink(message)]
+        pub fn set_property_type(
+            &mut self,
+            property_id: u64,
+            property_type: PropertyType,
+        ) -> Result<(), OracleError> {
+            self.ensure_admin()?;
+            self.property_types.insert(&property_id, &property_type);
+            Ok(())
+        }
+
+        /// Set the confidence multiplier for a property type (admin only)
+        #[ink(message)]
+        pub fn set_confidence_multiplier(
+            &mut self,
+            property_type: PropertyType,
+            multiplier: u32,
+        ) -> Result<(), OracleError> {
+            self.ensure_admin()?;
+
+            if multiplier > 100 {
+                return Err(OracleError::InvalidParameters);
+            }
+
+            self.confidence_multipliers
+                .insert(&property_type, &multiplier);
+            Ok(())
+        }
+
+        /// Get the confidence multiplier for a property type
+        #[ink(message)]
+        pub fn get_confidence_multiplier(&self, property_type: PropertyType) -> u32 {
+            self.confidence_multipliers
+                .get(&property_type)
+                .unwrap_or(100)
+        }
+
+        /// Submit a price for a property from a registered source (source address only)
+        /// Rejects prices deviating from the source's prior price by more than `max_price_jump_bps`
+        #[ink(message)]
+        pub fn submit_price_data(
+            &mut self,
+            property_id: u64,
+            price_data: PriceData,
+        ) -> Result<(), OracleError> {
+            let source = self
+                .oracle_sources
+                .get(&price_data.source)
+                .ok_or(OracleError::OracleSourceNotFound)?;
+
+            if self.env().caller() != source.address {
+                return Err(OracleError::Unauthorized);
+            }
+
+            if !source.is_active {
+                return Err(OracleError::PriceFeedError);
+            }
+
+            let key = (price_data.source.clone(), property_id);
+            if let Some(prior) = self.source_prices.get(&key) {
+                if self.exceeds_price_jump(prior.price, price_data.price) {
+                    return Err(OracleError::PriceJumpExceeded);
+                }
+            }
+
+            self.source_prices.insert(&key, &price_data);
+            Ok(())
+        }
+
+        /// Accept a source price bypassing the price-jump check (admin only)
+        #[ink(message)]
+        pub fn override_price_data(
+            &mut self,
+            property_id: u64,
+            price_data: PriceData,
+        ) -> Result<(), OracleError> {
+            self.ensure_admin()?;
+
+            if self.oracle_sources.get(&price_data.source).is_none() {
+                return Err(OracleError::OracleSourceNotFound);
+            }
+
+            self.source_prices
+                .insert(&(price_data.source.clone(), property_id), &price_data);
+            Ok(())
+        }
+
+        /// Get the latest price submitted by a source for a property
+        #[ink(message)]
+        pub fn get_source_price(&self, source_id: String, property_id: u64) -> Option<PriceData> {
+            self.source_prices.get(&(source_id, property_id))
+        }
+
+        /// Set how old a source price may be before it is left out of valuations (admin only)
+        #[ink(message)]
+        pub fn set_max_price_age_ms(&mut self, max_age_ms: u64) -> Result<(), OracleError> {
+            self.ensure_admin()?;
+
+            if max_age_ms == 0 {
+                return Err(OracleError::InvalidParameters);
+            }
+
+            self.max_price_age_ms = max_age_ms;
+            Ok(())
+        }
+
+        /// Get the maximum age of a source price in milliseconds
+        #[ink(message)]
+        pub fn get_max_price_age_ms(&self) -> u64 {
+            self.max_price_age_ms
+        }
+
+        /// Set the maximum price jump between consecutive source prices (admin only)
+        #[ink(message)]
+        pub fn set_max_price_jump_bps(&mut self, max_jump_bps: u32) -> Result<(), OracleError> {
+            self.ensure_admin()?;
+            self.max_price_jump_bps = max_jump_bps;
+            Ok(())
+        }
+
+        /// Get the maximum price jump in basis points (0 = unlimited)
+        #[ink(message)]
+        pub fn get_max_price_jump_bps(&self) -> u32 {
+            self.max_price_jump_bps
+        }
+
+        /// Set the maximum deviation from the median before a source is treated as an outlier (admin only)
+        #[ink(message)]
+        pub fn set_max_source_deviation_pct(
+            &mut self,
+            max_deviation_pct: u32,
+        ) -> Result<(), OracleError> {
+            self.ensure_admin()?;
+
+            if max_deviation_pct == 0 {
+                return Err(OracleError::InvalidParameters);
+            }
+
+            self.max_source_deviation_pct = max_deviation_pct;
+            Ok(())
+        }
+
+        /// Get the maximum deviation from the median source price (percentage)
+        #[ink(message)]
+        pub fn get_max_source_deviation_pct(&self) -> u32 {
+            self.max_source_deviation_pct
+        }
+
         /// Get comparable properties for AVM analysis
         #[ink(message)]
         pub fn get_comparable_properties(&self, property_id: u64, radius_km: u32) -> Vec<ComparableProperty> {
@@ -294,8 +689,130 @@ mod propchain_oracle {
                 .collect()
         }
 
+        /// Authorize or deauthorize an appraiser (admin only)
+        #[ink(message)]
+        pub fn set_appraiser(
+            &mut self,
+            appraiser: AccountId,
+            authorized: bool,
+        ) -> Result<(), OracleError> {
+            self.ensure_admin()?;
+            if authorized {
+                self.appraisers.insert(&appraiser, &true);
+            } else {
+                self.appraisers.remove(&appraiser);
+            }
+            Ok(())
+        }
+
+        /// Check whether an account is an authorized appraiser
+        #[ink(message)]
+        pub fn is_appraiser(&self, account: AccountId) -> bool {
+            self.appraisers.get(&account).unwrap_or(false)
+        }
+
+        /// Replace the comparable sales recorded for a property (admin or appraiser)
+        #[ink(message)]
+        pub fn submit_comparables(
+            &mut self,
+            property_id: u64,
+            comparables: Vec<ComparableProperty>,
+        ) -> Result<(), OracleError> {
+            let caller = self.env().caller();
+            if caller != self.admin && !self.is_appraiser(caller) {
+                return Err(OracleError::Unauthorized);
+            }
+
+            // An adjustment of -100% or less would price the comparable at nothing
+            if comparables.is_empty()
+                || comparables.iter().any(|comp| {
+                    comp.price_per_sqm == 0
+                        || comp.price_per_sqm > MAX_COMPARABLE_PRICE_PER_SQM
+                        || comp.adjustment_factor <= -100
+                        || comp.adjustment_factor > MAX_COMPARABLE_ADJUSTMENT_PCT
+                })
+            {
+                return Err(OracleError::InvalidParameters);
+            }
+
+            self.comparable_cache.insert(&property_id, &comparables);
+            Ok(())
+        }
+
+        /// Estimate a property's value from its comparable sales
+        /// Each comparable's price per sqm is adjusted by its `adjustment_factor`, the adjusted
+        /// prices are averaged weighted by inverse distance, and the result is scaled by the
+        /// subject's size. Confidence reflects how many comparables there are and how closely they agree.
+        #[ink(message)]
+        pub fn estimate_from_comparables(
+            &self,
+            property_id: u64,
+            subject_size_sqm: u64,
+        ) -> Result<PropertyValuation, OracleError> {
+            if subject_size_sqm == 0 {
+                return Err(OracleError::InvalidParameters);
+            }
+
+            let comparables = self.comparable_cache.get(&property_id).unwrap_or_default();
+            if comparables.is_empty() {
+                return Err(OracleError::InsufficientSources);
+            }
+
+            let mut adjusted_prices = Vec::with_capacity(comparables.len());
+            let mut total_weighted_price = 0u128;
+            let mut total_weight = 0u128;
+            for comp in &comparables {
+                let factor = 100i64 + comp.adjustment_factor as i64;
+                if factor <= 0 {
+                    return Err(OracleError::InvalidParameters);
+                }
+                let adjusted = comp
+                    .price_per_sqm
+                    .checked_mul(factor as u128)
+                    .ok_or(OracleError::InvalidParameters)?
+                    / 100;
+                // Inverse-distance weight; a comparable at 0 km gets the full weight and
+                // distant ones keep a minimal weight so the total never reaches zero
+                let weight = (1_000 / (comp.distance_km as u128 + 1)).max(1);
+                total_weighted_price = adjusted
+                    .checked_mul(weight)
+                    .and_then(|weighted| total_weighted_price.checked_add(weighted))
+                    .ok_or(OracleError::InvalidParameters)?;
+                total_weight += weight;
+                adjusted_prices.push(PriceData {
+                    price: adjusted,
+                    timestamp: comp.sale_date,
+                    source: String::new(),
+                });
+            }
+
+            let price_per_sqm = total_weighted_price / total_weight;
+            let value = price_per_sqm
+                .checked_mul(subject_size_sqm as u128)
+                .ok_or(OracleError::InvalidParameters)?;
+            let confidence_score = self.calculate_confidence_score(&adjusted_prices)?;
+
+            Ok(PropertyValuation {
+                property_id,
+                valuation: self.apply_location_adjustment(property_id, value),
+                confidence_score: self.apply_confidence_multiplier(property_id, confidence_score),
+                sources_used: comparables.len() as u32,
+                last_updated: self.env().block_timestamp(),
+                valuation_method: ValuationMethod::MarketData,
+            })
+        }
+
         // Helper methods
 
+        fn sync_active_source(&mut self, source_id: &str, active: bool) {
+            let listed = self.active_sources.iter().any(|id| id == source_id);
+            if active && !listed {
+                self.active_sources.push(source_id.to_string());
+            } else if !active && listed {
+                self.active_sources.retain(|id| id != source_id);
+            }
+        }
+
         fn ensure_admin(&self) -> Result<(), OracleError> {
             if self.env().caller() != self.admin {
                 return Err(OracleError::Unauthorized);
@@ -303,11 +820,18 @@ mod propchain_oracle {
             Ok(())
         }
 
-        fn collect_prices_from_sources(&self, property_id: u64) -> Result<Vec<PriceData>, OracleError> {
+        fn collect_prices_from_sources(
+            &self,
+            property_id: u64,
+        ) -> Result<Vec<PriceData>, OracleError> {
             let mut prices = Vec::new();
 
             for source_id in &self.active_sources {
-                if let Some(source) = self.oracle_sources.get(source_id) {
+                if let Some(source) = self
+                    .oracle_sources
+                    .get(source_id)
+                    .filter(|source| source.is_active)
+                {
                     // In a real implementation, this would call external price feeds
                     // For now, we'll simulate price collection
                     match self.get_price_from_source(&source, property_id) {
@@ -324,7 +848,16 @@ mod propchain_oracle {
             Ok(prices)
         }
 
-        fn get_price_from_source(&self, source: &OracleSource, _property_id: u64) -> Result<PriceData, OracleError> {
+        fn get_price_from_source(
+            &self,
+            source: &OracleSource,
+            property_id: u64,
+        ) -> Result<PriceData, OracleError> {
+            // Prices pushed through `submit_price_data` take precedence
+            if let Some(price_data) = self.source_prices.get(&(source.id.clone(), property_id)) {
+                return Ok(price_data);
+            }
+
             // This is a placeholder for actual price feed integration
             // In production, this would call Chainlink, Pyth, or other oracles
             match source.source_type {
@@ -347,33 +880,65 @@ mod propchain_oracle {
             }
         }
 
+        fn exceeds_price_jump(&self, old_price: u128, new_price: u128) -> bool {
+            if self.max_price_jump_bps == 0 || old_price == 0 {
+                return false;
+            }
+
+            let diff = old_price.abs_diff(new_price);
+            diff.saturating_mul(10000) / old_price > self.max_price_jump_bps as u128
+        }
+
         fn is_price_fresh(&self, price_data: &PriceData) -> bool {
             let current_time = self.env().block_timestamp();
-            current_time.saturating_sub(price_data.timestamp) <= self.max_price_staleness
+            current_time.saturating_sub(price_data.timestamp) <= self.max_price_age_ms
         }
 
         pub fn aggregate_prices(&self, prices: &[PriceData]) -> Result<u128, OracleError> {
-            if prices.is_empty() {
+            // Stale prices must not steer the valuation
+            let fresh_prices: Vec<PriceData> = prices
+                .iter()
+                .filter(|price_data| self.is_price_fresh(price_data))
+                .cloned()
+                .collect();
+
+            if fresh_prices.is_empty() || fresh_prices.len() < self.min_sources_required as usize {
                 return Err(OracleError::InsufficientSources);
             }
 
             // Remove outliers
-            let filtered_prices = self.filter_outliers(prices);
+            let (filtered_prices, _) = self.partition_outliers(&fresh_prices);
 
             if filtered_prices.is_empty() {
                 return Err(OracleError::InsufficientSources);
             }
 
-            // Weighted average based on source weights
+            self.weighted_price(&filtered_prices)
+        }
+
+        /// Weighted average over active sources; dividing by their total weight
+        /// renormalizes the weights when some sources are excluded
+        fn weighted_price(&self, prices: &[PriceData]) -> Result<u128, OracleError> {
             let mut total_weighted_price = 0u128;
             let mut total_weight = 0u32;
-
-            for price_data in &filtered_prices {
-                let weight = self.get_source_weight(&price_data.source)?;
-                total_weighted_price += price_data.price * weight as u128;
-                total_weight += weight;
+            let mut active_prices = 0u32;
+
+            for price_data in prices {
+                let Some(source) = self
+                    .oracle_sources
+                    .get(&price_data.source)
+                    .filter(|source| source.is_active)
+                else {
+                    continue;
+                };
+                total_weighted_price += price_data.price * source.weight as u128;
+                total_weight += source.weight;
+                active_prices += 1;
             }
 
+            if active_prices == 0 {
+                return Err(OracleError::NoActiveSources);
+            }
             if total_weight == 0 {
                 return Err(OracleError::InvalidParameters);
             }
@@ -417,12 +982,6 @@ mod propchain_oracle {
                 .collect()
         }
 
-        fn get_source_weight(&self, source_id: &str) -> Result<u32, OracleError> {
-            self.oracle_sources.get(&source_id.to_string())
-                .map(|source| source.weight)
-                .ok_or(OracleError::OracleSourceNotFound)
-        }
-
         pub fn calculate_confidence_score(&self, prices: &[PriceData]) -> Result<u32, OracleError> {
             if prices.is_empty() {
                 return Ok(0);
@@ -473,6 +1032,32 @@ mod propchain_oracle {
             Ok(source_confidence + variance_confidence)
         }
 
+        fn apply_confidence_multiplier(&self, property_id: u64, confidence_score: u32) -> u32 {
+            let multiplier = self
+                .property_types
+                .get(&property_id)
+                .map(|property_type| self.get_confidence_multiplier(property_type))
+                .unwrap_or(100);
+
+            (confidence_score.min(100) * multiplier) / 100
+        }
+
+        /// Scale a value by the property's location adjustment, capped at `MAX_LOCATION_ADJUSTMENT_PCT`
+        fn apply_location_adjustment(&self, property_id: u64, value: u128) -> u128 {
+            let percentage = self
+                .property_locations
+                .get(&property_id)
+                .and_then(|location_code| self.location_adjustments.get(&location_code))
+                .map(|adjustment| {
+                    adjustment
+                        .adjustment_percentage
+                        .clamp(-MAX_LOCATION_ADJUSTMENT_PCT, MAX_LOCATION_ADJUSTMENT_PCT)
+                })
+                .unwrap_or(0);
+
+            value.saturating_mul((100 + percentage) as u128) / 100
+        }
+
         fn calculate_volatility(&self, property_id: u64) -> Result<u32, OracleError> {
             let historical = self.get_historical_valuations(property_id, 30); // Last 30 valuations
 
@@ -512,10 +1097,92 @@ mod propchain_oracle {
             ))
         }
 
-        fn detect_outliers(&self, _property_id: u64) -> Result<u32, OracleError> {
-            // This would implement outlier detection logic
-            // For now, return 0
-            Ok(0)
+        /// Split prices into those within `max_source_deviation_pct` of the median and the outliers.
+        /// With fewer than three prices there is no majority to measure against, so all are kept.
+        fn partition_outliers(&self, prices: &[PriceData]) -> (Vec<PriceData>, Vec<PriceData>) {
+            if prices.len() < 3 {
+                return (prices.to_vec(), Vec::new());
+            }
+
+            let median = Self::median_price(prices);
+            prices
+                .iter()
+                .cloned()
+                .partition(|p| !self.deviates_from_median(p.price, median))
+        }
+
+        /// Valuation, confidence interval and volatility computed from one filtered set of source prices.
+        /// The confidence score is not yet discounted by the property type multiplier.
+        fn valuation_from_source_prices(
+            &self,
+            property_id: u64,
+            prices: &[PriceData],
+        ) -> Result<ValuationWithConfidence, OracleError> {
+            let fresh_prices: Vec<PriceData> = prices
+                .iter()
+                .filter(|price_data| self.is_price_fresh(price_data))
+                .cloned()
+                .collect();
+            if fresh_prices.is_empty() || fresh_prices.len() < self.min_sources_required as usize {
+                return Err(OracleError::InsufficientSources);
+            }
+
+            let median = Self::median_price(&fresh_prices);
+            let (inliers, outliers) = self.partition_outliers(&fresh_prices);
+            if inliers.is_empty() {
+                return Err(OracleError::InsufficientSources);
+            }
+
+            let confidence_score = self.calculate_confidence_score(&inliers)?;
+            let valuation = PropertyValuation {
+                property_id,
+                valuation: self
+                    .apply_location_adjustment(property_id, self.weighted_price(&inliers)?),
+                confidence_score,
+                sources_used: inliers.len() as u32,
+                last_updated: self.env().block_timestamp(),
+                valuation_method: ValuationMethod::MarketData,
+            };
+
+            let min = inliers.iter().map(|p| p.price).min().unwrap_or_default();
+            let max = inliers.iter().map(|p| p.price).max().unwrap_or_default();
+
+            // Spread of the accepted prices relative to the median, as a percentage
+            let volatility_index = if median > 0 {
+                ((max - min) * 100 / median).min(100) as u32
+            } else {
+                0
+            };
+
+            Ok(ValuationWithConfidence {
+                valuation,
+                volatility_index,
+                confidence_interval: (
+                    self.apply_location_adjustment(property_id, min),
+                    self.apply_location_adjustment(property_id, max),
+                ),
+                outlier_sources: outliers.len() as u32,
+            })
+        }
+
+        fn median_price(prices: &[PriceData]) -> u128 {
+            let mut sorted: Vec<u128> = prices.iter().map(|p| p.price).collect();
+            sorted.sort_unstable();
+
+            if sorted.is_empty() {
+                return 0;
+            }
+
+            // Same index for odd lengths, the two middle prices for even lengths
+            let len = sorted.len();
+            (sorted[(len - 1) / 2] + sorted[len / 2]) / 2
+        }
+
+        fn deviates_from_median(&self, price: u128, median: u128) -> bool {
+            if median == 0 {
+                return false;
+            }
+            price.abs_diff(median) * 100 / median > self.max_source_deviation_pct as u128
         }
 
         fn store_historical_valuation(&mut self, property_id: u64, valuation: PropertyValuation) {
@@ -535,9 +1202,22 @@ mod propchain_oracle {
             if let Some(last_valuation) = self.property_valuations.get(&property_id) {
                 let change_percentage = self.calculate_percentage_change(last_valuation.valuation, new_valuation);
 
-                if let Some(alerts) = self.price_alerts.get(&property_id) {
-                    for alert in alerts {
-                        if alert.is_active && change_percentage >= alert.threshold_percentage as u128 {
+                if let Some(mut alerts) = self.price_alerts.get(&property_id) {
+                    let now = self.env().block_timestamp();
+                    let mut triggered = false;
+
+                    for alert in alerts.iter_mut() {
+                        // Don't re-trigger an alert within the same block
+                        let fired_this_block =
+                            alert.last_triggered != 0 && alert.last_triggered == now;
+
+                        if alert.is_active
+                            && !fired_this_block
+                            && change_percentage >= alert.threshold_percentage as u128
+                        {
+                            alert.last_triggered = now;
+                            triggered = true;
+
                             self.env().emit_event(PriceAlertTriggered {
                                 property_id,
                                 old_valuation: last_valuation.valuation,
@@ -547,6 +1227,10 @@ mod propchain_oracle {
                             });
                         }
                     }
+
+                    if triggered {
+                        self.price_alerts.insert(&property_id, &alerts);
+                    }
                 }
             }
             Ok(())
@@ -674,6 +1358,101 @@ mod oracle_tests {
         assert_eq!(alerts.len(), 1);
         assert_eq!(alerts[0].threshold_percentage, 5);
         assert_eq!(alerts[0].alert_address, accounts.bob);
+
+        // Only the admin registers alerts
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(
+            oracle.set_price_alert(1, 5, accounts.bob),
+            Err(OracleError::Unauthorized)
+        );
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+
+        // The per-property list is bounded
+        use crate::propchain_oracle::MAX_PRICE_ALERTS_PER_PROPERTY;
+        for _ in 1..MAX_PRICE_ALERTS_PER_PROPERTY {
+            assert!(oracle.set_price_alert(1, 5, accounts.bob).is_ok());
+        }
+        assert_eq!(
+            oracle.set_price_alert(1, 5, accounts.bob),
+            Err(OracleError::AlertLimitReached)
+        );
+        assert_eq!(
+            oracle.price_alerts.get(&1).unwrap().len(),
+            MAX_PRICE_ALERTS_PER_PROPERTY as usize
+        );
+        assert!(oracle.set_price_alert(2, 5, accounts.bob).is_ok());
+    }
+
+    #[ink::test]
+    fn test_price_alert_triggers_on_threshold() {
+        let mut oracle = setup_oracle();
+        let accounts = DefaultAccounts::default();
+
+        let valuation = |value| PropertyValuation {
+            property_id: 1,
+            valuation: value,
+            confidence_score: 80,
+            sources_used: 2,
+            last_updated: 0,
+            valuation_method: ValuationMethod::MarketData,
+        };
+        let alert = |threshold_percentage, alert_address| PriceAlert {
+            property_id: 1,
+            threshold_percentage,
+            alert_address,
+            last_triggered: 0,
+            is_active: true,
+        };
+        let alert_events = || {
+            let signature =
+                <crate::propchain_oracle::PriceAlertTriggered as ink::env::Event>::SIGNATURE_TOPIC
+                    .unwrap();
+            test::recorded_events()
+                .filter(|event| event.topics.first().map(|t| t.as_slice()) == Some(&signature[..]))
+                .count()
+        };
+
+        assert_eq!(
+            oracle.register_price_alert(alert(0, accounts.bob)),
+            Err(OracleError::InvalidParameters)
+        );
+        assert!(oracle.register_price_alert(alert(5, accounts.bob)).is_ok());
+        let mut inactive = alert(1, accounts.charlie);
+        inactive.is_active = false;
+        assert!(oracle.register_price_alert(inactive).is_ok());
+
+        test::set_block_timestamp::<DefaultEnvironment>(1000);
+        assert!(oracle
+            .update_property_valuation(1, valuation(100000))
+            .is_ok());
+
+        // 2% move stays under the 5% threshold
+        assert!(oracle
+            .update_property_valuation(1, valuation(102000))
+            .is_ok());
+        assert_eq!(alert_events(), 0);
+
+        // ~10% move fires the active alert only
+        assert!(oracle
+            .update_property_valuation(1, valuation(112200))
+            .is_ok());
+        assert_eq!(alert_events(), 1);
+        let alerts = oracle.price_alerts.get(&1).unwrap();
+        assert_eq!(alerts[0].last_triggered, 1000);
+        assert_eq!(alerts[1].last_triggered, 0);
+
+        // Another large move in the same block doesn't re-trigger
+        assert!(oracle
+            .update_property_valuation(1, valuation(150000))
+            .is_ok());
+        assert_eq!(alert_events(), 1);
+
+        test::set_block_timestamp::<DefaultEnvironment>(2000);
+        assert!(oracle
+            .update_property_valuation(1, valuation(100000))
+            .is_ok());
+        assert_eq!(alert_events(), 2);
+        assert_eq!(oracle.price_alerts.get(&1).unwrap()[0].last_triggered, 2000);
     }
 
     #[ink::test]
@@ -695,7 +1474,21 @@ mod oracle_tests {
 
     #[ink::test]
     fn test_aggregate_prices_works() {
-        let oracle = setup_oracle();
+        let mut oracle = setup_oracle();
+        let accounts = DefaultAccounts::default();
+
+        for id in ["source1", "source2", "source3"] {
+            assert!(oracle
+                .add_oracle_source(OracleSource {
+                    id: id.to_string(),
+                    source_type: OracleSourceType::Custom,
+                    address: accounts.bob,
+                    is_active: true,
+                    weight: 10,
+                    last_updated: 0,
+                })
+                .is_ok());
+        }
 
         let prices = vec![
             PriceData {
@@ -799,6 +1592,552 @@ mod oracle_tests {
         assert_eq!(stored.unwrap(), adjustment);
     }
 
+    #[ink::test]
+    fn test_location_adjustment_moves_valuation() {
+        let mut oracle = setup_oracle();
+        let accounts = DefaultAccounts::default();
+
+        let source = |id: &str, address| OracleSource {
+            id: id.to_string(),
+            source_type: OracleSourceType::Custom,
+            address,
+            is_active: true,
+            weight: 50,
+            last_updated: 0,
+        };
+        assert!(oracle
+            .add_oracle_source(source("feed_a", accounts.bob))
+            .is_ok());
+        assert!(oracle
+            .add_oracle_source(source("feed_b", accounts.charlie))
+            .is_ok());
+
+        let adjustment = |location_code: &str, adjustment_percentage| LocationAdjustment {
+            location_code: location_code.to_string(),
+            adjustment_percentage,
+            last_updated: 0,
+            confidence_score: 90,
+        };
+        assert!(oracle
+            .set_location_adjustment(adjustment("PRIME", 10))
+            .is_ok());
+        assert!(oracle
+            .set_location_adjustment(adjustment("FLOOD_ZONE", -20))
+            .is_ok());
+        assert!(oracle
+            .set_location_adjustment(adjustment("HYPE", 300))
+            .is_ok());
+        assert_eq!(
+            oracle
+                .get_location_adjustment("FLOOD_ZONE".to_string())
+                .unwrap()
+                .adjustment_percentage,
+            -20
+        );
+
+        let refresh = |oracle: &mut PropertyValuationOracle, property_id| {
+            for (id, address) in [("feed_a", accounts.bob), ("feed_b", accounts.charlie)] {
+                test::set_caller::<DefaultEnvironment>(address);
+                let price = PriceData {
+                    price: 100000,
+                    timestamp: 0,
+                    source: String::new(),
+                };
+                assert!(oracle
+                    .update_price(id.to_string(), property_id, price)
+                    .is_ok());
+            }
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            oracle.get_valuation(property_id).unwrap().valuation
+        };
+
+        // No location assigned: the base valuation is unchanged
+        assert_eq!(refresh(&mut oracle, 1), 100000);
+
+        assert!(oracle.set_property_location(1, "PRIME".to_string()).is_ok());
+        assert_eq!(refresh(&mut oracle, 1), 110000);
+
+        assert!(oracle
+            .set_property_location(1, "FLOOD_ZONE".to_string())
+            .is_ok());
+        assert_eq!(refresh(&mut oracle, 1), 80000);
+
+        // Extreme factors are capped
+        assert!(oracle.set_property_location(2, "HYPE".to_string()).is_ok());
+        assert_eq!(refresh(&mut oracle, 2), 150000);
+    }
+
+    #[ink::test]
+    fn test_confidence_multiplier_discounts_property_type() {
+        let mut oracle = setup_oracle();
+
+        let valuation = |property_id| PropertyValuation {
+            property_id,
+            valuation: 500000,
+            confidence_score: 80,
+            sources_used: 3,
+            last_updated: 0,
+            valuation_method: ValuationMethod::MarketData,
+        };
+
+        assert!(oracle.update_property_valuation(1, valuation(1)).is_ok());
+        assert!(oracle.update_property_valuation(2, valuation(2)).is_ok());
+        assert!(oracle
+            .set_property_type(1, PropertyType::Residential)
+            .is_ok());
+        assert!(oracle.set_property_type(2, PropertyType::Land).is_ok());
+
+        // Default multiplier leaves confidence unchanged
+        assert_eq!(oracle.get_confidence_multiplier(PropertyType::Land), 100);
+        assert!(oracle
+            .set_confidence_multiplier(PropertyType::Land, 50)
+            .is_ok());
+        assert_eq!(
+            oracle.set_confidence_multiplier(PropertyType::Land, 101),
+            Err(OracleError::InvalidParameters)
+        );
+
+        let residential = oracle.get_valuation_with_confidence(1).unwrap();
+        let land = oracle.get_valuation_with_confidence(2).unwrap();
+
+        assert_eq!(residential.valuation.confidence_score, 80);
+        assert_eq!(land.valuation.confidence_score, 40);
+        assert!(land.confidence_interval.0 < residential.confidence_interval.0);
+    }
+
+    #[ink::test]
+    fn test_price_jump_halt() {
+        let mut oracle = setup_oracle();
+        let accounts = DefaultAccounts::default();
+
+        let source = OracleSource {
+            id: "manual_feed".to_string(),
+            source_type: OracleSourceType::Manual,
+            address: accounts.bob,
+            is_active: true,
+            weight: 50,
+            last_updated: oracle.env().block_timestamp(),
+        };
+        assert!(oracle.add_oracle_source(source).is_ok());
+        assert!(oracle.set_max_price_jump_bps(1000).is_ok()); // 10%
+
+        let price = |price| PriceData {
+            price,
+            timestamp: 0,
+            source: "manual_feed".to_string(),
+        };
+
+        // Only the source address may submit
+        assert_eq!(
+            oracle.submit_price_data(1, price(100000)),
+            Err(OracleError::Unauthorized)
+        );
+
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert!(oracle.submit_price_data(1, price(100000)).is_ok());
+
+        // Within threshold
+        assert!(oracle.submit_price_data(1, price(109000)).is_ok());
+
+        // Extreme jump is rejected and the prior price kept
+        assert_eq!(
+            oracle.submit_price_data(1, price(500000)),
+            Err(OracleError::PriceJumpExceeded)
+        );
+        assert_eq!(
+            oracle
+                .get_source_price("manual_feed".to_string(), 1)
+                .unwrap()
+                .price,
+            109000
+        );
+
+        // Admin override accepts it
+        assert_eq!(
+            oracle.override_price_data(1, price(500000)),
+            Err(OracleError::Unauthorized)
+        );
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        assert!(oracle.override_price_data(1, price(500000)).is_ok());
+        assert_eq!(
+            oracle
+                .get_source_price("manual_feed".to_string(), 1)
+                .unwrap()
+                .price,
+            500000
+        );
+    }
+
+    #[ink::test]
+    fn test_update_price_computes_weighted_valuation() {
+        let mut oracle = setup_oracle();
+        let accounts = DefaultAccounts::default();
+
+        let source = |id: &str, address, weight| OracleSource {
+            id: id.to_string(),
+            source_type: OracleSourceType::Custom,
+            address,
+            is_active: true,
+            weight,
+            last_updated: 0,
+        };
+        assert!(oracle
+            .add_oracle_source(source("appraiser", accounts.bob, 60))
+            .is_ok());
+        assert!(oracle
+            .add_oracle_source(source("market_feed", accounts.charlie, 40))
+            .is_ok());
+
+        let price = |price| PriceData {
+            price,
+            timestamp: 0,
+            source: String::new(),
+        };
+
+        // Sources can only push under their own ID
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(
+            oracle.update_price("market_feed".to_string(), 1, price(200000)),
+            Err(OracleError::Unauthorized)
+        );
+
+        // A single source is not enough for a valuation
+        assert!(oracle
+            .update_price("appraiser".to_string(), 1, price(100000))
+            .is_ok());
+        assert_eq!(oracle.get_valuation(1), None);
+
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        assert!(oracle
+            .update_price("market_feed".to_string(), 1, price(200000))
+            .is_ok());
+
+        // (100000 * 60 + 200000 * 40) / 100
+        let valuation = oracle.get_valuation(1).unwrap();
+        assert_eq!(valuation.valuation, 140000);
+        assert_eq!(valuation.sources_used, 2);
+        assert_eq!(valuation.valuation_method, ValuationMethod::MarketData);
+        assert!(valuation.confidence_score > 0 && valuation.confidence_score <= 100);
+
+        // Closer agreement between sources raises confidence
+        assert!(oracle
+            .update_price("market_feed".to_string(), 1, price(102000))
+            .is_ok());
+        let agreed = oracle.get_valuation(1).unwrap();
+        assert!(agreed.confidence_score > valuation.confidence_score);
+    }
+
+    #[ink::test]
+    fn test_valuation_with_confidence_excludes_outliers() {
+        let mut oracle = setup_oracle();
+        let accounts = DefaultAccounts::default();
+
+        let feeds = [
+            ("feed_a", accounts.bob, 100000),
+            ("feed_b", accounts.charlie, 102000),
+            ("feed_c", accounts.django, 300000), // Outlier
+        ];
+        for (id, address, _) in feeds {
+            let source = OracleSource {
+                id: id.to_string(),
+                source_type: OracleSourceType::Custom,
+                address,
+                is_active: true,
+                weight: 50,
+                last_updated: 0,
+            };
+            assert!(oracle.add_oracle_source(source).is_ok());
+        }
+        assert_eq!(
+            oracle.set_max_source_deviation_pct(0),
+            Err(OracleError::InvalidParameters)
+        );
+        assert_eq!(oracle.get_max_source_deviation_pct(), 20);
+
+        for (id, address, price) in feeds {
+            test::set_caller::<DefaultEnvironment>(address);
+            let price_data = PriceData {
+                price,
+                timestamp: 0,
+                source: id.to_string(),
+            };
+            assert!(oracle.submit_price_data(1, price_data).is_ok());
+        }
+
+        let result = oracle.get_valuation_with_confidence(1).unwrap();
+        assert_eq!(result.outlier_sources, 1);
+        assert_eq!(result.valuation.sources_used, 2);
+        assert_eq!(result.valuation.valuation, 101000);
+        assert_eq!(result.confidence_interval, (100000, 102000));
+        // (102000 - 100000) * 100 / median 102000
+        assert_eq!(result.volatility_index, 1);
+    }
+
+    #[ink::test]
+    fn test_stored_valuation_matches_confidence_path() {
+        let mut oracle = setup_oracle();
+        let accounts = DefaultAccounts::default();
+
+        let feeds = [
+            ("feed_a", accounts.bob, 100000),
+            ("feed_b", accounts.charlie, 104000),
+            ("feed_c", accounts.django, 300000), // Outlier
+        ];
+        for (id, address, _) in feeds {
+            let source = OracleSource {
+                id: id.to_string(),
+                source_type: OracleSourceType::Custom,
+                address,
+                is_active: true,
+                weight: 50,
+                last_updated: 0,
+            };
+            assert!(oracle.add_oracle_source(source).is_ok());
+        }
+        assert!(oracle
+            .set_location_adjustment(LocationAdjustment {
+                location_code: "PRIME".to_string(),
+                adjustment_percentage: 10,
+                last_updated: 0,
+                confidence_score: 90,
+            })
+            .is_ok());
+        assert!(oracle.set_property_location(1, "PRIME".to_string()).is_ok());
+
+        for (id, address, price) in feeds {
+            test::set_caller::<DefaultEnvironment>(address);
+            let price_data = PriceData {
+                price,
+                timestamp: 0,
+                source: String::new(),
+            };
+            assert!(oracle.update_price(id.to_string(), 1, price_data).is_ok());
+        }
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+
+        // The outlier is excluded from the stored valuation as well, and both carry the location adjustment
+        let stored = oracle.get_valuation(1).unwrap();
+        let result = oracle.get_valuation_with_confidence(1).unwrap();
+        assert_eq!(stored, result.valuation);
+        assert_eq!(stored.valuation, 112200);
+        assert_eq!(stored.sources_used, 2);
+        assert_eq!(result.confidence_interval, (110000, 114400));
+        assert!(
+            result.confidence_interval.0 <= stored.valuation
+                && stored.valuation <= result.confidence_interval.1
+        );
+
+        // Once the source prices go stale, the stored valuation keeps its recorded volatility
+        test::set_block_timestamp::<DefaultEnvironment>(oracle.get_max_price_age_ms() + 1);
+        let fallback = oracle.get_valuation_with_confidence(1).unwrap();
+        assert_eq!(fallback.valuation, stored);
+        // (104000 - 100000) * 100 / median 104000
+        assert_eq!(fallback.volatility_index, 3);
+        assert_eq!(result.volatility_index, 3);
+    }
+
+    #[ink::test]
+    fn test_market_trend_and_volatility_per_property_type() {
+        let mut oracle = setup_oracle();
+        let accounts = DefaultAccounts::default();
+        let location = "downtown".to_string();
+
+        assert!(oracle
+            .set_market_trend(MarketTrend {
+                property_type: PropertyType::Residential,
+                location: location.clone(),
+                trend_percentage: 4,
+                period_months: 12,
+                last_updated: 0,
+            })
+            .is_ok());
+        assert!(oracle
+            .update_market_trend(MarketTrend {
+                property_type: PropertyType::Commercial,
+                location: location.clone(),
+                trend_percentage: -7,
+                period_months: 6,
+                last_updated: 0,
+            })
+            .is_ok());
+
+        let residential = oracle
+            .get_market_trend(PropertyType::Residential, location.clone())
+            .unwrap();
+        assert_eq!(residential.trend_percentage, 4);
+        let commercial = oracle
+            .get_market_trend(PropertyType::Commercial, location.clone())
+            .unwrap();
+        assert_eq!(commercial.trend_percentage, -7);
+        assert!(oracle
+            .get_market_trend(PropertyType::Land, location.clone())
+            .is_none());
+
+        // Without published metrics, volatility is estimated from the trend
+        assert!(oracle
+            .get_volatility(PropertyType::Commercial, location.clone())
+            .is_none());
+        let estimated = oracle
+            .get_market_volatility(PropertyType::Commercial, location.clone())
+            .unwrap();
+        assert_eq!(estimated.volatility_index, 7);
+
+        let published = VolatilityMetrics {
+            property_type: PropertyType::Commercial,
+            location: location.clone(),
+            volatility_index: 35,
+            average_price_change: -7,
+            period_days: 180,
+            last_updated: 0,
+        };
+        assert_eq!(
+            oracle.set_volatility(VolatilityMetrics {
+                volatility_index: 101,
+                ..published.clone()
+            }),
+            Err(OracleError::InvalidParameters)
+        );
+        assert!(oracle.set_volatility(published.clone()).is_ok());
+        assert_eq!(
+            oracle.get_volatility(PropertyType::Commercial, location.clone()),
+            Some(published.clone())
+        );
+        assert_eq!(
+            oracle.get_market_volatility(PropertyType::Commercial, location.clone()),
+            Ok(published.clone())
+        );
+        // Residential in the same location is unaffected
+        assert!(oracle
+            .get_volatility(PropertyType::Residential, location.clone())
+            .is_none());
+        assert_eq!(
+            oracle
+                .get_market_volatility(PropertyType::Residential, location.clone())
+                .unwrap()
+                .volatility_index,
+            4
+        );
+
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(
+            oracle.set_volatility(published),
+            Err(OracleError::Unauthorized)
+        );
+        assert_eq!(
+            oracle.set_market_trend(residential),
+            Err(OracleError::Unauthorized)
+        );
+    }
+
+    fn comparable(
+        distance_km: u32,
+        price_per_sqm: u128,
+        adjustment_factor: i32,
+    ) -> ComparableProperty {
+        ComparableProperty {
+            property_id: 100 + distance_km as u64,
+            distance_km,
+            price_per_sqm,
+            size_sqm: 120,
+            sale_date: 0,
+            adjustment_factor,
+        }
+    }
+
+    #[ink::test]
+    fn test_estimate_from_comparables() {
+        let mut oracle = setup_oracle();
+        let accounts = DefaultAccounts::default();
+
+        assert_eq!(
+            oracle.estimate_from_comparables(1, 100),
+            Err(OracleError::InsufficientSources)
+        );
+
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        let comparables = vec![
+            comparable(0, 1000, 10),  // adjusted 1100, weight 1000
+            comparable(1, 1000, -10), // adjusted 900, weight 500
+            comparable(3, 1200, 0),   // adjusted 1200, weight 250
+        ];
+        assert_eq!(
+            oracle.submit_comparables(1, comparables.clone()),
+            Err(OracleError::Unauthorized)
+        );
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        assert!(oracle.set_appraiser(accounts.bob, true).is_ok());
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(
+            oracle.submit_comparables(1, vec![comparable(0, 1000, -100)]),
+            Err(OracleError::InvalidParameters)
+        );
+        assert!(oracle.submit_comparables(1, comparables).is_ok());
+
+        let estimate = oracle.estimate_from_comparables(1, 100).unwrap();
+        // (1100 * 1000 + 900 * 500 + 1200 * 250) / 1750 = 1057 per sqm
+        assert_eq!(estimate.valuation, 105_700);
+        assert_eq!(estimate.valuation_method, ValuationMethod::MarketData);
+        assert_eq!(estimate.sources_used, 3);
+        assert_eq!(estimate.confidence_score, 88);
+
+        // A single comparable earns less confidence
+        assert!(oracle
+            .submit_comparables(2, vec![comparable(2, 1000, 0)])
+            .is_ok());
+        let single = oracle.estimate_from_comparables(2, 50).unwrap();
+        assert_eq!(single.valuation, 50_000);
+        assert!(single.confidence_score < estimate.confidence_score);
+    }
+
+    #[ink::test]
+    fn test_estimate_from_comparables_rejects_unsafe_inputs() {
+        use crate::propchain_oracle::{
+            MAX_COMPARABLE_ADJUSTMENT_PCT, MAX_COMPARABLE_PRICE_PER_SQM,
+        };
+
+        let mut oracle = setup_oracle();
+
+        // Prices and adjustments beyond the caps are refused up front
+        assert_eq!(
+            oracle.submit_comparables(1, vec![comparable(0, MAX_COMPARABLE_PRICE_PER_SQM + 1, 0)]),
+            Err(OracleError::InvalidParameters)
+        );
+        assert_eq!(
+            oracle.submit_comparables(
+                1,
+                vec![comparable(0, 1000, MAX_COMPARABLE_ADJUSTMENT_PCT + 1)]
+            ),
+            Err(OracleError::InvalidParameters)
+        );
+
+        // Comparables 1000 km or more away would get a zero inverse-distance weight
+        assert!(oracle
+            .submit_comparables(
+                1,
+                vec![comparable(1_000, 1000, 0), comparable(5_000, 2000, 0)]
+            )
+            .is_ok());
+        let estimate = oracle.estimate_from_comparables(1, 10).unwrap();
+        assert_eq!(estimate.valuation, 15_000);
+
+        // The largest accepted inputs do not overflow
+        assert!(oracle
+            .submit_comparables(
+                2,
+                vec![comparable(
+                    0,
+                    MAX_COMPARABLE_PRICE_PER_SQM,
+                    MAX_COMPARABLE_ADJUSTMENT_PCT
+                )]
+            )
+            .is_ok());
+        let estimate = oracle.estimate_from_comparables(2, u64::MAX).unwrap();
+        assert_eq!(
+            estimate.valuation,
+            2 * MAX_COMPARABLE_PRICE_PER_SQM * u64::MAX as u128
+        );
+    }
+
     #[ink::test]
     fn test_get_comparable_properties_works() {
         let oracle = setup_oracle();
@@ -817,6 +2156,231 @@ mod oracle_tests {
         assert_eq!(history.len(), 0);
     }
 
+    #[ink::test]
+    fn test_deactivated_source_weights_are_renormalized() {
+        let mut oracle = setup_oracle();
+        let accounts = DefaultAccounts::default();
+
+        let feeds = [
+            ("feed_a", accounts.bob, 50, 100000),
+            ("feed_b", accounts.charlie, 30, 110000),
+            ("feed_c", accounts.django, 20, 120000),
+        ];
+        for (id, address, weight, _) in feeds {
+            assert!(oracle
+                .add_oracle_source(OracleSource {
+                    id: id.to_string(),
+                    source_type: OracleSourceType::Custom,
+                    address,
+                    is_active: true,
+                    weight,
+                    last_updated: 0,
+                })
+                .is_ok());
+        }
+        for (id, address, _, price) in feeds {
+            test::set_caller::<DefaultEnvironment>(address);
+            let price_data = PriceData {
+                price,
+                timestamp: 0,
+                source: String::new(),
+            };
+            assert!(oracle.update_price(id.to_string(), 1, price_data).is_ok());
+        }
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+
+        // (100000 * 50 + 110000 * 30 + 120000 * 20) / 100
+        assert_eq!(oracle.get_valuation(1).unwrap().valuation, 107000);
+
+        // Prices collected before feed_a was deactivated no longer count toward the aggregate
+        let collected: Vec<PriceData> = feeds
+            .iter()
+            .map(|(id, _, _, _)| oracle.get_source_price(id.to_string(), 1).unwrap())
+            .collect();
+        assert!(oracle
+            .set_oracle_active("feed_a".to_string(), false)
+            .is_ok());
+        assert!(
+            !oracle
+                .get_oracle_source("feed_a".to_string())
+                .unwrap()
+                .is_active
+        );
+        assert_eq!(
+            oracle.active_sources,
+            vec!["feed_b".to_string(), "feed_c".to_string()]
+        );
+
+        // (110000 * 30 + 120000 * 20) / 50
+        assert_eq!(oracle.aggregate_prices(&collected), Ok(114000));
+        assert!(oracle.update_valuation_from_sources(1).is_ok());
+        assert_eq!(oracle.get_valuation(1).unwrap().valuation, 114000);
+
+        // Reactivation restores the source
+        assert!(oracle.set_oracle_active("feed_a".to_string(), true).is_ok());
+        assert!(oracle.update_valuation_from_sources(1).is_ok());
+        assert_eq!(oracle.get_valuation(1).unwrap().valuation, 107000);
+
+        assert!(oracle.remove_oracle_source("feed_a".to_string()).is_ok());
+        assert!(oracle.get_oracle_source("feed_a".to_string()).is_none());
+        assert_eq!(
+            oracle.remove_oracle_source("feed_a".to_string()),
+            Err(OracleError::OracleSourceNotFound)
+        );
+
+        // With every source inactive there is nothing to aggregate
+        assert!(oracle
+            .set_oracle_active("feed_b".to_string(), false)
+            .is_ok());
+        assert!(oracle
+            .set_oracle_active("feed_c".to_string(), false)
+            .is_ok());
+        assert_eq!(
+            oracle.aggregate_prices(&collected),
+            Err(OracleError::NoActiveSources)
+        );
+        assert_eq!(
+            oracle.update_valuation_from_sources(1),
+            Err(OracleError::NoActiveSources)
+        );
+
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(
+            oracle.set_oracle_active("feed_b".to_string(), true),
+            Err(OracleError::Unauthorized)
+        );
+    }
+
+    #[ink::test]
+    fn test_update_valuation_from_sources_requires_admin() {
+        let mut oracle = setup_oracle();
+        let accounts = DefaultAccounts::default();
+
+        let feeds = [("feed_a", accounts.bob), ("feed_b", accounts.charlie)];
+        for (id, address) in feeds {
+            assert!(oracle
+                .add_oracle_source(OracleSource {
+                    id: id.to_string(),
+                    source_type: OracleSourceType::Custom,
+                    address,
+                    is_active: true,
+                    weight: 10,
+                    last_updated: 0,
+                })
+                .is_ok());
+            test::set_caller::<DefaultEnvironment>(address);
+            let price_data = PriceData {
+                price: 100000,
+                timestamp: 0,
+                source: String::new(),
+            };
+            assert!(oracle.update_price(id.to_string(), 1, price_data).is_ok());
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+        }
+        let history_len = oracle.get_historical_valuations(1, 100).len();
+
+        // A registered source cannot trigger extra history entries either
+        for caller in [accounts.eve, accounts.bob] {
+            test::set_caller::<DefaultEnvironment>(caller);
+            assert_eq!(
+                oracle.update_valuation_from_sources(1),
+                Err(OracleError::Unauthorized)
+            );
+        }
+        assert_eq!(oracle.get_historical_valuations(1, 100).len(), history_len);
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        assert!(oracle.update_valuation_from_sources(1).is_ok());
+        assert_eq!(
+            oracle.get_historical_valuations(1, 100).len(),
+            history_len + 1
+        );
+    }
+
+    #[ink::test]
+    fn test_stale_prices_are_excluded() {
+        let mut oracle = setup_oracle();
+        let accounts = DefaultAccounts::default();
+
+        assert_eq!(oracle.get_max_price_age_ms(), 3_600_000);
+        assert_eq!(
+            oracle.set_max_price_age_ms(0),
+            Err(OracleError::InvalidParameters)
+        );
+        assert!(oracle.set_max_price_age_ms(1_000_000).is_ok());
+
+        let feeds = [
+            ("feed_a", accounts.bob),
+            ("feed_b", accounts.charlie),
+            ("feed_c", accounts.django),
+        ];
+        for (id, address) in feeds {
+            assert!(oracle
+                .add_oracle_source(OracleSource {
+                    id: id.to_string(),
+                    source_type: OracleSourceType::Custom,
+                    address,
+                    is_active: true,
+                    weight: 10,
+                    last_updated: 0,
+                })
+                .is_ok());
+        }
+        let push =
+            |oracle: &mut PropertyValuationOracle, feed: (&str, AccountId), price, timestamp| {
+                test::set_caller::<DefaultEnvironment>(feed.1);
+                let price_data = PriceData {
+                    price,
+                    timestamp,
+                    source: String::new(),
+                };
+                assert!(oracle
+                    .update_price(feed.0.to_string(), 1, price_data)
+                    .is_ok());
+                test::set_caller::<DefaultEnvironment>(accounts.alice);
+            };
+
+        test::set_block_timestamp::<DefaultEnvironment>(5_000_000);
+        // feed_a reports a wildly different price, but it is four times older than allowed
+        push(&mut oracle, feeds[0], 500000, 1_000_000);
+        push(&mut oracle, feeds[1], 100000, 4_500_000);
+        push(&mut oracle, feeds[2], 100000, 5_000_000);
+
+        let partial = oracle.get_valuation(1).unwrap();
+        assert_eq!(partial.valuation, 100000);
+        assert_eq!(partial.sources_used, 2);
+
+        // With all three sources fresh, confidence is higher
+        push(&mut oracle, feeds[0], 100000, 5_000_000);
+        let full = oracle.get_valuation(1).unwrap();
+        assert_eq!(full.sources_used, 3);
+        assert!(partial.confidence_score < full.confidence_score);
+
+        // Aggregating a mix of fresh and stale prices ignores the stale ones
+        let price = |price, timestamp, source: &str| PriceData {
+            price,
+            timestamp,
+            source: source.to_string(),
+        };
+        let mixed = vec![
+            price(500000, 1_000_000, "feed_a"),
+            price(100000, 5_000_000, "feed_b"),
+            price(110000, 5_000_000, "feed_c"),
+        ];
+        assert_eq!(oracle.aggregate_prices(&mixed), Ok(105000));
+
+        // Once too few fresh prices remain there is no valuation to compute
+        test::set_block_timestamp::<DefaultEnvironment>(6_100_000);
+        assert_eq!(
+            oracle.aggregate_prices(&mixed),
+            Err(OracleError::InsufficientSources)
+        );
+        assert_eq!(
+            oracle.update_valuation_from_sources(1),
+            Err(OracleError::InsufficientSources)
+        );
+    }
+
     #[ink::test]
     fn test_insufficient_sources_error() {
         let oracle = setup_oracle();
@@ -27,6 +27,11 @@ mod property_token {
         BridgeNotSupported,
         InvalidChain,
         BridgeLocked,
+        BridgeAlreadyProcessed,
+        BridgeAlreadyApproved,
+        InvalidThreshold,
+        // Safe transfer errors
+        TransferToNonReceiver,
     }
 
     /// Property Token contract that maintains compatibility with ERC-721 and ERC-1155
@@ -37,37 +42,55 @@ mod property_token {
         token_owner: Mapping<TokenId, AccountId>,
         owner_token_count: Mapping<AccountId, u32>,
         token_approvals: Mapping<TokenId, AccountId>,
+        // Operator approvals shared by the ERC-721 and ERC-1155 paths
         operator_approvals: Mapping<(AccountId, AccountId), bool>,
-        
+        owner_operators: Mapping<AccountId, Vec<AccountId>>,
+
         // ERC-1155 batch operation support
         balances: Mapping<(AccountId, TokenId), u128>,
-        operators: Mapping<(AccountId, AccountId), bool>,
-        
+
         // Property-specific mappings
         token_properties: Mapping<TokenId, PropertyInfo>,
         property_tokens: Mapping<u64, TokenId>, // property_id to token_id mapping
         ownership_history: Mapping<TokenId, Vec<OwnershipTransfer>>,
         compliance_flags: Mapping<TokenId, ComplianceInfo>,
         legal_documents: Mapping<TokenId, Vec<DocumentInfo>>,
-        
+
         // Cross-chain bridge mappings
         bridged_tokens: Mapping<(ChainId, TokenId), BridgedTokenInfo>,
+        // Destination chain each token is locked for while bridged out
+        bridge_locks: Mapping<TokenId, ChainId>,
+        // Bridges that have been initiated but not yet completed, by slot
+        pending_bridges: Mapping<u32, (ChainId, TokenId)>,
+        pending_bridge_count: u32,
+        pending_bridge_positions: Mapping<(ChainId, TokenId), u32>,
+        // Source transfers that have already been minted on this chain
+        processed_bridge_receives: Mapping<(ChainId, TokenId), bool>,
+        // Operators that approved a receive, keyed by (source_chain, original_token_id, recipient)
+        bridge_receive_approvals: Mapping<(ChainId, TokenId, AccountId), Vec<AccountId>>,
+        // Distinct operator approvals needed before a bridged token is minted
+        required_bridge_signatures: u32,
         bridge_operators: Vec<AccountId>,
-        
+
         // Standard counters
         total_supply: u64,
         token_counter: u64,
         admin: AccountId,
+
+        // Optional compliance registry consulted on transfers
+        compliance_registry: Option<AccountId>,
     }
 
     /// Token ID type alias
     pub type TokenId = u64;
-    
+
     /// Chain ID type alias
     pub type ChainId = u64;
 
     /// Ownership transfer record
-    #[derive(Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout)]
+    #[derive(
+        Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
+    )]
     #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
     pub struct OwnershipTransfer {
         pub from: AccountId,
@@ -94,6 +117,7 @@ mod property_token {
         pub document_type: String,
         pub upload_date: u64,
         pub uploader: AccountId,
+        pub verified: bool,
     }
 
     /// Bridged token information
@@ -158,6 +182,14 @@ mod property_token {
         pub owner: AccountId,
     }
 
+    #[ink(event)]
+    pub struct BatchPropertyTokensMinted {
+        #[ink(topic)]
+        pub owner: AccountId,
+        pub token_ids: Vec<TokenId>,
+        pub count: u64,
+    }
+
     #[ink(event)]
     pub struct LegalDocumentAttached {
         #[ink(topic)]
@@ -168,6 +200,16 @@ mod property_token {
         pub document_type: String,
     }
 
+    #[ink(event)]
+    pub struct LegalDocumentRemoved {
+        #[ink(topic)]
+        pub token_id: TokenId,
+        #[ink(topic)]
+        pub document_hash: Hash,
+        #[ink(topic)]
+        pub removed_by: AccountId,
+    }
+
     #[ink(event)]
     pub struct ComplianceVerified {
         #[ink(topic)]
@@ -188,6 +230,66 @@ mod property_token {
         pub recipient: AccountId,
     }
 
+    #[ink(event)]
+    pub struct BridgeReceiveApproved {
+        #[ink(topic)]
+        pub source_chain: ChainId,
+        #[ink(topic)]
+        pub original_token_id: TokenId,
+        #[ink(topic)]
+        pub operator: AccountId,
+        pub recipient: AccountId,
+        pub approvals: u32,
+    }
+
+    /// Calls the token dispatches into other contracts
+    trait ExternalCalls {
+        /// Asks `registry` whether `account` passes its compliance checks
+        fn is_compliant(
+            registry: AccountId,
+            account: AccountId,
+        ) -> Result<bool, compliance_client::CallError>;
+
+        /// Calls the `on_property_received` hook of `receiver`
+        fn on_property_received(
+            receiver: AccountId,
+            operator: AccountId,
+            from: AccountId,
+            token_id: TokenId,
+            data: Vec<u8>,
+        ) -> Result<[u8; 4], compliance_client::CallError>;
+    }
+
+    /// Dispatches calls to the deployed contracts
+    #[cfg_attr(test, allow(dead_code))]
+    struct OnChainCalls;
+
+    impl ExternalCalls for OnChainCalls {
+        fn is_compliant(
+            registry: AccountId,
+            account: AccountId,
+        ) -> Result<bool, compliance_client::CallError> {
+            compliance_client::is_compliant(registry, account)
+        }
+
+        fn on_property_received(
+            receiver: AccountId,
+            operator: AccountId,
+            from: AccountId,
+            token_id: TokenId,
+            data: Vec<u8>,
+        ) -> Result<[u8; 4], compliance_client::CallError> {
+            receiver_client::on_property_received(receiver, operator, from, token_id, data)
+        }
+    }
+
+    /// The off-chain test environment cannot dispatch cross-contract calls, so unit tests
+    /// answer them from `tests::MockCalls` instead
+    #[cfg(not(test))]
+    type Calls = OnChainCalls;
+    #[cfg(test)]
+    type Calls = tests::MockCalls;
+
     impl PropertyToken {
         /// Creates a new PropertyToken contract
         #[ink(constructor)]
@@ -199,26 +301,35 @@ mod property_token {
                 owner_token_count: Mapping::default(),
                 token_approvals: Mapping::default(),
                 operator_approvals: Mapping::default(),
-                
+                owner_operators: Mapping::default(),
+
                 // ERC-1155 batch operation support
                 balances: Mapping::default(),
-                operators: Mapping::default(),
-                
+
                 // Property-specific mappings
                 token_properties: Mapping::default(),
                 property_tokens: Mapping::default(),
                 ownership_history: Mapping::default(),
                 compliance_flags: Mapping::default(),
                 legal_documents: Mapping::default(),
-                
+
                 // Cross-chain bridge mappings
                 bridged_tokens: Mapping::default(),
+                bridge_locks: Mapping::default(),
+                pending_bridges: Mapping::default(),
+                pending_bridge_count: 0,
+                pending_bridge_positions: Mapping::default(),
+                processed_bridge_receives: Mapping::default(),
+                bridge_receive_approvals: Mapping::default(),
+                required_bridge_signatures: 1,
                 bridge_operators: vec![caller],
-                
+
                 // Standard counters
                 total_supply: 0,
                 token_counter: 0,
                 admin: caller,
+
+                compliance_registry: None,
             }
         }
 
@@ -243,35 +354,74 @@ mod property_token {
             token_id: TokenId,
         ) -> Result<(), Error> {
             let caller = self.env().caller();
-            
+
             // Check if caller is authorized to transfer
-            let token_owner = self.token_owner.get(&token_id).ok_or(Error::TokenNotFound)?;
+            let token_owner = self
+                .token_owner
+                .get(&token_id)
+                .ok_or(Error::TokenNotFound)?;
             if token_owner != from {
                 return Err(Error::Unauthorized);
             }
-            
-            if caller != from 
+
+            if caller != from
                 && Some(caller) != self.token_approvals.get(&token_id)
-                && !self.is_approved_for_all(from, caller) {
+                && !self.is_approved_for_all(from, caller)
+            {
                 return Err(Error::Unauthorized);
             }
-            
+
+            // Check compliance for recipient
+            self.ensure_compliant(to)?;
+
             // Perform the transfer
             self.remove_token_from_owner(from, token_id)?;
             self.add_token_to_owner(to, token_id)?;
-            
+            self.token_owner.insert(&token_id, &to);
+
+            // Keep the ERC-1155 balances in sync with ownership
+            let from_balance = self.balances.get((&from, &token_id)).unwrap_or(0);
+            self.balances.remove((&from, &token_id));
+            let to_balance = self.balances.get((&to, &token_id)).unwrap_or(0);
+            self.balances
+                .insert((&to, &token_id), &(to_balance + from_balance));
+
             // Clear approvals
             self.token_approvals.remove(&token_id);
-            
+
             // Update ownership history
             self.update_ownership_history(token_id, from, to)?;
-            
+
             self.env().emit_event(Transfer {
                 from: Some(from),
                 to: Some(to),
                 id: token_id,
             });
-            
+
+            Ok(())
+        }
+
+        /// ERC-721: Transfers a token and, if the recipient is a contract, requires it to
+        /// accept the token by returning `ON_PROPERTY_RECEIVED_MAGIC` from `on_property_received`
+        #[ink(message)]
+        pub fn safe_transfer_from(
+            &mut self,
+            from: AccountId,
+            to: AccountId,
+            token_id: TokenId,
+            data: Vec<u8>,
+        ) -> Result<(), Error> {
+            self.transfer_from(from, to, token_id)?;
+
+            // Rejection reverts the whole call, including the transfer above
+            if self.is_contract(to) {
+                let operator = self.env().caller();
+                let accepted = self.notify_receiver(to, operator, from, token_id, data);
+                if accepted != Some(receiver_client::ON_PROPERTY_RECEIVED_MAGIC) {
+                    return Err(Error::TransferToNonReceiver);
+                }
+            }
+
             Ok(())
         }
 
@@ -279,28 +429,47 @@ mod property_token {
         #[ink(message)]
         pub fn approve(&mut self, to: AccountId, token_id: TokenId) -> Result<(), Error> {
             let caller = self.env().caller();
-            let token_owner = self.token_owner.get(&token_id).ok_or(Error::TokenNotFound)?;
-            
+            let token_owner = self
+                .token_owner
+                .get(&token_id)
+                .ok_or(Error::TokenNotFound)?;
+
             if token_owner != caller && !self.is_approved_for_all(token_owner, caller) {
                 return Err(Error::Unauthorized);
             }
-            
+
             self.token_approvals.insert(&token_id, &to);
-            
+
             self.env().emit_event(Approval {
                 owner: token_owner,
                 spender: to,
                 id: token_id,
             });
-            
+
             Ok(())
         }
 
         /// ERC-721: Sets or unsets an operator for an owner
         #[ink(message)]
-        pub fn set_approval_for_all(&mut self, operator: AccountId, approved: bool) -> Result<(), Error> {
+        pub fn set_approval_for_all(
+            &mut self,
+            operator: AccountId,
+            approved: bool,
+        ) -> Result<(), Error> {
             let caller = self.env().caller();
-            self.operator_approvals.insert((&caller, &operator), &approved);
+            let mut operators = self.owner_operators.get(&caller).unwrap_or_default();
+
+            if approved {
+                self.operator_approvals.insert((&caller, &operator), &true);
+                if !operators.contains(&operator) {
+                    operators.push(operator);
+                }
+            } else {
+                // Drop the entry entirely rather than leaving a stale `false`
+                self.operator_approvals.remove((&caller, &operator));
+                operators.retain(|op| op != &operator);
+            }
+            self.owner_operators.insert(&caller, &operators);
             
             self.env().emit_event(ApprovalForAll {
                 owner: caller,
@@ -311,13 +480,38 @@ mod property_token {
             Ok(())
         }
 
+        /// Revokes every operator the caller has approved
+        #[ink(message)]
+        pub fn clear_all_operators(&mut self) -> Result<(), Error> {
+            let caller = self.env().caller();
+            let operators = self.owner_operators.take(&caller).unwrap_or_default();
+
+            for operator in operators {
+                self.operator_approvals.remove((&caller, &operator));
+
+                self.env().emit_event(ApprovalForAll {
+                    owner: caller,
+                    operator,
+                    approved: false,
+                });
+            }
+
+            Ok(())
+        }
+
+        /// Returns the operators currently approved by an owner
+        #[ink(message)]
+        pub fn get_operators(&self, owner: AccountId) -> Vec<AccountId> {
+            self.owner_operators.get(&owner).unwrap_or_default()
+        }
+
         /// ERC-721: Gets the approved account for a token
         #[ink(message)]
         pub fn get_approved(&self, token_id: TokenId) -> Option<AccountId> {
             self.token_approvals.get(&token_id)
         }
 
-        /// ERC-721: Checks if an operator is approved for an owner
+        /// ERC-721/ERC-1155: Checks if an operator is approved for an owner
         #[ink(message)]
         pub fn is_approved_for_all(&self, owner: AccountId, operator: AccountId) -> bool {
             self.operator_approvals.get((&owner, &operator)).unwrap_or(false)
@@ -349,34 +543,38 @@ mod property_token {
             data: Vec<u8>,
         ) -> Result<(), Error> {
             let caller = self.env().caller();
-            
-            if from != caller 
-                && !self.is_approved_for_all(from, caller) {
+
+            if from != caller && !self.is_approved_for_all(from, caller) {
                 return Err(Error::Unauthorized);
             }
-            
+
             // Verify lengths match
             if ids.len() != amounts.len() {
                 return Err(Error::Unauthorized); // Using this as a general error for mismatched arrays
             }
-            
+
+            // Check compliance for recipient
+            self.ensure_compliant(to)?;
+
             // Transfer each token
             for i in 0..ids.len() {
                 let token_id = ids[i];
                 let amount = amounts[i];
-                
+
                 // Check balance
                 let from_balance = self.balances.get((&from, &token_id)).unwrap_or(0);
                 if from_balance < amount {
                     return Err(Error::Unauthorized);
                 }
-                
+
                 // Update balances
-                self.balances.insert((&from, &token_id), &(from_balance - amount));
+                self.balances
+                    .insert((&from, &token_id), &(from_balance - amount));
                 let to_balance = self.balances.get((&to, &token_id)).unwrap_or(0);
-                self.balances.insert((&to, &token_id), &(to_balance + amount));
+                self.balances
+                    .insert((&to, &token_id), &(to_balance + amount));
             }
-            
+
             // Emit transfer events for each token
             for i in 0..ids.len() {
                 self.env().emit_event(Transfer {
@@ -394,107 +592,209 @@ mod property_token {
         pub fn uri(&self, token_id: TokenId) -> Option<String> {
             // Return a standard URI format for the token metadata
             let property_info = self.token_properties.get(&token_id)?;
-            Some(format!("ipfs://property/{}/{}/metadata.json", self.env().account_id(), token_id))
+            Some(format!(
+                "ipfs://property/{}/{}/metadata.json",
+                self.env().account_id(),
+                token_id
+            ))
         }
 
         /// Property-specific: Registers a property and mints a token
         #[ink(message)]
-        pub fn register_property_with_token(&mut self, metadata: PropertyMetadata) -> Result<TokenId, Error> {
+        pub fn register_property_with_token(
+            &mut self,
+            metadata: PropertyMetadata,
+        ) -> Result<TokenId, Error> {
             let caller = self.env().caller();
-            
+
+            // Minter must be compliant if a compliance registry is configured
+            self.ensure_compliant(caller)?;
+
             // Register property in the property registry (simulated here)
             // In a real implementation, this might call an external contract
-            
+
             // Mint a new token
             self.token_counter += 1;
             let token_id = self.token_counter;
-            
-            // Store property information
-            let property_info = PropertyInfo {
-                id: token_id, // Using token_id as property id for this implementation
-                owner: caller,
-                metadata: metadata.clone(),
-                registered_at: self.env().block_timestamp(),
-            };
-            
-            self.token_owner.insert(&token_id, &caller);
+
+            self.store_minted_token(caller, token_id, metadata);
             self.add_token_to_owner(caller, token_id)?;
-            
-            // Initialize balances
-            self.balances.insert((&caller, &token_id), &1u128);
-            
-            // Store property-specific information
-            self.token_properties.insert(&token_id, &property_info);
-            self.property_tokens.insert(&token_id, &token_id); // property_id maps to token_id
-            
-            // Initialize ownership history
-            let initial_transfer = OwnershipTransfer {
-                from: AccountId::from([0u8; 32]), // Zero address for minting
-                to: caller,
-                timestamp: self.env().block_timestamp(),
-                transaction_hash: self.env().hash_encoded(&(&caller, token_id)),
-            };
-            
-            self.ownership_history.insert(&token_id, &vec![initial_transfer]);
-            
-            // Initialize compliance as unverified
-            let compliance_info = ComplianceInfo {
-                verified: false,
-                verification_date: 0,
-                verifier: AccountId::from([0u8; 32]),
-                compliance_type: String::from("KYC"),
-            };
-            self.compliance_flags.insert(&token_id, &compliance_info);
-            
-            // Initialize legal documents vector
-            self.legal_documents.insert(&token_id, &Vec::new());
-            
             self.total_supply += 1;
-            
+
             self.env().emit_event(PropertyTokenMinted {
                 token_id,
                 property_id: token_id,
                 owner: caller,
             });
-            
+
             Ok(token_id)
         }
 
+        /// Property-specific: Registers several properties and mints a token for each
+        /// Token IDs are sequential; counts are updated once for the whole batch
+        #[ink(message)]
+        pub fn batch_register_property_with_token(
+            &mut self,
+            metadatas: Vec<PropertyMetadata>,
+        ) -> Result<Vec<TokenId>, Error> {
+            let caller = self.env().caller();
+
+            // Minter must be compliant if a compliance registry is configured
+            self.ensure_compliant(caller)?;
+
+            let start_id = self.token_counter + 1;
+            let count = metadatas.len() as u64;
+
+            let mut token_ids = Vec::new();
+            for (i, metadata) in metadatas.into_iter().enumerate() {
+                let token_id = start_id + i as u64;
+                self.store_minted_token(caller, token_id, metadata);
+                token_ids.push(token_id);
+            }
+
+            // Update counters once at the end
+            self.token_counter += count;
+            self.total_supply += count;
+            let owned = self.owner_token_count.get(&caller).unwrap_or(0);
+            self.owner_token_count
+                .insert(&caller, &(owned + count as u32));
+
+            for &token_id in &token_ids {
+                self.env().emit_event(PropertyTokenMinted {
+                    token_id,
+                    property_id: token_id,
+                    owner: caller,
+                });
+            }
+            self.env().emit_event(BatchPropertyTokensMinted {
+                owner: caller,
+                token_ids: token_ids.clone(),
+                count,
+            });
+
+            Ok(token_ids)
+        }
+
         /// Property-specific: Attaches a legal document to a token
         #[ink(message)]
-        pub fn attach_legal_document(&mut self, token_id: TokenId, document_hash: Hash, document_type: String) -> Result<(), Error> {
+        pub fn attach_legal_document(
+            &mut self,
+            token_id: TokenId,
+            document_hash: Hash,
+            document_type: String,
+        ) -> Result<(), Error> {
             let caller = self.env().caller();
-            let token_owner = self.token_owner.get(&token_id).ok_or(Error::TokenNotFound)?;
-            
+            let token_owner = self
+                .token_owner
+                .get(&token_id)
+                .ok_or(Error::TokenNotFound)?;
+
             if token_owner != caller {
                 return Err(Error::Unauthorized);
             }
-            
+
             // Get existing documents
             let mut documents = self.legal_documents.get(&token_id).unwrap_or(Vec::new());
-            
+
             // Add new document
             let document_info = DocumentInfo {
                 document_hash,
                 document_type: document_type.clone(),
                 upload_date: self.env().block_timestamp(),
                 uploader: caller,
+                verified: false,
             };
-            
+
             documents.push(document_info);
-            
+
             // Save updated documents
             self.legal_documents.insert(&token_id, &documents);
-            
+
             self.env().emit_event(LegalDocumentAttached {
                 token_id,
                 document_hash,
                 document_type,
             });
-            
+
+            Ok(())
+        }
+
+        /// Property-specific: Removes a legal document from a token
+        #[ink(message)]
+        pub fn remove_legal_document(
+            &mut self,
+            token_id: TokenId,
+            document_hash: Hash,
+        ) -> Result<(), Error> {
+            let caller = self.env().caller();
+            let token_owner = self
+                .token_owner
+                .get(&token_id)
+                .ok_or(Error::TokenNotFound)?;
+
+            if token_owner != caller {
+                return Err(Error::Unauthorized);
+            }
+
+            let mut documents = self.legal_documents.get(&token_id).unwrap_or(Vec::new());
+            let position = documents
+                .iter()
+                .position(|doc| doc.document_hash == document_hash)
+                .ok_or(Error::DocumentNotFound)?;
+
+            documents.remove(position);
+            self.legal_documents.insert(&token_id, &documents);
+
+            self.env().emit_event(LegalDocumentRemoved {
+                token_id,
+                document_hash,
+                removed_by: caller,
+            });
+
+            Ok(())
+        }
+
+        /// Property-specific: Marks a legal document attached to a token as verified
+        #[ink(message)]
+        pub fn verify_legal_document(
+            &mut self,
+            token_id: TokenId,
+            document_hash: Hash,
+        ) -> Result<(), Error> {
+            let caller = self.env().caller();
+
+            // Only admin or bridge operators can verify documents
+            if caller != self.admin && !self.bridge_operators.contains(&caller) {
+                return Err(Error::Unauthorized);
+            }
+
+            let mut documents = self
+                .legal_documents
+                .get(&token_id)
+                .ok_or(Error::TokenNotFound)?;
+            let document = documents
+                .iter_mut()
+                .find(|doc| doc.document_hash == document_hash)
+                .ok_or(Error::DocumentNotFound)?;
+
+            document.verified = true;
+            self.legal_documents.insert(&token_id, &documents);
+
+            self.env().emit_event(ComplianceVerified {
+                token_id,
+                verified: true,
+                verifier: caller,
+            });
+
             Ok(())
         }
 
+        /// Property-specific: Gets the legal documents attached to a token
+        #[ink(message)]
+        pub fn get_legal_documents(&self, token_id: TokenId) -> Vec<DocumentInfo> {
+            self.legal_documents.get(&token_id).unwrap_or_default()
+        }
+
         /// Property-specific: Verifies compliance for a token
         #[ink(message)]
         pub fn verify_compliance(&mut self, token_id: TokenId, verification_status: bool) -> Result<(), Error> {
@@ -527,60 +827,343 @@ mod property_token {
             self.ownership_history.get(&token_id)
         }
 
+        /// Property-specific: Gets a page of a token's ownership history, oldest first
+        #[ink(message)]
+        pub fn get_ownership_history_paged(
+            &self,
+            token_id: TokenId,
+            start: u32,
+            limit: u32,
+        ) -> Vec<OwnershipTransfer> {
+            self.ownership_history
+                .get(&token_id)
+                .unwrap_or_default()
+                .into_iter()
+                .skip(start as usize)
+                .take(limit as usize)
+                .collect()
+        }
+
+        /// Property-specific: Gets the number of ownership history entries for a token
+        #[ink(message)]
+        pub fn ownership_history_len(&self, token_id: TokenId) -> u32 {
+            self.ownership_history
+                .get(&token_id)
+                .map(|history| history.len() as u32)
+                .unwrap_or(0)
+        }
+
         /// Cross-chain: Initiates token bridging to another chain
         #[ink(message)]
-        pub fn bridge_to_chain(&mut self, destination_chain: ChainId, token_id: TokenId, recipient: AccountId) -> Result<(), Error> {
+        pub fn bridge_to_chain(
+            &mut self,
+            destination_chain: ChainId,
+            token_id: TokenId,
+            recipient: AccountId,
+        ) -> Result<(), Error> {
             let caller = self.env().caller();
-            let token_owner = self.token_owner.get(&token_id).ok_or(Error::TokenNotFound)?;
-            
-            if token_owner != caller {
+            let token_owner = self
+                .token_owner
+                .get(&token_id)
+                .ok_or(Error::TokenNotFound)?;
+
+            // Checks: a token can only be bridged out once
+            if self.bridge_locks.contains(&token_id) {
+                return Err(Error::BridgeLocked);
+            }
+
+            if token_owner != caller {
                 return Err(Error::Unauthorized);
             }
-            
+
             // Check compliance before bridging
-            let compliance_info = self.compliance_flags.get(&token_id).ok_or(Error::ComplianceFailed)?;
+            let compliance_info = self
+                .compliance_flags
+                .get(&token_id)
+                .ok_or(Error::ComplianceFailed)?;
             if !compliance_info.verified {
                 return Err(Error::ComplianceFailed);
             }
-            
-            // Lock the token for bridging
-            self.balances.insert((&token_owner, &token_id), &0u128);
-            self.token_owner.insert(&token_id, &AccountId::from([0u8; 32])); // Set to zero address while locked
-            
-            // Record bridging info
-            let bridged_info = BridgedTokenInfo {
+
+            // Effects: record the bridge as pending, lock the token, then mark it locked
+            let mut bridged_info = BridgedTokenInfo {
                 original_chain: 1, // Current chain ID
                 original_token_id: token_id,
                 destination_chain,
                 destination_token_id: token_id, // Same token ID on destination
                 bridged_at: self.env().block_timestamp(),
-                status: BridgingStatus::Locked,
+                status: BridgingStatus::Pending,
             };
-            
-            self.bridged_tokens.insert((&destination_chain, &token_id), &bridged_info);
-            
+            self.bridged_tokens
+                .insert((&destination_chain, &token_id), &bridged_info);
+
+            self.bridge_locks.insert(&token_id, &destination_chain);
+            self.balances.insert((&token_owner, &token_id), &0u128);
+            self.token_owner
+                .insert(&token_id, &AccountId::from([0u8; 32])); // Set to zero address while locked
+
+            bridged_info.status = BridgingStatus::Locked;
+            self.bridged_tokens
+                .insert((&destination_chain, &token_id), &bridged_info);
+            self.add_pending_bridge((destination_chain, token_id));
+
+            // Interactions: only notify once all state is written
             self.env().emit_event(TokenBridged {
                 token_id,
                 destination_chain,
                 recipient,
             });
-            
+
             Ok(())
         }
 
         /// Cross-chain: Receives a bridged token from another chain
+        ///
+        /// Counts as the caller's approval; the token is minted once
+        /// `required_bridge_signatures` operators have approved it.
+        #[ink(message)]
+        pub fn receive_bridged_token(
+            &mut self,
+            source_chain: ChainId,
+            original_token_id: TokenId,
+            recipient: AccountId,
+        ) -> Result<(), Error> {
+            self.approve_bridge_receive(source_chain, original_token_id, recipient)
+        }
+
+        /// Cross-chain: Approves minting a bridged token, minting it once enough operators agree
         #[ink(message)]
-        pub fn receive_bridged_token(&mut self, source_chain: ChainId, original_token_id: TokenId, recipient: AccountId) -> Result<(), Error> {
+        pub fn approve_bridge_receive(
+            &mut self,
+            source_chain: ChainId,
+            original_token_id: TokenId,
+            recipient: AccountId,
+        ) -> Result<(), Error> {
             // Only bridge operators can receive bridged tokens
             let caller = self.env().caller();
             if !self.bridge_operators.contains(&caller) {
                 return Err(Error::Unauthorized);
             }
+
+            // Each source transfer can only be minted once
+            if self
+                .processed_bridge_receives
+                .contains((&source_chain, &original_token_id))
+            {
+                return Err(Error::BridgeAlreadyProcessed);
+            }
+
+            let key = (source_chain, original_token_id, recipient);
+            let mut approvals = self.bridge_receive_approvals.get(&key).unwrap_or_default();
+            if approvals.contains(&caller) {
+                return Err(Error::BridgeAlreadyApproved);
+            }
+            approvals.push(caller);
+
+            // Approvals from operators that have since been removed no longer count
+            let approval_count = approvals
+                .iter()
+                .filter(|op| self.bridge_operators.contains(op))
+                .count() as u32;
+
+            self.env().emit_event(BridgeReceiveApproved {
+                source_chain,
+                original_token_id,
+                operator: caller,
+                recipient,
+                approvals: approval_count,
+            });
+
+            if approval_count < self.required_bridge_signatures {
+                self.bridge_receive_approvals.insert(&key, &approvals);
+                return Ok(());
+            }
+
+            // Recipient must be compliant if a compliance registry is configured
+            self.ensure_compliant(recipient)?;
+
+            self.bridge_receive_approvals.remove(&key);
+            self.processed_bridge_receives
+                .insert((&source_chain, &original_token_id), &true);
+            self.mint_bridged_token(source_chain, original_token_id, recipient, caller)
+        }
+
+        /// Cross-chain: Gets the operators that approved a pending bridge receive
+        #[ink(message)]
+        pub fn get_bridge_receive_approvals(
+            &self,
+            source_chain: ChainId,
+            original_token_id: TokenId,
+            recipient: AccountId,
+        ) -> Vec<AccountId> {
+            self.bridge_receive_approvals
+                .get((source_chain, original_token_id, recipient))
+                .unwrap_or_default()
+        }
+
+        /// Sets how many distinct operators must approve a bridge receive (admin only)
+        #[ink(message)]
+        pub fn set_required_bridge_signatures(&mut self, required: u32) -> Result<(), Error> {
+            let caller = self.env().caller();
+            if caller != self.admin {
+                return Err(Error::Unauthorized);
+            }
+
+            if required == 0 || required as usize > self.bridge_operators.len() {
+                return Err(Error::InvalidThreshold);
+            }
+
+            self.required_bridge_signatures = required;
+            Ok(())
+        }
+
+        /// Returns how many operator approvals a bridge receive needs
+        #[ink(message)]
+        pub fn get_required_bridge_signatures(&self) -> u32 {
+            self.required_bridge_signatures
+        }
+
+        /// Cross-chain: Gets the destination chain a token is locked for, if bridged out
+        #[ink(message)]
+        pub fn get_bridge_lock(&self, token_id: TokenId) -> Option<ChainId> {
+            self.bridge_locks.get(&token_id)
+        }
+
+        /// Cross-chain: Gets the bridging record for a token and destination chain
+        #[ink(message)]
+        pub fn get_bridged_token_info(
+            &self,
+            destination_chain: ChainId,
+            token_id: TokenId,
+        ) -> Option<BridgedTokenInfo> {
+            self.bridged_tokens.get((&destination_chain, &token_id))
+        }
+
+        /// Cross-chain: Lists bridges that have not completed yet, paginated
+        #[ink(message)]
+        pub fn get_pending_bridges(&self, start: u32, limit: u32) -> Vec<(ChainId, TokenId)> {
+            let end = start.saturating_add(limit).min(self.pending_bridge_count);
+            (start..end)
+                .filter_map(|slot| self.pending_bridges.get(&slot))
+                .collect()
+        }
+
+        /// Adds a bridge operator
+        #[ink(message)]
+        pub fn add_bridge_operator(&mut self, operator: AccountId) -> Result<(), Error> {
+            let caller = self.env().caller();
+            if caller != self.admin {
+                return Err(Error::Unauthorized);
+            }
+
+            if !self.bridge_operators.contains(&operator) {
+                self.bridge_operators.push(operator);
+            }
+
+            Ok(())
+        }
+
+        /// Removes a bridge operator
+        #[ink(message)]
+        pub fn remove_bridge_operator(&mut self, operator: AccountId) -> Result<(), Error> {
+            let caller = self.env().caller();
+            if caller != self.admin {
+                return Err(Error::Unauthorized);
+            }
             
+            self.bridge_operators.retain(|op| op != &operator);
+            Ok(())
+        }
+
+        /// Returns the total supply of tokens
+        #[ink(message)]
+        pub fn total_supply(&self) -> u64 {
+            self.total_supply
+        }
+
+        /// Returns the current token counter
+        #[ink(message)]
+        pub fn current_token_id(&self) -> TokenId {
+            self.token_counter
+        }
+
+        /// Returns the admin account
+        #[ink(message)]
+        pub fn admin(&self) -> AccountId {
+            self.admin
+        }
+
+        /// Sets the compliance registry consulted on transfers (admin only)
+        #[ink(message)]
+        pub fn set_compliance_registry(
+            &mut self,
+            registry: Option<AccountId>,
+        ) -> Result<(), Error> {
+            let caller = self.env().caller();
+            if caller != self.admin {
+                return Err(Error::Unauthorized);
+            }
+
+            self.compliance_registry = registry;
+            Ok(())
+        }
+
+        /// Returns the compliance registry address, if set
+        #[ink(message)]
+        pub fn get_compliance_registry(&self) -> Option<AccountId> {
+            self.compliance_registry
+        }
+
+        /// Internal helper to require an account to be compliant
+        /// Passes when no compliance registry is configured
+        fn ensure_compliant(&self, account: AccountId) -> Result<(), Error> {
+            let Some(registry) = self.compliance_registry else {
+                return Ok(());
+            };
+
+            if !self.query_compliance(registry, account) {
+                return Err(Error::ComplianceFailed);
+            }
+
+            Ok(())
+        }
+
+        /// Internal helper to call `is_compliant` on the compliance registry
+        /// A failed call is treated as non-compliant
+        fn query_compliance(&self, registry: AccountId, account: AccountId) -> bool {
+            Calls::is_compliant(registry, account).unwrap_or(false)
+        }
+
+        /// Internal helper to check whether an account is a contract
+        fn is_contract(&self, account: AccountId) -> bool {
+            self.env().is_contract(&account)
+        }
+
+        /// Internal helper to call `on_property_received` on a receiving contract
+        /// Returns None if the hook could not be called
+        fn notify_receiver(
+            &self,
+            receiver: AccountId,
+            operator: AccountId,
+            from: AccountId,
+            token_id: TokenId,
+            data: Vec<u8>,
+        ) -> Option<[u8; 4]> {
+            Calls::on_property_received(receiver, operator, from, token_id, data).ok()
+        }
+
+        /// Mints the bridged copy of a source token once its receive is approved
+        fn mint_bridged_token(
+            &mut self,
+            source_chain: ChainId,
+            original_token_id: TokenId,
+            recipient: AccountId,
+            caller: AccountId,
+        ) -> Result<(), Error> {
             // Create a new token for the recipient
             self.token_counter += 1;
             let new_token_id = self.token_counter;
-            
+
             // Copy property information from the original token (if available)
             if let Some(original_property) = self.token_properties.get(&original_token_id) {
                 let mut new_property = original_property.clone();
@@ -594,23 +1177,25 @@ mod property_token {
                     legal_description: String::from("Property bridged from another chain"),
                     valuation: 0,
                     documents_url: String::from(""),
+                    latitude: None,
+                    longitude: None,
                 };
-                
+
                 let new_property = PropertyInfo {
                     id: new_token_id,
                     owner: recipient,
                     metadata: basic_metadata,
                     registered_at: self.env().block_timestamp(),
                 };
-                
+
                 self.token_properties.insert(&new_token_id, &new_property);
             }
-            
+
             // Set ownership
             self.token_owner.insert(&new_token_id, &recipient);
             self.add_token_to_owner(recipient, new_token_id)?;
             self.balances.insert((&recipient, &new_token_id), &1u128);
-            
+
             // Initialize ownership history for the new token
             let initial_transfer = OwnershipTransfer {
                 from: AccountId::from([0u8; 32]), // Zero address for minting
@@ -618,12 +1203,14 @@ mod property_token {
                 timestamp: self.env().block_timestamp(),
                 transaction_hash: self.env().hash_encoded(&(&recipient, new_token_id)),
             };
-            
-            self.ownership_history.insert(&new_token_id, &vec![initial_transfer]);
-            
+
+            self.ownership_history
+                .insert(&new_token_id, &vec![initial_transfer]);
+
             // Copy compliance info
             if let Some(original_compliance) = self.compliance_flags.get(&original_token_id) {
-                self.compliance_flags.insert(&new_token_id, &original_compliance);
+                self.compliance_flags
+                    .insert(&new_token_id, &original_compliance);
             } else {
                 let compliance_info = ComplianceInfo {
                     verified: true, // Assume verified when bridging
@@ -631,77 +1218,111 @@ mod property_token {
                     verifier: caller,
                     compliance_type: String::from("Bridge"),
                 };
-                self.compliance_flags.insert(&new_token_id, &compliance_info);
+                self.compliance_flags
+                    .insert(&new_token_id, &compliance_info);
             }
-            
+
             // Copy legal documents
             if let Some(original_docs) = self.legal_documents.get(&original_token_id) {
                 self.legal_documents.insert(&new_token_id, &original_docs);
             } else {
                 self.legal_documents.insert(&new_token_id, &Vec::new());
             }
-            
+
             self.total_supply += 1;
-            
+
             // Update the bridged token status
-            if let Some(mut bridged_info) = self.bridged_tokens.get((&source_chain, &original_token_id)) {
+            if let Some(mut bridged_info) =
+                self.bridged_tokens.get((&source_chain, &original_token_id))
+            {
                 bridged_info.status = BridgingStatus::Completed;
                 bridged_info.destination_token_id = new_token_id;
-                self.bridged_tokens.insert((&source_chain, &original_token_id), &bridged_info);
+                self.bridged_tokens
+                    .insert((&source_chain, &original_token_id), &bridged_info);
             }
-            
+            self.remove_pending_bridge((source_chain, original_token_id));
+
             self.env().emit_event(Transfer {
                 from: None, // None indicates minting
                 to: Some(recipient),
                 id: new_token_id,
             });
-            
+
             Ok(())
         }
 
-        /// Adds a bridge operator
-        #[ink(message)]
-        pub fn add_bridge_operator(&mut self, operator: AccountId) -> Result<(), Error> {
-            let caller = self.env().caller();
-            if caller != self.admin {
-                return Err(Error::Unauthorized);
-            }
-            
-            if !self.bridge_operators.contains(&operator) {
-                self.bridge_operators.push(operator);
-            }
-            
-            Ok(())
+        /// Internal helper to append a bridge to the pending index
+        fn add_pending_bridge(&mut self, bridge: (ChainId, TokenId)) {
+            let slot = self.pending_bridge_count;
+            self.pending_bridges.insert(&slot, &bridge);
+            self.pending_bridge_positions.insert(&bridge, &slot);
+            self.pending_bridge_count = slot + 1;
         }
 
-        /// Removes a bridge operator
-        #[ink(message)]
-        pub fn remove_bridge_operator(&mut self, operator: AccountId) -> Result<(), Error> {
-            let caller = self.env().caller();
-            if caller != self.admin {
-                return Err(Error::Unauthorized);
+        /// Internal helper to remove a bridge from the pending index
+        /// Moves the last pending bridge into the freed slot
+        fn remove_pending_bridge(&mut self, bridge: (ChainId, TokenId)) {
+            let Some(slot) = self.pending_bridge_positions.take(&bridge) else {
+                return;
+            };
+            let last = self.pending_bridge_count - 1;
+            if slot != last {
+                if let Some(moved) = self.pending_bridges.get(&last) {
+                    self.pending_bridges.insert(&slot, &moved);
+                    self.pending_bridge_positions.insert(&moved, &slot);
+                }
             }
-            
-            self.bridge_operators.retain(|op| op != &operator);
-            Ok(())
+            self.pending_bridges.remove(&last);
+            self.pending_bridge_count = last;
         }
 
-        /// Returns the total supply of tokens
-        #[ink(message)]
-        pub fn total_supply(&self) -> u64 {
-            self.total_supply
-        }
+        /// Internal helper to store a newly minted token and its property records
+        /// Does not update owner counts or total supply
+        fn store_minted_token(
+            &mut self,
+            owner: AccountId,
+            token_id: TokenId,
+            metadata: PropertyMetadata,
+        ) {
+            // Store property information
+            let property_info = PropertyInfo {
+                id: token_id, // Using token_id as property id for this implementation
+                owner,
+                metadata,
+                registered_at: self.env().block_timestamp(),
+            };
 
-        /// Returns the current token counter
-        #[ink(message)]
-        pub fn current_token_id(&self) -> TokenId {
-            self.token_counter
-        }
+            self.token_owner.insert(&token_id, &owner);
 
-        /// Returns the admin account
-        #[ink(message)]
-        pub fn admin(&self) -> AccountId {
-            self.admin
+            // Initialize balances
+            self.balances.insert((&owner, &token_id), &1u128);
+
+            // Store property-specific information
+            self.token_properties.insert(&token_id, &property_info);
+            self.property_tokens.insert(&token_id, &token_id); // property_id maps to token_id
+
+            // Initialize ownership history
+            let initial_transfer = OwnershipTransfer {
+                from: AccountId::from([0u8; 32]), // Zero address for minting
+                to: owner,
+                timestamp: self.env().block_timestamp(),
+                transaction_hash: self.env().hash_encoded(&(&owner, token_id)),
+            };
+
+            self.ownership_history
+                .insert(&token_id, &vec![initial_transfer]);
+
+            // Initialize compliance as unverified
+            let compliance_info = ComplianceInfo {
+                verified: false,
+                verification_date: 0,
+                verifier: AccountId::from([0u8; 32]),
+                compliance_type: String::from("KYC"),
+            };
+            self.compliance_flags.insert(&token_id, &compliance_info);
+
+            // Initialize legal documents vector
+            self.legal_documents.insert(&token_id, &Vec::new());
         }
 
         /// Internal helper to add a token to an owner
@@ -746,10 +1367,76 @@ mod property_token {
         use super::*;
         use ink::env::{DefaultEnvironment, test};
 
+        use propchain_traits::compliance_client::CallError;
+        use std::cell::RefCell;
+
         fn setup_contract() -> PropertyToken {
+            MockCalls::reset();
             PropertyToken::new()
         }
 
+        thread_local! {
+            // (registry, account) pairs the registry reports as compliant
+            static COMPLIANT: RefCell<Vec<(AccountId, AccountId)>> = RefCell::new(Vec::new());
+            // Receiver contract and the hook's answer (None = hook not implemented)
+            static RECEIVERS: RefCell<Vec<(AccountId, Option<[u8; 4]>)>> = RefCell::new(Vec::new());
+        }
+
+        /// Answers cross-contract calls for contracts registered in the off-chain environment
+        /// Calls to accounts that are not registered as contracts fail like they would on-chain
+        pub struct MockCalls;
+
+        impl MockCalls {
+            /// Forgets every registry answer and receiver hook
+            fn reset() {
+                COMPLIANT.with(|c| c.borrow_mut().clear());
+                RECEIVERS.with(|r| r.borrow_mut().clear());
+            }
+
+            /// Deploys a compliance registry at `registry`
+            fn deploy_registry(registry: AccountId) {
+                test::set_contract::<DefaultEnvironment>(registry);
+            }
+
+            fn set_compliant(registry: AccountId, account: AccountId) {
+                COMPLIANT.with(|c| c.borrow_mut().push((registry, account)));
+            }
+
+            /// Deploys a receiver contract at `account` whose hook answers `response`
+            fn set_receiver(account: AccountId, response: Option<[u8; 4]>) {
+                test::set_contract::<DefaultEnvironment>(account);
+                RECEIVERS.with(|r| r.borrow_mut().push((account, response)));
+            }
+        }
+
+        impl ExternalCalls for MockCalls {
+            fn is_compliant(registry: AccountId, account: AccountId) -> Result<bool, CallError> {
+                if !test::is_contract::<DefaultEnvironment>(registry) {
+                    return Err(CallError::CallFailed);
+                }
+                Ok(COMPLIANT.with(|c| c.borrow().contains(&(registry, account))))
+            }
+
+            fn on_property_received(
+                receiver: AccountId,
+                _operator: AccountId,
+                _from: AccountId,
+                _token_id: TokenId,
+                _data: Vec<u8>,
+            ) -> Result<[u8; 4], CallError> {
+                if !test::is_contract::<DefaultEnvironment>(receiver) {
+                    return Err(CallError::CallFailed);
+                }
+                RECEIVERS.with(|r| {
+                    r.borrow()
+                        .iter()
+                        .find(|(a, _)| *a == receiver)
+                        .and_then(|(_, response)| *response)
+                        .ok_or(CallError::DispatchFailed)
+                })
+            }
+        }
+
         #[ink::test]
         fn test_constructor_works() {
             let contract = setup_contract();
@@ -767,6 +1454,8 @@ mod property_token {
                 legal_description: String::from("Sample property"),
                 valuation: 500000,
                 documents_url: String::from("ipfs://sample-docs"),
+                latitude: None,
+                longitude: None,
             };
             
             let result = contract.register_property_with_token(metadata.clone());
@@ -778,15 +1467,76 @@ mod property_token {
         }
 
         #[ink::test]
-        fn test_balance_of() {
+        fn test_batch_register_property_with_token() {
             let mut contract = setup_contract();
-            
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+
+            // An existing token shifts the batch IDs
+            let first = contract
+                .register_property_with_token(PropertyMetadata {
+                    location: String::from("1 First St"),
+                    size: 500,
+                    legal_description: String::from("Existing property"),
+                    valuation: 100000,
+                    documents_url: String::from("ipfs://existing-docs"),
+                    latitude: None,
+                    longitude: None,
+                })
+                .unwrap();
+
+            let metadatas: Vec<PropertyMetadata> = (1..=3)
+                .map(|unit| PropertyMetadata {
+                    location: format!("Unit {}, 10 Tower Rd", unit),
+                    size: 900,
+                    legal_description: String::from("Development unit"),
+                    valuation: 250000,
+                    documents_url: String::from("ipfs://development-docs"),
+                    latitude: None,
+                    longitude: None,
+                })
+                .collect();
+
+            let token_ids = contract
+                .batch_register_property_with_token(metadatas)
+                .unwrap();
+            assert_eq!(token_ids, vec![first + 1, first + 2, first + 3]);
+
+            assert_eq!(contract.total_supply(), 4);
+            assert_eq!(contract.current_token_id(), 4);
+            assert_eq!(contract.balance_of(accounts.alice), 4);
+            for &token_id in &token_ids {
+                assert_eq!(contract.owner_of(token_id), Some(accounts.alice));
+                assert_eq!(
+                    contract.balance_of_batch(vec![accounts.alice], vec![token_id]),
+                    vec![1]
+                );
+            }
+            assert_eq!(
+                contract
+                    .token_properties
+                    .get(&token_ids[2])
+                    .unwrap()
+                    .metadata
+                    .location,
+                "Unit 3, 10 Tower Rd"
+            );
+
+            // One PropertyTokenMinted per token plus the batch event
+            assert_eq!(test::recorded_events().count(), 1 + 3 + 1);
+        }
+
+        #[ink::test]
+        fn test_balance_of() {
+            let mut contract = setup_contract();
+
             let metadata = PropertyMetadata {
                 location: String::from("123 Main St"),
                 size: 1000,
                 legal_description: String::from("Sample property"),
                 valuation: 500000,
                 documents_url: String::from("ipfs://sample-docs"),
+                latitude: None,
+                longitude: None,
             };
             
             let token_id = contract.register_property_with_token(metadata).unwrap();
@@ -799,16 +1549,199 @@ mod property_token {
             assert_eq!(contract.balance_of(accounts.alice), 1);
         }
 
+        #[ink::test]
+        fn test_transfer_from_updates_owner_and_balances() {
+            let mut contract = setup_contract();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+
+            let metadata = PropertyMetadata {
+                location: String::from("123 Main St"),
+                size: 1000,
+                legal_description: String::from("Sample property"),
+                valuation: 500000,
+                documents_url: String::from("ipfs://sample-docs"),
+                latitude: None,
+                longitude: None,
+            };
+
+            let token_id = contract.register_property_with_token(metadata).unwrap();
+
+            assert!(contract
+                .transfer_from(accounts.alice, accounts.bob, token_id)
+                .is_ok());
+
+            assert_eq!(contract.owner_of(token_id), Some(accounts.bob));
+            assert_eq!(contract.balance_of(accounts.alice), 0);
+            assert_eq!(contract.balance_of(accounts.bob), 1);
+            assert_eq!(
+                contract
+                    .balance_of_batch(vec![accounts.alice, accounts.bob], vec![token_id, token_id]),
+                vec![0, 1]
+            );
+
+            // The previous owner can no longer move the token
+            let result = contract.transfer_from(accounts.alice, accounts.charlie, token_id);
+            assert_eq!(result, Err(Error::Unauthorized));
+        }
+
+        #[ink::test]
+        fn test_ownership_history_paged() {
+            let mut contract = setup_contract();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+
+            let metadata = PropertyMetadata {
+                location: String::from("123 Main St"),
+                size: 1000,
+                legal_description: String::from("Sample property"),
+                valuation: 500000,
+                documents_url: String::from("ipfs://sample-docs"),
+                latitude: None,
+                longitude: None,
+            };
+
+            let token_id = contract.register_property_with_token(metadata).unwrap();
+
+            // Trade the token back and forth between alice and bob
+            let mut owner = accounts.alice;
+            for _ in 0..10 {
+                let next = if owner == accounts.alice {
+                    accounts.bob
+                } else {
+                    accounts.alice
+                };
+                test::set_caller::<DefaultEnvironment>(owner);
+                contract.transfer_from(owner, next, token_id).unwrap();
+                owner = next;
+            }
+
+            // Mint entry plus one per transfer
+            assert_eq!(contract.ownership_history_len(token_id), 11);
+            assert_eq!(contract.ownership_history_len(token_id + 1), 0);
+
+            let mut paged = Vec::new();
+            let mut start = 0;
+            loop {
+                let page = contract.get_ownership_history_paged(token_id, start, 3);
+                if page.is_empty() {
+                    break;
+                }
+                assert!(page.len() <= 3);
+                start += page.len() as u32;
+                paged.extend(page);
+            }
+
+            assert_eq!(paged, contract.get_ownership_history(token_id).unwrap());
+            assert_eq!(paged[0].to, accounts.alice);
+            assert_eq!(paged[10].from, accounts.bob);
+            assert_eq!(paged[10].to, accounts.alice);
+            assert!(contract
+                .get_ownership_history_paged(token_id, 11, 3)
+                .is_empty());
+        }
+
+        #[ink::test]
+        fn test_safe_transfer_to_accepting_receiver() {
+            let mut contract = setup_contract();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+
+            let metadata = PropertyMetadata {
+                location: String::from("123 Main St"),
+                size: 1000,
+                legal_description: String::from("Sample property"),
+                valuation: 500000,
+                documents_url: String::from("ipfs://sample-docs"),
+                latitude: None,
+                longitude: None,
+            };
+
+            let token_id = contract.register_property_with_token(metadata).unwrap();
+
+            let receiver = accounts.django;
+            MockCalls::set_receiver(receiver, Some(receiver_client::ON_PROPERTY_RECEIVED_MAGIC));
+
+            assert!(contract
+                .safe_transfer_from(accounts.alice, receiver, token_id, vec![1, 2, 3])
+                .is_ok());
+            assert_eq!(contract.owner_of(token_id), Some(receiver));
+
+            // Plain accounts need no hook
+            let token_id = contract
+                .register_property_with_token(PropertyMetadata {
+                    location: String::from("456 Oak Ave"),
+                    size: 800,
+                    legal_description: String::from("Second property"),
+                    valuation: 300000,
+                    documents_url: String::from("ipfs://second-docs"),
+                    latitude: None,
+                    longitude: None,
+                })
+                .unwrap();
+            assert!(contract
+                .safe_transfer_from(accounts.alice, accounts.bob, token_id, Vec::new())
+                .is_ok());
+            assert_eq!(contract.owner_of(token_id), Some(accounts.bob));
+        }
+
+        #[ink::test]
+        fn test_safe_transfer_to_non_receiver_fails() {
+            let mut contract = setup_contract();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+
+            let metadata = PropertyMetadata {
+                location: String::from("123 Main St"),
+                size: 1000,
+                legal_description: String::from("Sample property"),
+                valuation: 500000,
+                documents_url: String::from("ipfs://sample-docs"),
+                latitude: None,
+                longitude: None,
+            };
+
+            let token_id = contract.register_property_with_token(metadata).unwrap();
+
+            // A contract answering with the wrong value rejects the token
+            let rejecting = accounts.eve;
+            MockCalls::set_receiver(rejecting, Some([0xde, 0xad, 0xbe, 0xef]));
+            let result =
+                contract.safe_transfer_from(accounts.alice, rejecting, token_id, Vec::new());
+            assert_eq!(result, Err(Error::TransferToNonReceiver));
+
+            // So does a contract without the hook
+            let mut contract = setup_contract();
+            let token_id = contract
+                .register_property_with_token(PropertyMetadata {
+                    location: String::from("456 Oak Ave"),
+                    size: 800,
+                    legal_description: String::from("Second property"),
+                    valuation: 300000,
+                    documents_url: String::from("ipfs://second-docs"),
+                    latitude: None,
+                    longitude: None,
+                })
+                .unwrap();
+            let no_hook = accounts.frank;
+            MockCalls::set_receiver(no_hook, None);
+            let result = contract.safe_transfer_from(accounts.alice, no_hook, token_id, Vec::new());
+            assert_eq!(result, Err(Error::TransferToNonReceiver));
+
+            // transfer_from itself does not consult the hook
+            assert!(contract
+                .transfer_from(accounts.alice, rejecting, token_id)
+                .is_ok());
+        }
+
         #[ink::test]
         fn test_attach_legal_document() {
             let mut contract = setup_contract();
-            
+
             let metadata = PropertyMetadata {
                 location: String::from("123 Main St"),
                 size: 1000,
                 legal_description: String::from("Sample property"),
                 valuation: 500000,
                 documents_url: String::from("ipfs://sample-docs"),
+                latitude: None,
+                longitude: None,
             };
             
             let token_id = contract.register_property_with_token(metadata).unwrap();
@@ -823,6 +1756,305 @@ mod property_token {
             assert!(result.is_ok());
         }
 
+        #[ink::test]
+        fn test_remove_legal_document() {
+            let mut contract = setup_contract();
+
+            let metadata = PropertyMetadata {
+                location: String::from("123 Main St"),
+                size: 1000,
+                legal_description: String::from("Sample property"),
+                valuation: 500000,
+                documents_url: String::from("ipfs://sample-docs"),
+                latitude: None,
+                longitude: None,
+            };
+
+            let token_id = contract.register_property_with_token(metadata).unwrap();
+
+            let doc_hash = Hash::from([1u8; 32]);
+            contract
+                .attach_legal_document(token_id, doc_hash, String::from("Deed"))
+                .unwrap();
+
+            assert!(contract.remove_legal_document(token_id, doc_hash).is_ok());
+            assert!(contract.get_legal_documents(token_id).is_empty());
+
+            // Removing a hash that is no longer attached fails
+            let result = contract.remove_legal_document(token_id, doc_hash);
+            assert_eq!(result, Err(Error::DocumentNotFound));
+        }
+
+        #[ink::test]
+        fn test_verify_legal_document() {
+            let mut contract = setup_contract();
+
+            let metadata = PropertyMetadata {
+                location: String::from("123 Main St"),
+                size: 1000,
+                legal_description: String::from("Sample property"),
+                valuation: 500000,
+                documents_url: String::from("ipfs://sample-docs"),
+                latitude: None,
+                longitude: None,
+            };
+
+            let token_id = contract.register_property_with_token(metadata).unwrap();
+
+            let doc_hash = Hash::from([1u8; 32]);
+            contract
+                .attach_legal_document(token_id, doc_hash, String::from("Deed"))
+                .unwrap();
+            assert!(!contract.get_legal_documents(token_id)[0].verified);
+
+            assert!(contract.verify_legal_document(token_id, doc_hash).is_ok());
+            assert!(contract.get_legal_documents(token_id)[0].verified);
+
+            // Non-admin callers cannot verify documents
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            let result = contract.verify_legal_document(token_id, doc_hash);
+            assert_eq!(result, Err(Error::Unauthorized));
+        }
+
+        #[ink::test]
+        fn test_transfer_gated_on_compliance_registry() {
+            let mut contract = setup_contract();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+
+            let metadata = PropertyMetadata {
+                location: String::from("123 Main St"),
+                size: 1000,
+                legal_description: String::from("Sample property"),
+                valuation: 500000,
+                documents_url: String::from("ipfs://sample-docs"),
+                latitude: None,
+                longitude: None,
+            };
+
+            let token_id = contract.register_property_with_token(metadata).unwrap();
+
+            let registry = accounts.django;
+            MockCalls::deploy_registry(registry);
+            assert!(contract.set_compliance_registry(Some(registry)).is_ok());
+            assert_eq!(contract.get_compliance_registry(), Some(registry));
+
+            // Bob is not compliant in the mock registry
+            let result = contract.transfer_from(accounts.alice, accounts.bob, token_id);
+            assert_eq!(result, Err(Error::ComplianceFailed));
+            let result = contract.safe_batch_transfer_from(
+                accounts.alice,
+                accounts.bob,
+                vec![token_id],
+                vec![1],
+                Vec::new(),
+            );
+            assert_eq!(result, Err(Error::ComplianceFailed));
+
+            // Once Bob is compliant the transfer goes through
+            MockCalls::set_compliant(registry, accounts.bob);
+            assert!(contract
+                .transfer_from(accounts.alice, accounts.bob, token_id)
+                .is_ok());
+        }
+
+        #[ink::test]
+        fn test_unreachable_compliance_registry_fails_closed() {
+            let mut contract = setup_contract();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+
+            // Nothing is deployed at the configured registry address, so the call fails
+            let registry = accounts.django;
+            MockCalls::set_compliant(registry, accounts.alice);
+            assert!(contract.set_compliance_registry(Some(registry)).is_ok());
+
+            let metadata = PropertyMetadata {
+                location: String::from("123 Main St"),
+                size: 1000,
+                legal_description: String::from("Sample property"),
+                valuation: 500000,
+                documents_url: String::from("ipfs://sample-docs"),
+                latitude: None,
+                longitude: None,
+            };
+            assert_eq!(
+                contract.register_property_with_token(metadata.clone()),
+                Err(Error::ComplianceFailed)
+            );
+
+            MockCalls::deploy_registry(registry);
+            assert!(contract.register_property_with_token(metadata).is_ok());
+        }
+
+        #[ink::test]
+        fn test_mint_gated_on_compliance_registry() {
+            let mut contract = setup_contract();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+
+            let registry = accounts.django;
+            MockCalls::deploy_registry(registry);
+            assert!(contract.set_compliance_registry(Some(registry)).is_ok());
+
+            let metadata = PropertyMetadata {
+                location: String::from("123 Main St"),
+                size: 1000,
+                legal_description: String::from("Sample property"),
+                valuation: 500000,
+                documents_url: String::from("ipfs://sample-docs"),
+                latitude: None,
+                longitude: None,
+            };
+
+            // Alice is not compliant in the mock registry
+            let result = contract.register_property_with_token(metadata.clone());
+            assert_eq!(result, Err(Error::ComplianceFailed));
+            assert_eq!(contract.total_supply(), 0);
+
+            // Bridged tokens are gated on the recipient
+            let result = contract.receive_bridged_token(2, 1, accounts.bob);
+            assert_eq!(result, Err(Error::ComplianceFailed));
+
+            MockCalls::set_compliant(registry, accounts.alice);
+            assert!(contract.register_property_with_token(metadata).is_ok());
+
+            MockCalls::set_compliant(registry, accounts.bob);
+            assert!(contract.receive_bridged_token(2, 1, accounts.bob).is_ok());
+        }
+
+        #[ink::test]
+        fn test_list_and_clear_operators() {
+            let mut contract = setup_contract();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+
+            assert!(contract.set_approval_for_all(accounts.bob, true).is_ok());
+            assert!(contract
+                .set_approval_for_all(accounts.charlie, true)
+                .is_ok());
+            // Re-approving does not duplicate the entry
+            assert!(contract.set_approval_for_all(accounts.bob, true).is_ok());
+            assert_eq!(
+                contract.get_operators(accounts.alice),
+                vec![accounts.bob, accounts.charlie]
+            );
+
+            // Revoking a single operator removes it from the list
+            assert!(contract.set_approval_for_all(accounts.bob, false).is_ok());
+            assert_eq!(
+                contract.get_operators(accounts.alice),
+                vec![accounts.charlie]
+            );
+            assert!(!contract.is_approved_for_all(accounts.alice, accounts.bob));
+
+            assert!(contract.set_approval_for_all(accounts.bob, true).is_ok());
+            assert!(contract.clear_all_operators().is_ok());
+            assert!(contract.get_operators(accounts.alice).is_empty());
+            assert!(!contract.is_approved_for_all(accounts.alice, accounts.bob));
+            assert!(!contract.is_approved_for_all(accounts.alice, accounts.charlie));
+        }
+
+        #[ink::test]
+        fn test_operator_can_transfer_single_and_batch() {
+            let mut contract = setup_contract();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+
+            let metadata = PropertyMetadata {
+                location: String::from("123 Main St"),
+                size: 1000,
+                legal_description: String::from("Sample property"),
+                valuation: 500000,
+                documents_url: String::from("ipfs://sample-docs"),
+                latitude: None,
+                longitude: None,
+            };
+            let single = contract
+                .register_property_with_token(metadata.clone())
+                .unwrap();
+            let batched = contract.register_property_with_token(metadata).unwrap();
+
+            assert!(contract.set_approval_for_all(accounts.bob, true).is_ok());
+
+            // Without approval Charlie can use neither path
+            test::set_caller::<DefaultEnvironment>(accounts.charlie);
+            assert_eq!(
+                contract.transfer_from(accounts.alice, accounts.charlie, single),
+                Err(Error::Unauthorized)
+            );
+            assert_eq!(
+                contract.safe_batch_transfer_from(
+                    accounts.alice,
+                    accounts.charlie,
+                    vec![batched],
+                    vec![1],
+                    Vec::new()
+                ),
+                Err(Error::Unauthorized)
+            );
+
+            // Bob's operator approval covers both the ERC-721 and ERC-1155 transfers
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert!(contract
+                .transfer_from(accounts.alice, accounts.charlie, single)
+                .is_ok());
+            assert_eq!(contract.owner_of(single), Some(accounts.charlie));
+
+            assert!(contract
+                .safe_batch_transfer_from(
+                    accounts.alice,
+                    accounts.charlie,
+                    vec![batched],
+                    vec![1],
+                    Vec::new()
+                )
+                .is_ok());
+            assert_eq!(
+                contract.balance_of_batch(
+                    vec![accounts.alice, accounts.charlie],
+                    vec![batched, batched]
+                ),
+                vec![0, 1]
+            );
+
+            // Revoking the approval closes both paths again
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let remaining = contract
+                .register_property_with_token(PropertyMetadata {
+                    location: String::from("456 Oak Ave"),
+                    size: 800,
+                    legal_description: String::from("Second property"),
+                    valuation: 300000,
+                    documents_url: String::from("ipfs://second-docs"),
+                    latitude: None,
+                    longitude: None,
+                })
+                .unwrap();
+            assert!(contract.set_approval_for_all(accounts.bob, false).is_ok());
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(
+                contract.transfer_from(accounts.alice, accounts.bob, remaining),
+                Err(Error::Unauthorized)
+            );
+            assert_eq!(
+                contract.safe_batch_transfer_from(
+                    accounts.alice,
+                    accounts.bob,
+                    vec![remaining],
+                    vec![1],
+                    Vec::new()
+                ),
+                Err(Error::Unauthorized)
+            );
+        }
+
+        #[ink::test]
+        fn test_set_compliance_registry_unauthorized() {
+            let mut contract = setup_contract();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            let result = contract.set_compliance_registry(Some(accounts.django));
+            assert_eq!(result, Err(Error::Unauthorized));
+        }
+
         #[ink::test]
         fn test_verify_compliance() {
             let mut contract = setup_contract();
@@ -833,6 +2065,8 @@ mod property_token {
                 legal_description: String::from("Sample property"),
                 valuation: 500000,
                 documents_url: String::from("ipfs://sample-docs"),
+                latitude: None,
+                longitude: None,
             };
             
             let token_id = contract.register_property_with_token(metadata).unwrap();
@@ -846,5 +2080,175 @@ mod property_token {
             let compliance_info = contract.compliance_flags.get(&token_id).unwrap();
             assert!(compliance_info.verified);
         }
+
+        #[ink::test]
+        fn test_bridge_to_chain_rejects_double_bridge() {
+            let mut contract = setup_contract();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+
+            let metadata = PropertyMetadata {
+                location: String::from("123 Main St"),
+                size: 1000,
+                legal_description: String::from("Sample property"),
+                valuation: 500000,
+                documents_url: String::from("ipfs://sample-docs"),
+                latitude: None,
+                longitude: None,
+            };
+            let token_id = contract.register_property_with_token(metadata).unwrap();
+            contract.verify_compliance(token_id, true).unwrap();
+
+            assert!(contract.bridge_to_chain(2, token_id, accounts.bob).is_ok());
+            assert_eq!(contract.get_bridge_lock(token_id), Some(2));
+            let info = contract.get_bridged_token_info(2, token_id).unwrap();
+            assert_eq!(info.status, BridgingStatus::Locked);
+
+            // Neither the same chain nor another one can take the token again
+            assert_eq!(
+                contract.bridge_to_chain(2, token_id, accounts.bob),
+                Err(Error::BridgeLocked)
+            );
+            assert_eq!(
+                contract.bridge_to_chain(3, token_id, accounts.bob),
+                Err(Error::BridgeLocked)
+            );
+            assert!(contract.get_bridged_token_info(3, token_id).is_none());
+        }
+
+        #[ink::test]
+        fn test_pending_bridges_track_completion() {
+            let mut contract = setup_contract();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+
+            let metadata = PropertyMetadata {
+                location: String::from("123 Main St"),
+                size: 1000,
+                legal_description: String::from("Sample property"),
+                valuation: 500000,
+                documents_url: String::from("ipfs://sample-docs"),
+                latitude: None,
+                longitude: None,
+            };
+            let token_id = contract
+                .register_property_with_token(metadata.clone())
+                .unwrap();
+            let other_token_id = contract.register_property_with_token(metadata).unwrap();
+            contract.verify_compliance(token_id, true).unwrap();
+            contract.verify_compliance(other_token_id, true).unwrap();
+            assert!(contract.get_pending_bridges(0, 10).is_empty());
+
+            contract.bridge_to_chain(2, token_id, accounts.bob).unwrap();
+            assert_eq!(contract.get_pending_bridges(0, 10), vec![(2, token_id)]);
+            assert!(contract.get_pending_bridges(1, 10).is_empty());
+            contract
+                .bridge_to_chain(3, other_token_id, accounts.bob)
+                .unwrap();
+            assert_eq!(
+                contract.get_pending_bridges(0, 10),
+                vec![(2, token_id), (3, other_token_id)]
+            );
+
+            // The bridge operator completes the first transfer
+            contract
+                .receive_bridged_token(2, token_id, accounts.bob)
+                .unwrap();
+            assert_eq!(
+                contract.get_pending_bridges(0, 10),
+                vec![(3, other_token_id)]
+            );
+            contract
+                .receive_bridged_token(3, other_token_id, accounts.bob)
+                .unwrap();
+            assert!(contract.get_pending_bridges(0, 10).is_empty());
+            let info = contract.get_bridged_token_info(2, token_id).unwrap();
+            assert_eq!(info.status, BridgingStatus::Completed);
+        }
+
+        #[ink::test]
+        fn test_receive_bridged_token_rejects_replay() {
+            let mut contract = setup_contract();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+
+            assert!(contract.receive_bridged_token(2, 7, accounts.bob).is_ok());
+            assert_eq!(contract.total_supply(), 1);
+
+            // Replaying the same source transfer must not mint again
+            assert_eq!(
+                contract.receive_bridged_token(2, 7, accounts.bob),
+                Err(Error::BridgeAlreadyProcessed)
+            );
+            assert_eq!(
+                contract.receive_bridged_token(2, 7, accounts.charlie),
+                Err(Error::BridgeAlreadyProcessed)
+            );
+            assert_eq!(contract.total_supply(), 1);
+            assert_eq!(contract.balance_of(accounts.bob), 1);
+
+            // The same token id from another chain is a distinct transfer
+            assert!(contract.receive_bridged_token(3, 7, accounts.bob).is_ok());
+            assert_eq!(contract.total_supply(), 2);
+        }
+
+        #[ink::test]
+        fn test_bridge_receive_requires_threshold_approvals() {
+            let mut contract = setup_contract();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+
+            contract.add_bridge_operator(accounts.bob).unwrap();
+            contract.add_bridge_operator(accounts.charlie).unwrap();
+            assert_eq!(
+                contract.set_required_bridge_signatures(0),
+                Err(Error::InvalidThreshold)
+            );
+            assert_eq!(
+                contract.set_required_bridge_signatures(4),
+                Err(Error::InvalidThreshold)
+            );
+            contract.set_required_bridge_signatures(2).unwrap();
+            assert_eq!(contract.get_required_bridge_signatures(), 2);
+
+            // First approval only records the operator
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert!(contract.approve_bridge_receive(2, 7, accounts.eve).is_ok());
+            assert_eq!(contract.total_supply(), 0);
+            assert_eq!(
+                contract.get_bridge_receive_approvals(2, 7, accounts.eve),
+                vec![accounts.bob]
+            );
+            assert_eq!(
+                contract.approve_bridge_receive(2, 7, accounts.eve),
+                Err(Error::BridgeAlreadyApproved)
+            );
+
+            // An approval for a different recipient does not count towards the first
+            test::set_caller::<DefaultEnvironment>(accounts.charlie);
+            assert!(contract
+                .approve_bridge_receive(2, 7, accounts.frank)
+                .is_ok());
+            assert_eq!(contract.total_supply(), 0);
+
+            // Non-operators cannot approve
+            test::set_caller::<DefaultEnvironment>(accounts.django);
+            assert_eq!(
+                contract.approve_bridge_receive(2, 7, accounts.eve),
+                Err(Error::Unauthorized)
+            );
+
+            // Second distinct operator approval mints the token
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            assert!(contract.approve_bridge_receive(2, 7, accounts.eve).is_ok());
+            assert_eq!(contract.total_supply(), 1);
+            assert_eq!(contract.balance_of(accounts.eve), 1);
+            assert!(contract
+                .get_bridge_receive_approvals(2, 7, accounts.eve)
+                .is_empty());
+
+            // The source transfer is now processed for every recipient
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(
+                contract.approve_bridge_receive(2, 7, accounts.frank),
+                Err(Error::BridgeAlreadyProcessed)
+            );
+        }
     }
 }
\ No newline at end of file
@@ -0,0 +1,54 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+pub use self::proxy_echo_logic::Error;
+
+/// Minimal implementation contract used to exercise `TransparentProxy` forwarding in E2E tests.
+#[ink::contract]
+mod proxy_echo_logic {
+    use ink::storage::Lazy;
+
+    #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum Error {
+        Rejected,
+    }
+
+    /// State lives only in a `Lazy` cell, so the root storage entry is empty and running this
+    /// contract against the proxy's storage doesn't clash with the proxy's own layout.
+    #[ink(storage)]
+    #[derive(Default)]
+    pub struct EchoLogic {
+        value: Lazy<u32>,
+    }
+
+    impl EchoLogic {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// Returns the given value unchanged.
+        #[ink(message)]
+        pub fn echo(&self, value: u32) -> u32 {
+            value
+        }
+
+        /// Always fails, reverting the call.
+        #[ink(message)]
+        pub fn reject(&self) -> Result<(), Error> {
+            Err(Error::Rejected)
+        }
+
+        /// One-time initializer storing `value`.
+        #[ink(message)]
+        pub fn init(&mut self, value: u32) {
+            self.value.set(&value);
+        }
+
+        /// Returns the value stored by `init`.
+        #[ink(message)]
+        pub fn value(&self) -> u32 {
+            self.value.get().unwrap_or_default()
+        }
+    }
+}
@@ -2,25 +2,68 @@
 
 #[ink::contract]
 mod propchain_proxy {
-    use ink::storage::Mapping;
+    use ink::env::call::{build_call, ExecutionInput, Selector};
+    use ink::env::CallFlags;
+    use ink::prelude::vec::Vec;
+    use ink::storage::{traits::ManualKey, Lazy};
 
     /// Unique storage key for the proxy data to avoid collisions.
     /// bytes4(keccak256("proxy.storage")) = 0xc5f3bc7a
     const PROXY_STORAGE_KEY: u32 = 0xC5F3BC7A;
 
+    /// Pre-encoded call arguments, written to the call input as-is.
+    struct RawInput<'a>(&'a [u8]);
+
+    impl scale::Encode for RawInput<'_> {
+        fn size_hint(&self) -> usize {
+            self.0.len()
+        }
+
+        fn encode_to<T: scale::Output + ?Sized>(&self, dest: &mut T) {
+            dest.write(self.0);
+        }
+    }
+
     #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
     #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
     pub enum Error {
         Unauthorized,
         UpgradeFailed,
+        /// A direct upgrade was attempted while an upgrade delay is configured
+        UpgradeDelayActive,
+        NoPendingUpgrade,
+        /// The pending upgrade's ETA has not been reached yet
+        UpgradeNotReady,
+        /// Forwarding is halted while the proxy is paused
+        ContractPaused,
     }
 
-    #[ink(storage)]
-    pub struct TransparentProxy {
+    /// Proxy configuration, kept out of the root storage cell.
+    #[derive(Debug, Clone, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    struct ProxyState {
         /// The address of the current implementation contract.
         code_hash: Hash,
         /// The address of the proxy admin.
         admin: AccountId,
+        /// Admin nominated by `change_admin`, awaiting `accept_admin`.
+        pending_admin: Option<AccountId>,
+        /// Minimum time (ms) between proposing and executing an upgrade.
+        upgrade_delay: Timestamp,
+        /// The proposed implementation and the earliest time it can be applied.
+        pending_upgrade: Option<(Hash, Timestamp)>,
+        /// When set, calls are no longer forwarded to the implementation.
+        paused: bool,
+    }
+
+    /// The root storage cell is left empty: `forward` delegates into the implementation, which
+    /// writes its own root struct there, so the proxy's state lives under `PROXY_STORAGE_KEY`.
+    #[ink(storage)]
+    pub struct TransparentProxy {
+        state: Lazy<ProxyState, ManualKey<PROXY_STORAGE_KEY>>,
     }
 
     #[ink(event)]
@@ -29,6 +72,39 @@ mod propchain_proxy {
         new_code_hash: Hash,
     }
 
+    #[ink(event)]
+    pub struct UpgradeProposed {
+        #[ink(topic)]
+        new_code_hash: Hash,
+        eta: Timestamp,
+    }
+
+    #[ink(event)]
+    pub struct UpgradeCancelled {
+        #[ink(topic)]
+        code_hash: Hash,
+    }
+
+    #[ink(event)]
+    pub struct Paused {
+        #[ink(topic)]
+        by: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct Unpaused {
+        #[ink(topic)]
+        by: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct AdminTransferStarted {
+        #[ink(topic)]
+        current_admin: AccountId,
+        #[ink(topic)]
+        pending_admin: AccountId,
+    }
+
     #[ink(event)]
     pub struct AdminChanged {
         #[ink(topic)]
@@ -36,44 +112,530 @@ mod propchain_proxy {
     }
 
     impl TransparentProxy {
+        /// Creates a proxy for `code_hash`. Upgrades must wait `upgrade_delay` ms after being
+        /// proposed; a delay of zero allows immediate upgrades via `upgrade_to`.
         #[ink(constructor)]
-        pub fn new(code_hash: Hash) -> Self {
-            Self {
+        pub fn new(code_hash: Hash, upgrade_delay: Timestamp) -> Self {
+            let mut state = Lazy::new();
+            state.set(&ProxyState {
                 code_hash,
                 admin: Self::env().caller(),
-            }
+                pending_admin: None,
+                upgrade_delay,
+                pending_upgrade: None,
+                paused: false,
+            });
+            Self { state }
         }
 
+        /// Immediately switches the implementation. Only allowed when no upgrade delay is set.
         #[ink(message)]
         pub fn upgrade_to(&mut self, new_code_hash: Hash) -> Result<(), Error> {
-            self.ensure_admin()?;
-            self.code_hash = new_code_hash;
+            let mut state = self.ensure_admin()?;
+            if state.upgrade_delay > 0 {
+                return Err(Error::UpgradeDelayActive);
+            }
+            state.code_hash = new_code_hash;
+            self.state.set(&state);
+            self.env().emit_event(Upgraded { new_code_hash });
+            Ok(())
+        }
+
+        /// Switches the implementation and runs its initializer in the same call. `input` holds the
+        /// SCALE-encoded arguments for `selector`. If the initializer fails the whole upgrade reverts.
+        /// Only allowed when no upgrade delay is set.
+        #[ink(message)]
+        pub fn upgrade_to_and_call(
+            &mut self,
+            new_code_hash: Hash,
+            selector: [u8; 4],
+            input: Vec<u8>,
+        ) -> Result<(), Error> {
+            let mut state = self.ensure_admin()?;
+            if state.upgrade_delay > 0 {
+                return Err(Error::UpgradeDelayActive);
+            }
+
+            let result = build_call::<ink::env::DefaultEnvironment>()
+                .delegate(new_code_hash)
+                .exec_input(ExecutionInput::new(Selector::new(selector)).push_arg(RawInput(&input)))
+                .returns::<()>()
+                .try_invoke();
+
+            if !matches!(result, Ok(Ok(()))) {
+                return Err(Error::UpgradeFailed);
+            }
+
+            state.code_hash = new_code_hash;
+            self.state.set(&state);
+            self.env().emit_event(Upgraded { new_code_hash });
+            Ok(())
+        }
+
+        /// Proposes a new implementation that can be applied once the upgrade delay has passed.
+        /// Replaces any previously pending proposal.
+        #[ink(message)]
+        pub fn propose_upgrade(&mut self, new_code_hash: Hash) -> Result<(), Error> {
+            let mut state = self.ensure_admin()?;
+            let eta = self
+                .env()
+                .block_timestamp()
+                .saturating_add(state.upgrade_delay);
+            state.pending_upgrade = Some((new_code_hash, eta));
+            self.state.set(&state);
+            self.env()
+                .emit_event(UpgradeProposed { new_code_hash, eta });
+            Ok(())
+        }
+
+        /// Applies the pending upgrade once its ETA has been reached.
+        #[ink(message)]
+        pub fn execute_upgrade(&mut self) -> Result<(), Error> {
+            let mut state = self.ensure_admin()?;
+            let (new_code_hash, eta) = state.pending_upgrade.ok_or(Error::NoPendingUpgrade)?;
+            if self.env().block_timestamp() < eta {
+                return Err(Error::UpgradeNotReady);
+            }
+            state.code_hash = new_code_hash;
+            state.pending_upgrade = None;
+            self.state.set(&state);
             self.env().emit_event(Upgraded { new_code_hash });
             Ok(())
         }
 
+        /// Discards the pending upgrade.
+        #[ink(message)]
+        pub fn cancel_upgrade(&mut self) -> Result<(), Error> {
+            let mut state = self.ensure_admin()?;
+            let (code_hash, _) = state
+                .pending_upgrade
+                .take()
+                .ok_or(Error::NoPendingUpgrade)?;
+            self.state.set(&state);
+            self.env().emit_event(UpgradeCancelled { code_hash });
+            Ok(())
+        }
+
+        /// Starts an admin transfer. The new admin takes over only after calling `accept_admin`.
         #[ink(message)]
         pub fn change_admin(&mut self, new_admin: AccountId) -> Result<(), Error> {
-            self.ensure_admin()?;
-            self.admin = new_admin;
-            self.env().emit_event(AdminChanged { new_admin });
+            let mut state = self.ensure_admin()?;
+            state.pending_admin = Some(new_admin);
+            self.state.set(&state);
+            self.env().emit_event(AdminTransferStarted {
+                current_admin: state.admin,
+                pending_admin: new_admin,
+            });
+            Ok(())
+        }
+
+        /// Completes an admin transfer. Callable only by the pending admin.
+        #[ink(message)]
+        pub fn accept_admin(&mut self) -> Result<(), Error> {
+            let caller = self.env().caller();
+            let mut state = self.load_state();
+            if state.pending_admin != Some(caller) {
+                return Err(Error::Unauthorized);
+            }
+            state.admin = caller;
+            state.pending_admin = None;
+            self.state.set(&state);
+            self.env().emit_event(AdminChanged { new_admin: caller });
+            Ok(())
+        }
+
+        /// Forwards any call that doesn't match a proxy message to the implementation.
+        ///
+        /// The original input is passed through unchanged and the delegate call is made as a
+        /// tail call, so the implementation's return data — including its revert flag — is
+        /// handed straight back to the caller instead of returning into the proxy.
+        /// Fails with `ContractPaused` while the proxy is paused.
+        #[ink(message, payable, selector = _)]
+        pub fn forward(&self) -> Result<(), Error> {
+            let state = self.load_state();
+            if state.paused {
+                return Err(Error::ContractPaused);
+            }
+            build_call::<ink::env::DefaultEnvironment>()
+                .delegate(state.code_hash)
+                .call_flags(CallFlags::FORWARD_INPUT.union(CallFlags::TAIL_CALL))
+                .invoke();
+            unreachable!("the delegate call never returns since `tail_call` was set");
+        }
+
+        /// Halts forwarding to the implementation. Admin messages keep working.
+        #[ink(message)]
+        pub fn pause(&mut self) -> Result<(), Error> {
+            let mut state = self.ensure_admin()?;
+            state.paused = true;
+            self.state.set(&state);
+            self.env().emit_event(Paused {
+                by: self.env().caller(),
+            });
+            Ok(())
+        }
+
+        /// Resumes forwarding to the implementation.
+        #[ink(message)]
+        pub fn unpause(&mut self) -> Result<(), Error> {
+            let mut state = self.ensure_admin()?;
+            state.paused = false;
+            self.state.set(&state);
+            self.env().emit_event(Unpaused {
+                by: self.env().caller(),
+            });
             Ok(())
         }
 
+        #[ink(message)]
+        pub fn is_paused(&self) -> bool {
+            self.load_state().paused
+        }
+
         #[ink(message)]
         pub fn code_hash(&self) -> Hash {
-            self.code_hash
+            self.load_state().code_hash
         }
 
         #[ink(message)]
         pub fn admin(&self) -> AccountId {
-            self.admin
+            self.load_state().admin
+        }
+
+        #[ink(message)]
+        pub fn pending_admin(&self) -> Option<AccountId> {
+            self.load_state().pending_admin
+        }
+
+        #[ink(message)]
+        pub fn upgrade_delay(&self) -> Timestamp {
+            self.load_state().upgrade_delay
+        }
+
+        #[ink(message)]
+        pub fn pending_upgrade(&self) -> Option<(Hash, Timestamp)> {
+            self.load_state().pending_upgrade
         }
 
-        fn ensure_admin(&self) -> Result<(), Error> {
-            if self.env().caller() != self.admin {
+        fn load_state(&self) -> ProxyState {
+            self.state
+                .get()
+                .expect("proxy state is written by the constructor")
+        }
+
+        /// Returns the proxy state if the caller is the admin.
+        fn ensure_admin(&self) -> Result<ProxyState, Error> {
+            let state = self.load_state();
+            if self.env().caller() != state.admin {
                 return Err(Error::Unauthorized);
             }
+            Ok(state)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        const DELAY: Timestamp = 1_000;
+
+        fn set_caller(caller: AccountId) {
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(caller);
+        }
+
+        fn set_timestamp(timestamp: Timestamp) {
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(timestamp);
+        }
+
+        #[ink::test]
+        fn upgrade_waits_for_eta() {
+            let mut proxy = TransparentProxy::new(Hash::from([0x01; 32]), DELAY);
+            let new_code_hash = Hash::from([0x02; 32]);
+
+            assert_eq!(
+                proxy.upgrade_to(new_code_hash),
+                Err(Error::UpgradeDelayActive)
+            );
+            assert_eq!(proxy.execute_upgrade(), Err(Error::NoPendingUpgrade));
+
+            set_timestamp(500);
+            assert_eq!(proxy.propose_upgrade(new_code_hash), Ok(()));
+            assert_eq!(proxy.pending_upgrade(), Some((new_code_hash, 500 + DELAY)));
+
+            // Before the ETA the implementation is unchanged
+            set_timestamp(500 + DELAY - 1);
+            assert_eq!(proxy.execute_upgrade(), Err(Error::UpgradeNotReady));
+            assert_eq!(proxy.code_hash(), Hash::from([0x01; 32]));
+
+            set_timestamp(500 + DELAY);
+            assert_eq!(proxy.execute_upgrade(), Ok(()));
+            assert_eq!(proxy.code_hash(), new_code_hash);
+            assert_eq!(proxy.pending_upgrade(), None);
+        }
+
+        #[ink::test]
+        fn cancel_upgrade_works() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut proxy = TransparentProxy::new(Hash::from([0x01; 32]), DELAY);
+
+            assert_eq!(proxy.cancel_upgrade(), Err(Error::NoPendingUpgrade));
+            proxy.propose_upgrade(Hash::from([0x02; 32])).unwrap();
+
+            set_caller(accounts.bob);
+            assert_eq!(proxy.cancel_upgrade(), Err(Error::Unauthorized));
+            assert_eq!(
+                proxy.propose_upgrade(Hash::from([0x03; 32])),
+                Err(Error::Unauthorized)
+            );
+
+            set_caller(accounts.alice);
+            assert_eq!(proxy.cancel_upgrade(), Ok(()));
+            set_timestamp(DELAY);
+            assert_eq!(proxy.execute_upgrade(), Err(Error::NoPendingUpgrade));
+            assert_eq!(proxy.code_hash(), Hash::from([0x01; 32]));
+        }
+
+        #[ink::test]
+        fn pause_blocks_forwarding() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut proxy = TransparentProxy::new(Hash::from([0x01; 32]), 0);
+
+            set_caller(accounts.bob);
+            assert_eq!(proxy.pause(), Err(Error::Unauthorized));
+
+            set_caller(accounts.alice);
+            assert_eq!(proxy.pause(), Ok(()));
+            assert!(proxy.is_paused());
+
+            set_caller(accounts.bob);
+            assert_eq!(proxy.forward(), Err(Error::ContractPaused));
+
+            // Admin functions still work while paused
+            set_caller(accounts.alice);
+            assert_eq!(proxy.upgrade_to(Hash::from([0x02; 32])), Ok(()));
+            assert_eq!(proxy.change_admin(accounts.charlie), Ok(()));
+
+            set_caller(accounts.charlie);
+            assert_eq!(proxy.accept_admin(), Ok(()));
+            assert_eq!(proxy.unpause(), Ok(()));
+            assert!(!proxy.is_paused());
+        }
+
+        #[ink::test]
+        fn admin_transfer_requires_acceptance() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut proxy = TransparentProxy::new(Hash::from([0x01; 32]), 0);
+
+            assert_eq!(proxy.change_admin(accounts.bob), Ok(()));
+            assert_eq!(proxy.admin(), accounts.alice);
+            assert_eq!(proxy.pending_admin(), Some(accounts.bob));
+
+            // Only the pending admin can accept
+            set_caller(accounts.charlie);
+            assert_eq!(proxy.accept_admin(), Err(Error::Unauthorized));
+            assert_eq!(proxy.admin(), accounts.alice);
+
+            set_caller(accounts.bob);
+            assert_eq!(proxy.accept_admin(), Ok(()));
+            assert_eq!(proxy.admin(), accounts.bob);
+            assert_eq!(proxy.pending_admin(), None);
+
+            // The previous admin lost control
+            set_caller(accounts.alice);
+            assert_eq!(proxy.pause(), Err(Error::Unauthorized));
+        }
+
+        #[ink::test]
+        fn implementation_root_writes_do_not_clobber_proxy_state() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut proxy = TransparentProxy::new(Hash::from([0x01; 32]), 0);
+
+            // A mutating message run through `forward` writes the implementation's root struct
+            // into the proxy's root cell. Had the proxy kept its fields there, this would have
+            // handed eve the admin role.
+            ink::env::set_contract_storage(&0u32, &(Hash::from([0xAA; 32]), accounts.eve));
+
+            assert_eq!(proxy.admin(), accounts.alice);
+            assert_eq!(proxy.code_hash(), Hash::from([0x01; 32]));
+
+            set_caller(accounts.eve);
+            assert_eq!(proxy.pause(), Err(Error::Unauthorized));
+
+            set_caller(accounts.alice);
+            assert_eq!(proxy.upgrade_to(Hash::from([0x02; 32])), Ok(()));
+            assert_eq!(proxy.code_hash(), Hash::from([0x02; 32]));
+        }
+
+        #[ink::test]
+        fn zero_delay_allows_direct_upgrade() {
+            let mut proxy = TransparentProxy::new(Hash::from([0x01; 32]), 0);
+            assert_eq!(proxy.upgrade_to(Hash::from([0x02; 32])), Ok(()));
+            assert_eq!(proxy.code_hash(), Hash::from([0x02; 32]));
+        }
+    }
+
+    #[cfg(all(test, feature = "e2e-tests"))]
+    mod e2e_tests {
+        use super::*;
+        use ink_e2e::ContractsBackend;
+
+        type E2EResult<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+        #[ink_e2e::test]
+        async fn forward_round_trips_return_data(
+            mut client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let logic_code_hash = client
+                .upload("proxy_echo_logic", &ink_e2e::alice())
+                .submit()
+                .await
+                .expect("logic upload failed")
+                .code_hash;
+
+            let mut constructor = TransparentProxyRef::new(logic_code_hash, 0);
+            let proxy = client
+                .instantiate("propchain_proxy", &ink_e2e::alice(), &mut constructor)
+                .submit()
+                .await
+                .expect("proxy instantiate failed");
+
+            // `echo` isn't a proxy message, so it is delegated to the logic contract
+            let echo = build_call::<ink::env::DefaultEnvironment>()
+                .call(proxy.account_id)
+                .exec_input(
+                    ExecutionInput::new(Selector::new(ink::selector_bytes!("echo")))
+                        .push_arg(42u32),
+                )
+                .returns::<u32>();
+            let result = client.call(&ink_e2e::alice(), &echo).dry_run().await?;
+            assert_eq!(result.return_value(), 42);
+
+            // A reverting implementation propagates its error through the proxy
+            let reject = build_call::<ink::env::DefaultEnvironment>()
+                .call(proxy.account_id)
+                .exec_input(ExecutionInput::new(Selector::new(ink::selector_bytes!(
+                    "reject"
+                ))))
+                .returns::<Result<(), proxy_echo_logic::Error>>();
+            let result = client.call(&ink_e2e::alice(), &reject).dry_run().await?;
+            assert_eq!(
+                result.return_value(),
+                Err(proxy_echo_logic::Error::Rejected)
+            );
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn forwarded_writes_keep_admin_path(
+            mut client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let logic_code_hash = client
+                .upload("proxy_echo_logic", &ink_e2e::alice())
+                .submit()
+                .await
+                .expect("logic upload failed")
+                .code_hash;
+
+            let mut constructor = TransparentProxyRef::new(logic_code_hash, 0);
+            let proxy = client
+                .instantiate("propchain_proxy", &ink_e2e::alice(), &mut constructor)
+                .submit()
+                .await
+                .expect("proxy instantiate failed");
+            let mut call_builder = proxy.call_builder::<TransparentProxy>();
+
+            // `init` mutates storage through the delegate call
+            let init = build_call::<ink::env::DefaultEnvironment>()
+                .call(proxy.account_id)
+                .exec_input(
+                    ExecutionInput::new(Selector::new(ink::selector_bytes!("init"))).push_arg(7u32),
+                )
+                .returns::<()>();
+            client
+                .call(&ink_e2e::alice(), &init)
+                .submit()
+                .await
+                .expect("forwarded init failed");
+
+            // The proxy's own state and admin messages are unaffected
+            let admin = client
+                .call(&ink_e2e::alice(), &call_builder.admin())
+                .dry_run()
+                .await?;
+            assert_eq!(
+                admin.return_value(),
+                ink_e2e::account_id(ink_e2e::AccountKeyring::Alice)
+            );
+            client
+                .call(&ink_e2e::alice(), &call_builder.pause())
+                .submit()
+                .await
+                .expect("pause failed");
+            let paused = client
+                .call(&ink_e2e::alice(), &call_builder.is_paused())
+                .dry_run()
+                .await?;
+            assert!(paused.return_value());
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn upgrade_to_and_call_runs_initializer(
+            mut client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let logic_code_hash = client
+                .upload("proxy_echo_logic", &ink_e2e::alice())
+                .submit()
+                .await
+                .expect("logic upload failed")
+                .code_hash;
+
+            let mut constructor = TransparentProxyRef::new(Hash::from([0x00; 32]), 0);
+            let proxy = client
+                .instantiate("propchain_proxy", &ink_e2e::alice(), &mut constructor)
+                .submit()
+                .await
+                .expect("proxy instantiate failed");
+            let mut call_builder = proxy.call_builder::<TransparentProxy>();
+
+            // A failing initializer reverts the upgrade
+            let failed = call_builder.upgrade_to_and_call(
+                logic_code_hash,
+                ink::selector_bytes!("reject"),
+                Vec::new(),
+            );
+            let result = client.call(&ink_e2e::alice(), &failed).dry_run().await?;
+            assert_eq!(result.return_value(), Err(Error::UpgradeFailed));
+
+            let upgrade = call_builder.upgrade_to_and_call(
+                logic_code_hash,
+                ink::selector_bytes!("init"),
+                scale::Encode::encode(&7u32),
+            );
+            client
+                .call(&ink_e2e::alice(), &upgrade)
+                .submit()
+                .await
+                .expect("upgrade_to_and_call failed");
+
+            let code_hash = client
+                .call(&ink_e2e::alice(), &call_builder.code_hash())
+                .dry_run()
+                .await?;
+            assert_eq!(code_hash.return_value(), logic_code_hash);
+
+            // The value written by the initializer is visible through the proxy
+            let value = build_call::<ink::env::DefaultEnvironment>()
+                .call(proxy.account_id)
+                .exec_input(ExecutionInput::new(Selector::new(ink::selector_bytes!(
+                    "value"
+                ))))
+                .returns::<u32>();
+            let result = client.call(&ink_e2e::alice(), &value).dry_run().await?;
+            assert_eq!(result.return_value(), 7);
+
             Ok(())
         }
     }
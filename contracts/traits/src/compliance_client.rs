@@ -0,0 +1,50 @@
+//! Shared client for querying a `ComplianceRegistry` from other PropChain contracts
+
+use ink::env::call::{
+    build_call,
+    utils::{Argument, ArgumentList, EmptyArgumentList},
+    ExecutionInput, Selector,
+};
+use ink::env::DefaultEnvironment;
+use ink::primitives::AccountId;
+
+/// Selector of the registry's `is_compliant(account)` message
+pub const IS_COMPLIANT_SELECTOR: [u8; 4] = ink::selector_bytes!("is_compliant");
+
+/// Errors returned when the compliance registry cannot be queried
+#[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum CallError {
+    /// The call into the registry failed or trapped
+    CallFailed,
+    /// The registry could not dispatch the message (e.g. unknown selector)
+    DispatchFailed,
+}
+
+/// Compliance interface implemented by the compliance registry
+pub trait ComplianceChecker {
+    /// Returns whether `account` currently passes all compliance checks
+    fn is_compliant(&self, account: AccountId) -> bool;
+}
+
+/// Call input for `is_compliant(account)`: selector followed by the encoded account
+pub fn is_compliant_input(
+    account: AccountId,
+) -> ExecutionInput<ArgumentList<Argument<AccountId>, EmptyArgumentList>> {
+    ExecutionInput::new(Selector::new(IS_COMPLIANT_SELECTOR)).push_arg(account)
+}
+
+/// Queries `registry` for the compliance status of `account`
+pub fn is_compliant(registry: AccountId, account: AccountId) -> Result<bool, CallError> {
+    let result = build_call::<DefaultEnvironment>()
+        .call(registry)
+        .exec_input(is_compliant_input(account))
+        .returns::<bool>()
+        .try_invoke();
+
+    match result {
+        Ok(Ok(compliant)) => Ok(compliant),
+        Ok(Err(_)) => Err(CallError::DispatchFailed),
+        Err(_) => Err(CallError::CallFailed),
+    }
+}
@@ -3,6 +3,9 @@
 use ink::prelude::string::String;
 use ink::primitives::AccountId;
 
+pub mod compliance_client;
+pub mod receiver_client;
+
 /// Trait definitions for PropChain contracts
 pub trait PropertyRegistry {
     /// Error type for the contract
@@ -36,6 +39,10 @@ pub struct PropertyMetadata {
     pub legal_description: String,
     pub valuation: u128,
     pub documents_url: String,
+    /// Latitude in microdegrees (degrees * 1_000_000), if known
+    pub latitude: Option<i32>,
+    /// Longitude in microdegrees (degrees * 1_000_000), if known
+    pub longitude: Option<i32>,
 }
 
 /// Property information structure
@@ -61,6 +68,21 @@ pub enum PropertyType {
     Office,
 }
 
+/// Listing state of a property
+#[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub enum PropertyStatus {
+    /// Not offered for sale (default)
+    Unlisted,
+    Listed,
+    /// An escrow is open against the property
+    UnderContract,
+    Sold,
+}
+
 /// Price data from external feeds
 #[derive(Debug, Clone, PartialEq, scale::Encode, scale::Decode)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
@@ -185,8 +207,13 @@ pub trait Escrow {
     /// Error type for escrow operations
     type Error;
 
-    /// Create a new escrow
-    fn create_escrow(&mut self, property_id: u64, amount: u128) -> Result<u64, Self::Error>;
+    /// Create a new escrow selling the property to `buyer`
+    fn create_escrow(
+        &mut self,
+        property_id: u64,
+        buyer: AccountId,
+        amount: u128,
+    ) -> Result<u64, Self::Error>;
 
     /// Release escrow funds
     fn release_escrow(&mut self, escrow_id: u64) -> Result<(), Self::Error>;
@@ -198,6 +225,34 @@ pub trait Escrow {
 #[cfg(not(feature = "std"))]
 use scale_info::prelude::vec::Vec;
 
+/// Multi-signature and settlement options for an advanced escrow
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct EscrowConfig {
+    pub participants: Vec<AccountId>, // Accounts allowed to sign approvals
+    pub required_signatures: u8,      // Signatures needed to release or refund
+    pub release_time_lock: Option<u64>, // Earliest release timestamp
+    pub fee_bps: u16,                 // Platform fee on release, in basis points
+    pub fee_recipient: AccountId,     // Credited with the platform fee
+    pub condition_deadline: Option<u64>, // After this, unmet conditions let the buyer expire the escrow
+    pub conditions_gate_refund: bool,    // Refunds also require every condition to be met
+}
+
+impl EscrowConfig {
+    /// Multi-signature escrow without time lock, fee or condition deadline
+    pub fn new(participants: Vec<AccountId>, required_signatures: u8) -> Self {
+        Self {
+            participants,
+            required_signatures,
+            release_time_lock: None,
+            fee_bps: 0,
+            fee_recipient: AccountId::from([0x0; 32]),
+            condition_deadline: None,
+            conditions_gate_refund: false,
+        }
+    }
+}
+
 /// Advanced escrow trait with multi-signature and document custody
 pub trait AdvancedEscrow {
     /// Error type for escrow operations
@@ -210,9 +265,7 @@ pub trait AdvancedEscrow {
         amount: u128,
         buyer: AccountId,
         seller: AccountId,
-        participants: Vec<AccountId>,
-        required_signatures: u8,
-        release_time_lock: Option<u64>,
+        config: EscrowConfig,
     ) -> Result<u64, Self::Error>;
 
     /// Deposit funds to escrow
@@ -239,8 +292,13 @@ pub trait AdvancedEscrow {
         document_hash: ink::primitives::Hash,
     ) -> Result<(), Self::Error>;
 
-    /// Add a condition to the escrow
-    fn add_condition(&mut self, escrow_id: u64, description: String) -> Result<u64, Self::Error>;
+    /// Add a condition to the escrow, optionally restricted to a single verifier
+    fn add_condition(
+        &mut self,
+        escrow_id: u64,
+        description: String,
+        verifier: Option<AccountId>,
+    ) -> Result<u64, Self::Error>;
 
     /// Mark a condition as met
     fn mark_condition_met(&mut self, escrow_id: u64, condition_id: u64) -> Result<(), Self::Error>;
@@ -0,0 +1,74 @@
+//! Shared client for notifying contracts that receive property tokens
+
+use ink::env::call::{
+    build_call,
+    utils::{Argument, ArgumentList, EmptyArgumentList},
+    ExecutionInput, Selector,
+};
+use ink::env::DefaultEnvironment;
+use ink::prelude::vec::Vec;
+use ink::primitives::AccountId;
+
+use crate::compliance_client::CallError;
+
+/// Selector of the receiver's `on_property_received(operator, from, token_id, data)` message
+pub const ON_PROPERTY_RECEIVED_SELECTOR: [u8; 4] = ink::selector_bytes!("on_property_received");
+
+/// Value a receiver returns to accept a token: the hook's own selector
+pub const ON_PROPERTY_RECEIVED_MAGIC: [u8; 4] = ON_PROPERTY_RECEIVED_SELECTOR;
+
+/// Hook implemented by contracts that can hold property tokens
+pub trait PropertyReceiver {
+    /// Called after a safe transfer to this contract.
+    /// Must return `ON_PROPERTY_RECEIVED_MAGIC` to accept the token.
+    fn on_property_received(
+        &mut self,
+        operator: AccountId,
+        from: AccountId,
+        token_id: u64,
+        data: Vec<u8>,
+    ) -> [u8; 4];
+}
+
+type ReceivedArgs = ArgumentList<
+    Argument<Vec<u8>>,
+    ArgumentList<
+        Argument<u64>,
+        ArgumentList<Argument<AccountId>, ArgumentList<Argument<AccountId>, EmptyArgumentList>>,
+    >,
+>;
+
+/// Call input for `on_property_received(operator, from, token_id, data)`
+pub fn on_property_received_input(
+    operator: AccountId,
+    from: AccountId,
+    token_id: u64,
+    data: Vec<u8>,
+) -> ExecutionInput<ReceivedArgs> {
+    ExecutionInput::new(Selector::new(ON_PROPERTY_RECEIVED_SELECTOR))
+        .push_arg(operator)
+        .push_arg(from)
+        .push_arg(token_id)
+        .push_arg(data)
+}
+
+/// Notifies `receiver` of an incoming token and returns the value it answered with
+pub fn on_property_received(
+    receiver: AccountId,
+    operator: AccountId,
+    from: AccountId,
+    token_id: u64,
+    data: Vec<u8>,
+) -> Result<[u8; 4], CallError> {
+    let result = build_call::<DefaultEnvironment>()
+        .call(receiver)
+        .exec_input(on_property_received_input(operator, from, token_id, data))
+        .returns::<[u8; 4]>()
+        .try_invoke();
+
+    match result {
+        Ok(Ok(magic)) => Ok(magic),
+        Ok(Err(_)) => Err(CallError::DispatchFailed),
+        Err(_) => Err(CallError::CallFailed),
+    }
+}
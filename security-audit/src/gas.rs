@@ -0,0 +1,202 @@
+//! Gas benchmarking for contract messages
+//!
+//! Benchmark tests report their measurements by printing one line per message:
+//!
+//! ```text
+//! gas_bench: <message> ref_time=<u64> proof_size=<u64>
+//! ```
+//!
+//! The `benchmark` subcommand collects these lines from `cargo test` output and
+//! compares them against a baseline file. The PropertyRegistry benchmarks live in
+//! `contracts/lib/src/gas_benchmarks.rs`.
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// Weight consumed by a single message call
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct GasUsage {
+    pub ref_time: u64,
+    pub proof_size: u64,
+}
+
+/// Stored measurements that new runs are compared against
+#[derive(Serialize, Deserialize, Debug, Default, PartialEq, Eq)]
+pub struct GasBaseline {
+    pub messages: BTreeMap<String, GasUsage>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct GasEntry {
+    pub message: String,
+    pub usage: GasUsage,
+    pub baseline: Option<GasUsage>,
+    /// Change in `ref_time` relative to the baseline, in percent
+    pub change_pct: Option<f64>,
+    pub regressed: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, PartialEq)]
+pub struct GasReport {
+    pub timestamp: String,
+    pub threshold_pct: f64,
+    pub entries: Vec<GasEntry>,
+    pub regressions: usize,
+}
+
+/// Why a benchmark run cannot be trusted
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RunError {
+    /// `cargo test` exited unsuccessfully, so measurements may be missing
+    TestsFailed,
+    /// The run printed no `gas_bench:` lines
+    NoMeasurements,
+}
+
+impl fmt::Display for RunError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RunError::TestsFailed => write!(f, "benchmark tests failed"),
+            RunError::NoMeasurements => write!(f, "no gas_bench measurements were reported"),
+        }
+    }
+}
+
+/// Check that a run is complete enough to compare against or become the baseline
+pub fn validate_run(
+    tests_passed: bool,
+    measurements: &BTreeMap<String, GasUsage>,
+) -> Result<(), RunError> {
+    if !tests_passed {
+        return Err(RunError::TestsFailed);
+    }
+    if measurements.is_empty() {
+        return Err(RunError::NoMeasurements);
+    }
+    Ok(())
+}
+
+/// Parse `gas_bench:` lines out of test output.
+///
+/// A message measured more than once keeps its last measurement.
+pub fn parse_benchmark_output(output: &str) -> BTreeMap<String, GasUsage> {
+    let pattern = Regex::new(r"gas_bench:\s*(\S+)\s+ref_time=(\d+)\s+proof_size=(\d+)").unwrap();
+
+    let mut measurements = BTreeMap::new();
+    for captures in pattern.captures_iter(output) {
+        let (Ok(ref_time), Ok(proof_size)) = (captures[2].parse(), captures[3].parse()) else {
+            continue;
+        };
+        measurements.insert(
+            captures[1].to_string(),
+            GasUsage {
+                ref_time,
+                proof_size,
+            },
+        );
+    }
+    measurements
+}
+
+/// Compare measurements against `baseline`, flagging messages whose `ref_time`
+/// grew by more than `threshold_pct` percent
+pub fn compare(
+    measurements: &BTreeMap<String, GasUsage>,
+    baseline: &GasBaseline,
+    threshold_pct: f64,
+) -> GasReport {
+    let entries: Vec<GasEntry> = measurements
+        .iter()
+        .map(|(message, usage)| {
+            let baseline = baseline.messages.get(message).copied();
+            let change_pct = baseline
+                .filter(|b| b.ref_time > 0)
+                .map(|b| (usage.ref_time as f64 - b.ref_time as f64) * 100.0 / b.ref_time as f64);
+            GasEntry {
+                message: message.clone(),
+                usage: *usage,
+                baseline,
+                change_pct,
+                regressed: change_pct.is_some_and(|change| change > threshold_pct),
+            }
+        })
+        .collect();
+
+    GasReport {
+        timestamp: chrono::Utc::now().to_rfc3339(),
+        threshold_pct,
+        regressions: entries.iter().filter(|e| e.regressed).count(),
+        entries,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    const FIXTURES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/gas");
+
+    #[test]
+    fn flags_regressions_against_baseline() {
+        let output = fs::read_to_string(format!("{}/benchmark_output.txt", FIXTURES)).unwrap();
+        let baseline: GasBaseline = serde_json::from_str(
+            &fs::read_to_string(format!("{}/baseline.json", FIXTURES)).unwrap(),
+        )
+        .unwrap();
+
+        let measurements = parse_benchmark_output(&output);
+        assert_eq!(measurements.len(), 3);
+        assert_eq!(
+            measurements["transfer_property"],
+            GasUsage {
+                ref_time: 1_320_000_000,
+                proof_size: 41_000
+            }
+        );
+
+        let report = compare(&measurements, &baseline, 10.0);
+        assert_eq!(report.regressions, 1);
+
+        let entry = |message: &str| {
+            report
+                .entries
+                .iter()
+                .find(|e| e.message == message)
+                .unwrap()
+        };
+
+        // 20% slower than the baseline
+        let transfer = entry("transfer_property");
+        assert!(transfer.regressed);
+        assert_eq!(transfer.change_pct, Some(20.0));
+
+        // 5% slower is within the threshold
+        let register = entry("register_property");
+        assert!(!register.regressed);
+        assert_eq!(register.change_pct, Some(5.0));
+
+        // New messages have nothing to regress against
+        let batch = entry("batch_register_properties");
+        assert_eq!(batch.baseline, None);
+        assert!(!batch.regressed);
+    }
+
+    #[test]
+    fn rejects_failed_or_empty_runs() {
+        let output = fs::read_to_string(format!("{}/benchmark_output.txt", FIXTURES)).unwrap();
+        let measurements = parse_benchmark_output(&output);
+
+        assert_eq!(validate_run(true, &measurements), Ok(()));
+        assert_eq!(
+            validate_run(false, &measurements),
+            Err(RunError::TestsFailed)
+        );
+        assert_eq!(
+            validate_run(true, &parse_benchmark_output("test result: ok")),
+            Err(RunError::NoMeasurements)
+        );
+    }
+}
@@ -1,11 +1,16 @@
 use anyhow::{Context, Result};
-use clap::{Parser, Subcommand};
+use clap::{Parser, Subcommand, ValueEnum};
 use colored::*;
+use regex::Regex;
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
 use std::fs;
+use std::path::Path;
 use std::process::Command;
 use walkdir::WalkDir;
 
+mod gas;
+
 #[derive(Parser)]
 #[command(author, version, about, long_about = None)]
 struct Cli {
@@ -20,9 +25,59 @@ enum Commands {
         /// Generate a report file
         #[arg(short, long)]
         report: Option<String>,
+
+        /// Format of the report file
+        #[arg(long, value_enum, default_value_t = ReportFormat::Json)]
+        format: ReportFormat,
+
+        /// Exit with status 1 if the security score is below this threshold
+        #[arg(long, value_name = "SCORE")]
+        fail_under: Option<u32>,
+
+        /// Read `cargo clippy --message-format=json` output from a file instead of running clippy
+        #[arg(long, value_name = "FILE")]
+        clippy_output: Option<String>,
+
+        /// Read `cargo audit --json` output from a file instead of running cargo audit
+        #[arg(long, value_name = "FILE")]
+        audit_output: Option<String>,
+    },
+    /// Run gas benchmarks and compare them against a baseline
+    Benchmark {
+        /// Package whose benchmarks to run (defaults to the whole workspace)
+        #[arg(short, long)]
+        package: Option<String>,
+
+        /// Run the ink! e2e benchmarks (requires a running contracts node)
+        #[arg(long, requires = "package")]
+        e2e: bool,
+
+        /// Baseline file to compare against
+        #[arg(long, default_value = "gas-baseline.json")]
+        baseline: String,
+
+        /// Flag messages whose ref_time grew by more than this percentage
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+
+        /// Generate a report file
+        #[arg(short, long)]
+        report: Option<String>,
+
+        /// Overwrite the baseline with this run's measurements
+        #[arg(long)]
+        update_baseline: bool,
     },
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+enum ReportFormat {
+    /// PropChain `SecurityReport` JSON
+    Json,
+    /// SARIF 2.1.0, for code scanning tools
+    Sarif,
+}
+
 #[derive(Serialize, Deserialize, Debug, Default)]
 struct SecurityReport {
     timestamp: String,
@@ -30,6 +85,9 @@ struct SecurityReport {
     static_analysis: StaticAnalysisResults,
     dependency_scan: DependencyScanResults,
     code_quality: CodeQualityResults,
+    /// Clippy and dependency diagnostics, used for SARIF output
+    #[serde(skip)]
+    diagnostics: Vec<Diagnostic>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Default)]
@@ -39,6 +97,26 @@ struct StaticAnalysisResults {
     complexity_warnings: usize,
     unsafe_blocks: usize,
     todos_found: usize,
+    zero_hashes: usize,
+    unchecked_arithmetic: usize,
+    env_unwraps: usize,
+    /// Custom linter findings with their locations
+    findings: Vec<Finding>,
+}
+
+impl StaticAnalysisResults {
+    /// Store a finding and bump the count for its kind
+    fn record(&mut self, finding: Finding) {
+        let count = match finding.kind {
+            FindingKind::UnsafeBlock => &mut self.unsafe_blocks,
+            FindingKind::Todo => &mut self.todos_found,
+            FindingKind::ZeroHash => &mut self.zero_hashes,
+            FindingKind::UncheckedArithmetic => &mut self.unchecked_arithmetic,
+            FindingKind::EnvUnwrap => &mut self.env_unwraps,
+        };
+        *count += 1;
+        self.findings.push(finding);
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, Default)]
@@ -53,13 +131,180 @@ struct CodeQualityResults {
     files_scanned: usize,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum FindingLevel {
+    Error,
+    Warning,
+    Note,
+}
+
+impl FindingLevel {
+    fn as_sarif(self) -> &'static str {
+        match self {
+            FindingLevel::Error => "error",
+            FindingLevel::Warning => "warning",
+            FindingLevel::Note => "note",
+        }
+    }
+}
+
+/// A clippy or dependency diagnostic
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Diagnostic {
+    rule_id: String,
+    level: FindingLevel,
+    message: String,
+    file: Option<String>,
+    line: Option<u64>,
+}
+
+/// A custom linter finding in a source file
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+struct Finding {
+    file: String,
+    line: usize,
+    kind: FindingKind,
+    /// The trimmed source line
+    snippet: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+enum FindingKind {
+    UnsafeBlock,
+    Todo,
+    /// Hard-coded all-zero hash, e.g. a placeholder transaction hash
+    ZeroHash,
+    /// `+=` on a `u128` balance or valuation without checked arithmetic
+    UncheckedArithmetic,
+    /// `.unwrap()` on an ink! environment call
+    EnvUnwrap,
+}
+
+impl FindingKind {
+    fn rule_id(self) -> &'static str {
+        match self {
+            FindingKind::UnsafeBlock => UNSAFE_RULE,
+            FindingKind::Todo => TODO_RULE,
+            FindingKind::ZeroHash => ZERO_HASH_RULE,
+            FindingKind::UncheckedArithmetic => UNCHECKED_ARITHMETIC_RULE,
+            FindingKind::EnvUnwrap => ENV_UNWRAP_RULE,
+        }
+    }
+
+    fn level(self) -> FindingLevel {
+        match self {
+            FindingKind::UnsafeBlock
+            | FindingKind::ZeroHash
+            | FindingKind::UncheckedArithmetic
+            | FindingKind::EnvUnwrap => FindingLevel::Warning,
+            FindingKind::Todo => FindingLevel::Note,
+        }
+    }
+
+    fn description(self) -> &'static str {
+        match self {
+            FindingKind::UnsafeBlock => "Unsafe block in contract code",
+            FindingKind::Todo => "Unresolved TODO/FIXME marker",
+            FindingKind::ZeroHash => "Hard-coded zero hash",
+            FindingKind::UncheckedArithmetic => "Unchecked u128 addition",
+            FindingKind::EnvUnwrap => "unwrap() on an environment call",
+        }
+    }
+}
+
+impl From<&Finding> for Diagnostic {
+    fn from(finding: &Finding) -> Self {
+        Diagnostic {
+            rule_id: finding.kind.rule_id().to_string(),
+            level: finding.kind.level(),
+            message: format!("{}: {}", finding.kind.description(), finding.snippet),
+            file: Some(finding.file.clone()),
+            line: Some(finding.line as u64),
+        }
+    }
+}
+
+const UNSAFE_RULE: &str = "unsafe-block";
+const TODO_RULE: &str = "todo-marker";
+const ZERO_HASH_RULE: &str = "zero-hash";
+const UNCHECKED_ARITHMETIC_RULE: &str = "unchecked-arithmetic";
+const ENV_UNWRAP_RULE: &str = "env-unwrap";
+
+/// Line-based patterns for ink!-specific footguns
+struct ContractLinter {
+    zero_hash: Regex,
+    hash_context: Regex,
+    u128_binding: Regex,
+    add_assign: Regex,
+    env_unwrap: Regex,
+}
+
+impl ContractLinter {
+    fn new() -> Self {
+        ContractLinter {
+            zero_hash: Regex::new(r"\[0u8;\s*32\]").unwrap(),
+            hash_context: Regex::new(r"(?i)hash").unwrap(),
+            u128_binding: Regex::new(r"(\w+)\s*(?::\s*u128\b|=\s*[0-9_]+u128\b)").unwrap(),
+            add_assign: Regex::new(r"(\w+)\s*\+=").unwrap(),
+            env_unwrap: Regex::new(r"\benv(?:\(\)|::).*\.unwrap\(\)").unwrap(),
+        }
+    }
+
+    /// Returns the 1-based line and kind of every finding in `content`
+    fn lint(&self, content: &str) -> Vec<(usize, FindingKind)> {
+        // Fields and locals typed as u128 anywhere in the file
+        let u128_names: HashSet<&str> = self
+            .u128_binding
+            .captures_iter(content)
+            .filter_map(|c| c.get(1).map(|m| m.as_str()))
+            .collect();
+
+        let mut findings = Vec::new();
+        for (index, line) in content.lines().enumerate() {
+            let mut push = |kind, count| {
+                findings.extend(std::iter::repeat((index + 1, kind)).take(count));
+            };
+
+            push(FindingKind::UnsafeBlock, line.matches("unsafe {").count());
+            push(
+                FindingKind::Todo,
+                line.matches("TODO").count() + line.matches("FIXME").count(),
+            );
+
+            if self.hash_context.is_match(line) {
+                push(
+                    FindingKind::ZeroHash,
+                    self.zero_hash.find_iter(line).count(),
+                );
+            }
+            let unchecked = self
+                .add_assign
+                .captures_iter(line)
+                .filter(|c| u128_names.contains(&c[1]) || line.contains("u128"))
+                .count();
+            push(FindingKind::UncheckedArithmetic, unchecked);
+            if self.env_unwrap.is_match(line) {
+                push(FindingKind::EnvUnwrap, 1);
+            }
+        }
+        findings
+    }
+}
+
 fn main() -> Result<()> {
     let cli = Cli::parse();
 
     match cli.command {
-        Commands::Audit { report } => {
+        Commands::Audit {
+            report,
+            format,
+            fail_under,
+            clippy_output,
+            audit_output,
+        } => {
             println!("{}", "Starting Security Audit Pipeline...".blue().bold());
-            
+
             let mut audit_report = SecurityReport {
                 timestamp: chrono::Utc::now().to_rfc3339(),
                 ..Default::default()
@@ -67,70 +312,50 @@ fn main() -> Result<()> {
 
             // 1. Static Analysis (Clippy)
             println!("{}", "Running Static Analysis (Clippy)...".yellow());
-            let clippy_output = Command::new("cargo")
-                .args(["clippy", "--message-format=json", "--all-targets", "--all-features"])
-                .output()
-                .context("Failed to run cargo clippy")?;
-            
-            // Parse clippy output (simplified)
-            let output_str = String::from_utf8_lossy(&clippy_output.stdout);
-            for line in output_str.lines() {
-                if let Ok(json) = serde_json::from_str::<serde_json::Value>(line) {
-                    if let Some(level) = json.get("level").and_then(|l| l.as_str()) {
-                        match level {
-                            "warning" => {
-                                audit_report.static_analysis.clippy_warnings += 1;
-                                if let Some(message) = json.get("message").and_then(|m| m.as_object()) {
-                                    if let Some(code) = message.get("code").and_then(|c| c.as_object()) {
-                                        if let Some(code_str) = code.get("code").and_then(|s| s.as_str()) {
-                                            if code_str.contains("complexity") {
-                                                audit_report.static_analysis.complexity_warnings += 1;
-                                            }
-                                        }
-                                    }
-                                }
-                            },
-                            "error" => audit_report.static_analysis.clippy_errors += 1,
-                            _ => {}
-                        }
-                    }
+            let clippy_json = match clippy_output {
+                Some(path) => fs::read_to_string(&path)
+                    .with_context(|| format!("Failed to read clippy output from {}", path))?,
+                None => {
+                    let output = Command::new("cargo")
+                        .args([
+                            "clippy",
+                            "--message-format=json",
+                            "--all-targets",
+                            "--all-features",
+                        ])
+                        .output()
+                        .context("Failed to run cargo clippy")?;
+                    String::from_utf8_lossy(&output.stdout).into_owned()
                 }
-            }
+            };
+            parse_clippy_output(&clippy_json, &mut audit_report);
 
-            // 2. Custom Linter (Unsafe & TODOs)
+            // 2. Custom Linter (unsafe, TODOs and ink! footguns)
             println!("{}", "Running Custom Rust Security Linters...".yellow());
-            for entry in WalkDir::new(".").into_iter().filter_map(|e| e.ok()) {
-                if entry.path().extension().map_or(false, |ext| ext == "rs") {
-                    audit_report.code_quality.files_scanned += 1;
-                    let content = fs::read_to_string(entry.path()).unwrap_or_default();
-                    
-                    audit_report.static_analysis.unsafe_blocks += content.matches("unsafe {").count();
-                    audit_report.static_analysis.todos_found += content.matches("TODO").count();
-                    audit_report.static_analysis.todos_found += content.matches("FIXME").count();
-                }
-            }
+            scan_sources(Path::new("."), &mut audit_report);
 
             // 3. Dependency Scan (cargo audit)
-            println!("{}", "Running Dependency Vulnerability Scanning...".yellow());
-            // Check if cargo-audit is installed
-            if Command::new("cargo").args(["audit", "--version"]).output().is_ok() {
-                let audit_cmd = Command::new("cargo")
-                    .args(["audit", "--json"])
-                    .output();
-                
+            println!(
+                "{}",
+                "Running Dependency Vulnerability Scanning...".yellow()
+            );
+            if let Some(path) = audit_output {
+                let audit_json = fs::read_to_string(&path)
+                    .with_context(|| format!("Failed to read cargo audit output from {}", path))?;
+                parse_cargo_audit_output(&audit_json, &mut audit_report);
+            } else if Command::new("cargo")
+                .args(["audit", "--version"])
+                .output()
+                .is_ok()
+            {
+                // cargo-audit is installed
+                let audit_cmd = Command::new("cargo").args(["audit", "--json"]).output();
+
                 if let Ok(output) = audit_cmd {
-                    let output_str = String::from_utf8_lossy(&output.stdout);
-                    if let Ok(json) = serde_json::from_str::<serde_json::Value>(&output_str) {
-                         if let Some(vulns) = json.get("vulnerabilities").and_then(|v| v.as_object()) {
-                             if let Some(list) = vulns.get("list").and_then(|l| l.as_array()) {
-                                 audit_report.dependency_scan.vulnerabilities = list.len();
-                             }
-                         }
-                         if let Some(warnings) = json.get("warnings").and_then(|w| w.as_object()) {
-                            // Count warnings if structure matches, otherwise simplified
-                             audit_report.dependency_scan.warnings = warnings.len();
-                         }
-                    }
+                    parse_cargo_audit_output(
+                        &String::from_utf8_lossy(&output.stdout),
+                        &mut audit_report,
+                    );
                 } else {
                     println!("{}", "cargo audit failed to run".red());
                 }
@@ -138,32 +363,612 @@ fn main() -> Result<()> {
                 println!("{}", "cargo-audit not found. Skipping...".red());
             }
 
-            // Calculate Score
-            let mut score: u32 = 100;
-            score = score.saturating_sub((audit_report.static_analysis.clippy_errors * 10) as u32);
-            score = score.saturating_sub((audit_report.static_analysis.clippy_warnings * 2) as u32);
-            score = score.saturating_sub((audit_report.static_analysis.complexity_warnings * 5) as u32);
-            score = score.saturating_sub((audit_report.static_analysis.unsafe_blocks * 5) as u32);
-            score = score.saturating_sub((audit_report.dependency_scan.vulnerabilities * 20) as u32);
-            
+            let score = calculate_score(&audit_report);
             audit_report.score = score;
 
             println!("{}", "Audit Complete!".green().bold());
             println!("Security Score: {}/100", score);
-            println!("Clippy Issues: {} errors, {} warnings ({} complexity)", 
-                audit_report.static_analysis.clippy_errors, 
+            println!(
+                "Clippy Issues: {} errors, {} warnings ({} complexity)",
+                audit_report.static_analysis.clippy_errors,
                 audit_report.static_analysis.clippy_warnings,
                 audit_report.static_analysis.complexity_warnings
             );
-            println!("Unsafe Blocks: {}", audit_report.static_analysis.unsafe_blocks);
-            println!("Vulnerabilities: {}", audit_report.dependency_scan.vulnerabilities);
+            println!(
+                "Unsafe Blocks: {}",
+                audit_report.static_analysis.unsafe_blocks
+            );
+            println!(
+                "Contract Footguns: {} zero hashes, {} unchecked u128 additions, {} env unwraps",
+                audit_report.static_analysis.zero_hashes,
+                audit_report.static_analysis.unchecked_arithmetic,
+                audit_report.static_analysis.env_unwraps
+            );
+            println!(
+                "Vulnerabilities: {}",
+                audit_report.dependency_scan.vulnerabilities
+            );
 
             if let Some(path) = report {
-                let report_json = serde_json::to_string_pretty(&audit_report)?;
+                let report_json = match format {
+                    ReportFormat::Json => serde_json::to_string_pretty(&audit_report)?,
+                    ReportFormat::Sarif => serde_json::to_string_pretty(&to_sarif(&audit_report))?,
+                };
                 fs::write(path, report_json)?;
                 println!("Report saved to file.");
             }
+
+            if let Some(message) =
+                fail_under.and_then(|threshold| threshold_failure(score, threshold))
+            {
+                eprintln!("{}", message.red().bold());
+                std::process::exit(1);
+            }
+        }
+        Commands::Benchmark {
+            package,
+            e2e,
+            baseline,
+            threshold,
+            report,
+            update_baseline,
+        } => {
+            println!("{}", "Running Gas Benchmarks...".blue().bold());
+
+            let mut args = vec!["test".to_string()];
+            match &package {
+                Some(package) => args.extend(["-p".to_string(), package.clone()]),
+                None => args.push("--workspace".to_string()),
+            }
+            if e2e {
+                args.extend(["--features".to_string(), "e2e-tests".to_string()]);
+            }
+            args.extend(["--".to_string(), "--nocapture".to_string()]);
+
+            let output = Command::new("cargo")
+                .args(&args)
+                .output()
+                .context("Failed to run cargo test")?;
+            let measurements =
+                gas::parse_benchmark_output(&String::from_utf8_lossy(&output.stdout));
+            let run_check = gas::validate_run(output.status.success(), &measurements);
+            if run_check == Err(gas::RunError::TestsFailed) {
+                println!(
+                    "{}",
+                    "Benchmark tests failed; reporting partial results".red()
+                );
+            }
+
+            let stored: gas::GasBaseline = match fs::read_to_string(&baseline) {
+                Ok(content) => serde_json::from_str(&content)
+                    .with_context(|| format!("Invalid baseline file {}", baseline))?,
+                Err(_) => {
+                    println!(
+                        "{}",
+                        format!("No baseline at {}. Skipping comparison...", baseline).red()
+                    );
+                    gas::GasBaseline::default()
+                }
+            };
+
+            let gas_report = gas::compare(&measurements, &stored, threshold);
+            for entry in &gas_report.entries {
+                let change = entry
+                    .change_pct
+                    .map(|change| format!("{:+.1}%", change))
+                    .unwrap_or_else(|| "new".to_string());
+                let line = format!(
+                    "{}: ref_time={} ({})",
+                    entry.message, entry.usage.ref_time, change
+                );
+                if entry.regressed {
+                    println!("{}", line.red());
+                } else {
+                    println!("{}", line);
+                }
+            }
+            println!("Messages Measured: {}", gas_report.entries.len());
+            println!("Regressions: {}", gas_report.regressions);
+
+            if let Some(path) = report {
+                fs::write(path, serde_json::to_string_pretty(&gas_report)?)?;
+                println!("Report saved to file.");
+            }
+
+            // An incomplete run must neither pass nor replace the baseline
+            if let Err(error) = run_check {
+                let action = if update_baseline {
+                    "; baseline not updated"
+                } else {
+                    ""
+                };
+                eprintln!(
+                    "{}",
+                    format!("Benchmark run invalid: {}{}", error, action)
+                        .red()
+                        .bold()
+                );
+                std::process::exit(1);
+            }
+
+            if update_baseline {
+                let updated = gas::GasBaseline {
+                    messages: measurements,
+                };
+                fs::write(&baseline, serde_json::to_string_pretty(&updated)?)?;
+                println!("Baseline updated.");
+            } else if gas_report.regressions > 0 {
+                eprintln!(
+                    "{}",
+                    format!(
+                        "{} message(s) regressed by more than {}%",
+                        gas_report.regressions, threshold
+                    )
+                    .red()
+                    .bold()
+                );
+                std::process::exit(1);
+            }
         }
     }
     Ok(())
 }
+
+/// Parse `cargo clippy --message-format=json` output into counts and findings
+fn parse_clippy_output(output: &str, report: &mut SecurityReport) {
+    for line in output.lines() {
+        let Ok(json) = serde_json::from_str::<serde_json::Value>(line) else {
+            continue;
+        };
+        if json.get("reason").and_then(|r| r.as_str()) != Some("compiler-message") {
+            continue;
+        }
+        let Some(message) = json.get("message") else {
+            continue;
+        };
+
+        // Skip summaries like "N warnings emitted", which carry no source span
+        let spans = message
+            .get("spans")
+            .and_then(|s| s.as_array())
+            .cloned()
+            .unwrap_or_default();
+        if spans.is_empty() {
+            continue;
+        }
+
+        let level = match message.get("level").and_then(|l| l.as_str()) {
+            Some("warning") => {
+                report.static_analysis.clippy_warnings += 1;
+                FindingLevel::Warning
+            }
+            Some("error") => {
+                report.static_analysis.clippy_errors += 1;
+                FindingLevel::Error
+            }
+            _ => continue,
+        };
+
+        let code = message
+            .get("code")
+            .and_then(|c| c.get("code"))
+            .and_then(|c| c.as_str());
+        if code.is_some_and(|c| c.contains("complexity")) {
+            report.static_analysis.complexity_warnings += 1;
+        }
+
+        let primary = spans
+            .iter()
+            .find(|span| span.get("is_primary").and_then(|p| p.as_bool()) == Some(true))
+            .or_else(|| spans.first());
+
+        report.diagnostics.push(Diagnostic {
+            rule_id: code.unwrap_or("rustc").to_string(),
+            level,
+            message: message
+                .get("message")
+                .and_then(|m| m.as_str())
+                .unwrap_or_default()
+                .to_string(),
+            file: primary
+                .and_then(|span| span.get("file_name"))
+                .and_then(|f| f.as_str())
+                .map(str::to_string),
+            line: primary
+                .and_then(|span| span.get("line_start"))
+                .and_then(|l| l.as_u64()),
+        });
+    }
+}
+
+/// Build output and deliberately insecure test fixtures are not part of the audited code
+fn is_excluded_dir(entry: &walkdir::DirEntry) -> bool {
+    if entry.depth() == 0 || !entry.file_type().is_dir() {
+        return false;
+    }
+    let name = entry.file_name();
+    name == "target"
+        || (name == "fixtures"
+            && entry
+                .path()
+                .parent()
+                .and_then(Path::file_name)
+                .is_some_and(|parent| parent == "tests"))
+}
+
+/// Scan Rust sources under `root` with the custom contract linter
+fn scan_sources(root: &Path, report: &mut SecurityReport) {
+    let linter = ContractLinter::new();
+    let entries = WalkDir::new(root)
+        .into_iter()
+        .filter_entry(|e| !is_excluded_dir(e));
+    for entry in entries.filter_map(|e| e.ok()) {
+        if entry.path().extension().is_some_and(|ext| ext == "rs") {
+            report.code_quality.files_scanned += 1;
+            let content = fs::read_to_string(entry.path()).unwrap_or_default();
+            let file = entry
+                .path()
+                .strip_prefix(root)
+                .unwrap_or(entry.path())
+                .display()
+                .to_string();
+
+            let lines: Vec<&str> = content.lines().collect();
+
+            for (line, kind) in linter.lint(&content) {
+                report.static_analysis.record(Finding {
+                    file: file.clone(),
+                    line,
+                    kind,
+                    snippet: lines[line - 1].trim().to_string(),
+                });
+            }
+        }
+    }
+}
+
+/// Parse `cargo audit --json` output into counts and findings
+fn parse_cargo_audit_output(output: &str, report: &mut SecurityReport) {
+    let Ok(json) = serde_json::from_str::<serde_json::Value>(output) else {
+        return;
+    };
+
+    if let Some(vulns) = json.get("vulnerabilities").and_then(|v| v.as_object()) {
+        if let Some(list) = vulns.get("list").and_then(|l| l.as_array()) {
+            report.dependency_scan.vulnerabilities = list.len();
+
+            for vuln in list {
+                let advisory = vuln.get("advisory");
+                let field = |name: &str| {
+                    advisory
+                        .and_then(|a| a.get(name))
+                        .and_then(|v| v.as_str())
+                        .unwrap_or_default()
+                        .to_string()
+                };
+                let id = field("id");
+                let package = field("package");
+
+                report.diagnostics.push(Diagnostic {
+                    rule_id: if id.is_empty() {
+                        "dependency-vulnerability".to_string()
+                    } else {
+                        id
+                    },
+                    level: FindingLevel::Error,
+                    message: format!("{}: {}", package, field("title")),
+                    file: Some("Cargo.lock".to_string()),
+                    line: None,
+                });
+            }
+        }
+    }
+    if let Some(warnings) = json.get("warnings").and_then(|w| w.as_object()) {
+        // Count warnings if structure matches, otherwise simplified
+        report.dependency_scan.warnings = warnings.len();
+    }
+}
+
+/// Message reported when `score` falls below the `--fail-under` threshold
+fn threshold_failure(score: u32, threshold: u32) -> Option<String> {
+    (score < threshold).then(|| {
+        format!(
+            "Security score {} is {} below the required {}",
+            score,
+            threshold - score,
+            threshold
+        )
+    })
+}
+
+fn calculate_score(report: &SecurityReport) -> u32 {
+    let mut score: u32 = 100;
+    score = score.saturating_sub((report.static_analysis.clippy_errors * 10) as u32);
+    score = score.saturating_sub((report.static_analysis.clippy_warnings * 2) as u32);
+    score = score.saturating_sub((report.static_analysis.complexity_warnings * 5) as u32);
+    score = score.saturating_sub((report.static_analysis.unsafe_blocks * 5) as u32);
+    score = score.saturating_sub((report.static_analysis.zero_hashes * 5) as u32);
+    score = score.saturating_sub((report.static_analysis.unchecked_arithmetic * 3) as u32);
+    score = score.saturating_sub((report.static_analysis.env_unwraps * 3) as u32);
+    score = score.saturating_sub((report.dependency_scan.vulnerabilities * 20) as u32);
+    score
+}
+
+/// Convert the report's findings into a SARIF 2.1.0 log
+fn to_sarif(report: &SecurityReport) -> serde_json::Value {
+    let diagnostics: Vec<Diagnostic> = report
+        .diagnostics
+        .iter()
+        .cloned()
+        .chain(report.static_analysis.findings.iter().map(Diagnostic::from))
+        .collect();
+
+    let mut rules = BTreeMap::new();
+    for finding in &report.static_analysis.findings {
+        rules.insert(
+            finding.kind.rule_id().to_string(),
+            finding.kind.description().to_string(),
+        );
+    }
+    for diagnostic in &report.diagnostics {
+        rules.entry(diagnostic.rule_id.clone()).or_insert_with(|| {
+            if diagnostic.rule_id.starts_with("RUSTSEC-") {
+                "Vulnerable dependency".to_string()
+            } else {
+                format!("Compiler diagnostic {}", diagnostic.rule_id)
+            }
+        });
+    }
+
+    let rules: Vec<_> = rules
+        .into_iter()
+        .map(|(id, description)| {
+            serde_json::json!({
+                "id": id,
+                "shortDescription": { "text": description },
+            })
+        })
+        .collect();
+
+    let results: Vec<_> = diagnostics
+        .iter()
+        .map(|finding| {
+            let mut result = serde_json::json!({
+                "ruleId": finding.rule_id,
+                "level": finding.level.as_sarif(),
+                "message": { "text": finding.message },
+            });
+
+            if let Some(file) = &finding.file {
+                let mut physical_location = serde_json::json!({
+                    "artifactLocation": { "uri": file.replace('\\', "/") },
+                });
+                if let Some(line) = finding.line {
+                    physical_location["region"] = serde_json::json!({ "startLine": line });
+                }
+                result["locations"] =
+                    serde_json::json!([{ "physicalLocation": physical_location }]);
+            }
+
+            result
+        })
+        .collect();
+
+    serde_json::json!({
+        "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "security-audit",
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "rules": rules,
+                }
+            },
+            "results": results,
+        }]
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIXTURE_CRATE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/sample_crate");
+    const FOOTGUN_FIXTURE: &str =
+        concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/ink_footguns");
+
+    #[test]
+    fn sarif_report_for_fixture_crate() {
+        let mut report = SecurityReport::default();
+
+        let clippy_output =
+            fs::read_to_string(Path::new(FIXTURE_CRATE).join("clippy.json")).unwrap();
+        parse_clippy_output(&clippy_output, &mut report);
+        scan_sources(Path::new(FIXTURE_CRATE), &mut report);
+
+        assert_eq!(report.static_analysis.clippy_warnings, 1);
+        assert_eq!(report.static_analysis.clippy_errors, 0);
+        assert_eq!(report.static_analysis.unsafe_blocks, 1);
+        assert_eq!(report.static_analysis.todos_found, 2);
+
+        let sarif = to_sarif(&report);
+        assert_eq!(sarif["version"], "2.1.0");
+        assert!(sarif["$schema"].as_str().unwrap().contains("sarif-2.1.0"));
+
+        let run = &sarif["runs"][0];
+        assert_eq!(run["tool"]["driver"]["name"], "security-audit");
+
+        let rule_ids: Vec<_> = run["tool"]["driver"]["rules"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|rule| rule["id"].as_str().unwrap())
+            .collect();
+        assert_eq!(
+            rule_ids,
+            ["clippy::needless_return", TODO_RULE, UNSAFE_RULE]
+        );
+
+        let results = run["results"].as_array().unwrap();
+        assert_eq!(results.len(), 4);
+
+        let clippy = &results[0];
+        assert_eq!(clippy["ruleId"], "clippy::needless_return");
+        assert_eq!(clippy["level"], "warning");
+        let location = &clippy["locations"][0]["physicalLocation"];
+        assert_eq!(location["artifactLocation"]["uri"], "src/lib.rs");
+        assert_eq!(location["region"]["startLine"], 4);
+
+        let unsafe_result = results
+            .iter()
+            .find(|result| result["ruleId"] == UNSAFE_RULE)
+            .unwrap();
+        assert_eq!(unsafe_result["level"], "warning");
+        let location = &unsafe_result["locations"][0]["physicalLocation"];
+        assert_eq!(location["artifactLocation"]["uri"], "src/lib.rs");
+        assert_eq!(location["region"]["startLine"], 9);
+
+        assert!(results
+            .iter()
+            .filter(|result| result["ruleId"] == TODO_RULE)
+            .all(|result| result["level"] == "note"));
+    }
+
+    #[test]
+    fn fail_under_threshold_for_fixture_crate() {
+        let mut report = SecurityReport::default();
+        let clippy_output =
+            fs::read_to_string(Path::new(FIXTURE_CRATE).join("clippy.json")).unwrap();
+        parse_clippy_output(&clippy_output, &mut report);
+        scan_sources(Path::new(FIXTURE_CRATE), &mut report);
+
+        // One clippy warning and one unsafe block
+        let score = calculate_score(&report);
+        assert_eq!(score, 100 - 2 - 5);
+
+        assert_eq!(threshold_failure(score, 50), None);
+        assert_eq!(threshold_failure(score, score), None);
+        assert_eq!(
+            threshold_failure(score, 100).as_deref(),
+            Some("Security score 93 is 7 below the required 100")
+        );
+    }
+
+    #[test]
+    fn linter_findings_record_locations() {
+        let mut report = SecurityReport::default();
+        scan_sources(Path::new(FIXTURE_CRATE), &mut report);
+
+        let findings = &report.static_analysis.findings;
+        assert_eq!(
+            findings
+                .iter()
+                .map(|f| (f.kind, f.line))
+                .collect::<Vec<_>>(),
+            [
+                (FindingKind::Todo, 8),
+                (FindingKind::UnsafeBlock, 9),
+                (FindingKind::Todo, 12),
+            ]
+        );
+        assert!(findings.iter().all(|f| f.file == "src/lib.rs"));
+        assert_eq!(findings[1].snippet, "unsafe { *bytes.as_ptr() }");
+
+        // Counts are derived from the findings
+        assert_eq!(report.static_analysis.unsafe_blocks, 1);
+        assert_eq!(report.static_analysis.todos_found, 2);
+
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(
+            json["static_analysis"]["findings"][1]["kind"],
+            "unsafe_block"
+        );
+        assert_eq!(json["static_analysis"]["findings"][1]["line"], 9);
+    }
+
+    #[test]
+    fn linter_detects_ink_footguns() {
+        let mut report = SecurityReport::default();
+        scan_sources(Path::new(FOOTGUN_FIXTURE), &mut report);
+
+        let located: Vec<_> = report
+            .static_analysis
+            .findings
+            .iter()
+            .map(|f| (f.kind, f.line))
+            .collect();
+        assert_eq!(
+            located,
+            [
+                (FindingKind::ZeroHash, 8),
+                (FindingKind::UncheckedArithmetic, 15),
+                (FindingKind::UncheckedArithmetic, 20),
+                (FindingKind::EnvUnwrap, 25),
+            ]
+        );
+
+        let static_analysis = &report.static_analysis;
+        assert_eq!(static_analysis.zero_hashes, 1);
+        assert_eq!(static_analysis.unchecked_arithmetic, 2);
+        assert_eq!(static_analysis.env_unwraps, 1);
+        assert_eq!(calculate_score(&report), 100 - 5 - 2 * 3 - 3);
+
+        let rule_ids: Vec<_> = to_sarif(&report)["runs"][0]["tool"]["driver"]["rules"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|rule| rule["id"].as_str().unwrap().to_string())
+            .collect();
+        assert_eq!(
+            rule_ids,
+            [ENV_UNWRAP_RULE, UNCHECKED_ARITHMETIC_RULE, ZERO_HASH_RULE]
+        );
+    }
+
+    #[test]
+    fn scan_skips_fixtures_and_target() {
+        let mut report = SecurityReport::default();
+        scan_sources(Path::new(env!("CARGO_MANIFEST_DIR")), &mut report);
+
+        assert!(report
+            .static_analysis
+            .findings
+            .iter()
+            .all(|f| !f.file.starts_with("tests/fixtures") && !f.file.starts_with("target")));
+        assert_eq!(report.static_analysis.zero_hashes, 0);
+        assert_eq!(report.static_analysis.env_unwraps, 0);
+    }
+
+    #[test]
+    fn cargo_audit_vulnerabilities_become_error_results() {
+        let mut report = SecurityReport::default();
+        let output = r#"{
+            "vulnerabilities": {
+                "found": true,
+                "count": 1,
+                "list": [{
+                    "advisory": { "id": "RUSTSEC-2020-0071", "package": "time", "title": "Potential segfault" },
+                    "package": { "name": "time", "version": "0.1.45" }
+                }]
+            },
+            "warnings": {}
+        }"#;
+
+        parse_cargo_audit_output(output, &mut report);
+        assert_eq!(report.dependency_scan.vulnerabilities, 1);
+
+        let sarif = to_sarif(&report);
+        let result = &sarif["runs"][0]["results"][0];
+        assert_eq!(result["ruleId"], "RUSTSEC-2020-0071");
+        assert_eq!(result["level"], "error");
+        assert_eq!(result["message"]["text"], "time: Potential segfault");
+        assert_eq!(
+            result["locations"][0]["physicalLocation"]["artifactLocation"]["uri"],
+            "Cargo.lock"
+        );
+        assert!(result["locations"][0]["physicalLocation"]
+            .get("region")
+            .is_none());
+    }
+}
@@ -0,0 +1,44 @@
+use std::path::Path;
+use std::process::{Command, Output};
+
+const FIXTURE_CRATE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/sample_crate");
+
+/// Runs `security-audit audit` in the fixture crate on its canned clippy and cargo audit output
+fn audit(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_security-audit"))
+        .args([
+            "audit",
+            "--clippy-output",
+            "clippy.json",
+            "--audit-output",
+            "audit.json",
+        ])
+        .args(args)
+        .current_dir(Path::new(FIXTURE_CRATE))
+        .output()
+        .expect("failed to run security-audit")
+}
+
+#[test]
+fn passes_when_score_meets_threshold() {
+    // The fixture's unsafe block alone keeps the score below 100
+    let output = audit(&["--fail-under", "50"]);
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+#[test]
+fn fails_when_score_is_below_threshold() {
+    let output = audit(&["--fail-under", "100"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("below the required 100"));
+}
+
+#[test]
+fn exits_zero_without_threshold() {
+    let output = audit(&[]);
+    assert!(output.status.success());
+}
@@ -0,0 +1,32 @@
+//! Fixture for the ink!-specific linter rules
+
+pub struct Hash([u8; 32]);
+pub struct AccountId([u8; 32]);
+
+pub fn record_transfer() -> Hash {
+    // Placeholder instead of the real transaction hash
+    let transaction_hash = Hash([0u8; 32]);
+    transaction_hash
+}
+
+pub fn total_valuation(valuations: &[u128]) -> u128 {
+    let mut total_valuation = 0u128;
+    for valuation in valuations {
+        total_valuation += *valuation;
+    }
+    // u64 counters are not flagged
+    let mut count = 0u64;
+    count += 1;
+    total_valuation += count as u128;
+    total_valuation
+}
+
+pub fn caller(env: &Env) -> AccountId {
+    env.env().caller().unwrap()
+}
+
+pub fn zero_address() -> AccountId {
+    AccountId([0u8; 32])
+}
+
+pub struct Env;
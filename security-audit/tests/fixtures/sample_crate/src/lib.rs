@@ -0,0 +1,13 @@
+//! Fixture crate for the security-audit SARIF tests
+
+pub fn answer() -> u32 {
+    return 42;
+}
+
+pub fn first_byte(bytes: &[u8]) -> u8 {
+    // TODO: handle empty input
+    unsafe { *bytes.as_ptr() }
+}
+
+// FIXME: remove once callers migrate
+pub fn legacy() {}
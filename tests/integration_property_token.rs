@@ -19,6 +19,8 @@ mod integration_tests {
             legal_description: String::from("Registry test property"),
             valuation: 400000,
             documents_url: String::from("ipfs://registry-docs"),
+            latitude: None,
+            longitude: None,
         };
         
         let property_id = registry.register_property(metadata.clone()).unwrap();
@@ -40,6 +42,8 @@ mod integration_tests {
             legal_description: String::from("Token test property"),
             valuation: 500000,
             documents_url: String::from("ipfs://token-docs"),
+            latitude: None,
+            longitude: None,
         };
         
         let token_id = token_contract.register_property_with_token(metadata).unwrap();
@@ -77,6 +81,8 @@ mod integration_tests {
             legal_description: String::from("From registry"),
             valuation: 300000,
             documents_url: String::from("ipfs://traditional"),
+            latitude: None,
+            longitude: None,
         };
         
         let registry_property_id = registry.register_property(registry_metadata).unwrap();
@@ -88,6 +94,8 @@ mod integration_tests {
             legal_description: String::from("From token contract"),
             valuation: 600000,
             documents_url: String::from("ipfs://enhanced"),
+            latitude: None,
+            longitude: None,
         };
         
         let token_id = token_contract.register_property_with_token(token_metadata).unwrap();
@@ -114,6 +122,8 @@ mod integration_tests {
             legal_description: String::from("Originally in old registry"),
             valuation: 350000,
             documents_url: String::from("ipfs://old-system"),
+            latitude: None,
+            longitude: None,
         };
         
         let old_property_id = old_registry.register_property(old_metadata.clone()).unwrap();
@@ -127,6 +137,8 @@ mod integration_tests {
             legal_description: old_metadata.legal_description,
             valuation: old_metadata.valuation,
             documents_url: old_metadata.documents_url,
+            latitude: None,
+            longitude: None,
         };
         
         let new_token_id = new_token_contract.register_property_with_token(new_metadata).unwrap();
@@ -158,6 +170,8 @@ mod integration_tests {
             legal_description: String::from("For escrow testing"),
             valuation: 450000,
             documents_url: String::from("ipfs://escrow-test"),
+            latitude: None,
+            longitude: None,
         };
         
         let token_id = token_contract.register_property_with_token(metadata).unwrap();
@@ -200,6 +214,8 @@ mod integration_tests {
                 legal_description: String::from("Batch created property"),
                 valuation,
                 documents_url: String::from("ipfs://batch"),
+                latitude: None,
+                longitude: None,
             };
             
             let token_id = token_contract.register_property_with_token(metadata).unwrap();
@@ -234,6 +250,8 @@ mod integration_tests {
             legal_description: String::from("Ownership tracking test"),
             valuation: 520000,
             documents_url: String::from("ipfs://tracking"),
+            latitude: None,
+            longitude: None,
         };
         
         let token_id = token_contract.register_property_with_token(metadata).unwrap();
@@ -267,6 +285,8 @@ mod integration_tests {
             legal_description: String::from("Security features test"),
             valuation: 480000,
             documents_url: String::from("ipfs://security"),
+            latitude: None,
+            longitude: None,
         };
         
         let token_id = token_contract.register_property_with_token(metadata).unwrap();
@@ -304,6 +324,8 @@ mod integration_tests {
             legal_description: String::from("Backward compatibility test"),
             valuation: 420000,
             documents_url: String::from("ipfs://compatibility"),
+            latitude: None,
+            longitude: None,
         };
         
         let token_id = token_contract.register_property_with_token(metadata).unwrap();
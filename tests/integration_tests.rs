@@ -24,6 +24,8 @@ async fn e2e_register_property() -> E2EResult<()> {
         legal_description: "Test property".to_string(),
         valuation: 500000,
         documents_url: "https://ipfs.io/test".to_string(),
+        latitude: None,
+        longitude: None,
     };
 
     // When
@@ -57,6 +59,8 @@ async fn e2e_get_property() -> E2EResult<()> {
         legal_description: "Test property".to_string(),
         valuation: 500000,
         documents_url: "https://ipfs.io/test".to_string(),
+        latitude: None,
+        longitude: None,
     };
 
     let register_msg = build_message::<PropertyRegistry>(contract_acc_id.clone())
@@ -99,6 +103,8 @@ async fn e2e_cross_contract_escrow_workflow() -> E2EResult<()> {
         legal_description: "Commercial property for escrow".to_string(),
         valuation: 750000,
         documents_url: "https://ipfs.io/escrow-test".to_string(),
+        latitude: None,
+        longitude: None,
     };
 
     // Register property
@@ -121,23 +127,36 @@ async fn e2e_cross_contract_escrow_workflow() -> E2EResult<()> {
         .expect("get escrow failed").return_value().expect("return value failed").unwrap();
     assert_eq!(escrow_info.property_id, property_id);
     assert_eq!(escrow_info.amount, escrow_amount);
-    assert!(!escrow_info.released);
+    assert!(!escrow_info.released());
 
     // Release escrow (transfers property)
     let release_msg = build_message::<PropertyRegistry>(contract_acc_id.clone())
         .call(|contract| contract.release_escrow(escrow_id));
-    client.call(&ink_e2e::alice(), release_msg, 0, None).await.expect("release failed");
+    client
+        .call(&ink_e2e::alice(), release_msg, 0, None)
+        .await
+        .expect("release failed");
 
     // Verify property transferred and escrow released
     let get_property_msg = build_message::<PropertyRegistry>(contract_acc_id.clone())
         .call(|contract| contract.get_property(property_id));
-    let property = client.call_dry_run(&ink_e2e::alice(), &get_property_msg, 0, None).await
-        .expect("get property failed").return_value().expect("return value failed").unwrap();
+    let property = client
+        .call_dry_run(&ink_e2e::alice(), &get_property_msg, 0, None)
+        .await
+        .expect("get property failed")
+        .return_value()
+        .expect("return value failed")
+        .unwrap();
     assert_eq!(property.owner, ink_e2e::alice().account_id); // Still Alice since buyer == seller in this test
 
-    let escrow_after = client.call_dry_run(&ink_e2e::alice(), &get_escrow_msg, 0, None).await
-        .expect("get escrow after failed").return_value().expect("return value failed").unwrap();
-    assert!(escrow_after.released);
+    let escrow_after = client
+        .call_dry_run(&ink_e2e::alice(), &get_escrow_msg, 0, None)
+        .await
+        .expect("get escrow after failed")
+        .return_value()
+        .expect("return value failed")
+        .unwrap();
+    assert!(escrow_after.released());
 
     Ok(())
 }
@@ -160,6 +179,8 @@ async fn e2e_network_failure_scenarios() -> E2EResult<()> {
         legal_description: "Property for failure test".to_string(),
         valuation: 600000,
         documents_url: "https://ipfs.io/failure-test".to_string(),
+        latitude: None,
+        longitude: None,
     };
 
     // Register property
@@ -221,6 +242,8 @@ async fn e2e_performance_under_load() -> E2EResult<()> {
             legal_description: format!("Test property {}", i),
             valuation: 100000 + i as u128 * 1000,
             documents_url: format!("https://ipfs.io/test{}", i),
+            latitude: None,
+            longitude: None,
         };
 
         let register_msg = build_message::<PropertyRegistry>(contract_acc_id.clone())
@@ -265,6 +288,8 @@ async fn e2e_transfer_property() -> E2EResult<()> {
         legal_description: "Test property".to_string(),
         valuation: 500000,
         documents_url: "https://ipfs.io/test".to_string(),
+        latitude: None,
+        longitude: None,
     };
 
     let register_msg = build_message::<PropertyRegistry>(contract_acc_id.clone())
@@ -310,6 +335,8 @@ async fn e2e_end_to_end_property_workflow() -> E2EResult<()> {
             legal_description: "Residential property".to_string(),
             valuation: 500000,
             documents_url: "https://ipfs.io/test1".to_string(),
+            latitude: None,
+            longitude: None,
         },
         PropertyMetadata {
             location: "456 Oak Ave".to_string(),
@@ -317,6 +344,8 @@ async fn e2e_end_to_end_property_workflow() -> E2EResult<()> {
             legal_description: "Commercial property".to_string(),
             valuation: 750000,
             documents_url: "https://ipfs.io/test2".to_string(),
+            latitude: None,
+            longitude: None,
         },
     ];
 
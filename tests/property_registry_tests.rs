@@ -32,6 +32,8 @@ mod tests {
             legal_description: "Test property".to_string(),
             valuation: 500000,
             documents_url: "https://ipfs.io/test".to_string(),
+            latitude: None,
+            longitude: None,
         };
 
         let result = contract.register_property(metadata);
@@ -53,6 +55,8 @@ mod tests {
             legal_description: "Test property".to_string(),
             valuation: 500000,
             documents_url: "https://ipfs.io/test".to_string(),
+            latitude: None,
+            longitude: None,
         };
 
         let property_id = contract.register_property(metadata).unwrap();
@@ -75,6 +79,8 @@ mod tests {
             legal_description: "Test property".to_string(),
             valuation: 500000,
             documents_url: "https://ipfs.io/test".to_string(),
+            latitude: None,
+            longitude: None,
         };
 
         let property_id = contract.register_property(metadata).unwrap();
@@ -100,6 +106,8 @@ mod tests {
             legal_description: "Test property".to_string(),
             valuation: 500000,
             documents_url: "https://ipfs.io/test".to_string(),
+            latitude: None,
+            longitude: None,
         };
 
         let property_id = contract.register_property(metadata).unwrap();
@@ -27,6 +27,8 @@ mod property_token_tests {
             legal_description: String::from("Sample property"),
             valuation: 500000,
             documents_url: String::from("ipfs://sample-docs"),
+            latitude: None,
+            longitude: None,
         };
         
         let result = contract.register_property_with_token(metadata.clone());
@@ -54,6 +56,8 @@ mod property_token_tests {
             legal_description: String::from("Sample property"),
             valuation: 500000,
             documents_url: String::from("ipfs://sample-docs"),
+            latitude: None,
+            longitude: None,
         };
         
         let token_id = contract.register_property_with_token(metadata).unwrap();
@@ -82,6 +86,8 @@ mod property_token_tests {
             legal_description: String::from("Sample property"),
             valuation: 500000,
             documents_url: String::from("ipfs://sample-docs"),
+            latitude: None,
+            longitude: None,
         };
         
         let token_id = contract.register_property_with_token(metadata).unwrap();
@@ -115,6 +121,8 @@ mod property_token_tests {
             legal_description: String::from("Sample property"),
             valuation: 500000,
             documents_url: String::from("ipfs://sample-docs"),
+            latitude: None,
+            longitude: None,
         };
         
         let token_id = contract.register_property_with_token(metadata).unwrap();
@@ -143,6 +151,8 @@ mod property_token_tests {
             legal_description: String::from("Sample property"),
             valuation: 500000,
             documents_url: String::from("ipfs://sample-docs"),
+            latitude: None,
+            longitude: None,
         };
         
         let token_id = contract.register_property_with_token(metadata).unwrap();
@@ -168,6 +178,8 @@ mod property_token_tests {
             legal_description: String::from("Sample property"),
             valuation: 500000,
             documents_url: String::from("ipfs://sample-docs"),
+            latitude: None,
+            longitude: None,
         };
         
         // Register two properties
@@ -197,6 +209,8 @@ mod property_token_tests {
             legal_description: String::from("Sample property"),
             valuation: 500000,
             documents_url: String::from("ipfs://sample-docs"),
+            latitude: None,
+            longitude: None,
         };
         
         let token_id = contract.register_property_with_token(metadata).unwrap();
@@ -240,6 +254,8 @@ mod property_token_tests {
             legal_description: String::from("Sample property"),
             valuation: 500000,
             documents_url: String::from("ipfs://sample-docs"),
+            latitude: None,
+            longitude: None,
         };
         
         let token_id = contract.register_property_with_token(metadata).unwrap();
@@ -266,6 +282,8 @@ mod property_token_tests {
             legal_description: String::from("Sample property"),
             valuation: 500000,
             documents_url: String::from("ipfs://sample-docs"),
+            latitude: None,
+            longitude: None,
         };
         
         let token_id = contract.register_property_with_token(metadata).unwrap();